@@ -11,6 +11,16 @@ const BR_SHUF_MASK_AVX: [i8; 32] = [
     13, 12, 15,
 ];
 
+const BSWAP16_MASK_AVX: [i8; 32] = [
+    1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14, 1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13,
+    12, 15, 14,
+];
+
+const BSWAP32_MASK_AVX: [i8; 32] = [
+    3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12, 3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15,
+    14, 13, 12,
+];
+
 const ALPHA_FF_MASK_AVX: [i8; 32] = [
     0, 0, 0, -1, 0, 0, 0, -1, 0, 0, 0, -1, 0, 0, 0, -1, 0, 0, 0, -1, 0, 0, 0, -1, 0, 0, 0, -1, 0,
     0, 0, -1,
@@ -1099,6 +1109,355 @@ pub(super) fn gray_alpha_to_4bpp_alpha_first_strided_v3(
     }
 }
 
+// ===========================================================================
+// Byte-order swaps (u16 / u32 samples)
+// ===========================================================================
+//
+// `N` is the sample width in bytes (2 or 4); every N-byte group is reversed.
+
+#[rite]
+pub(super) fn swap_bytes_row_v3<const N: usize>(_token: X64V3Token, row: &mut [u8]) {
+    let mask = if N == 2 {
+        _mm256_loadu_si256(&BSWAP16_MASK_AVX)
+    } else {
+        _mm256_loadu_si256(&BSWAP32_MASK_AVX)
+    };
+    let n = row.len();
+    let mut i = 0;
+    while i + 32 <= n {
+        let arr: &[u8; 32] = row[i..i + 32].try_into().unwrap();
+        let v = _mm256_loadu_si256(arr);
+        let shuffled = _mm256_shuffle_epi8(v, mask);
+        let out: &mut [u8; 32] = (&mut row[i..i + 32]).try_into().unwrap();
+        _mm256_storeu_si256(out, shuffled);
+        i += 32;
+    }
+    for v in row[i..].chunks_exact_mut(N) {
+        v.reverse();
+    }
+}
+
+#[rite]
+pub(super) fn copy_swap_bytes_row_v3<const N: usize>(
+    _token: X64V3Token,
+    src: &[u8],
+    dst: &mut [u8],
+) {
+    let mask = if N == 2 {
+        _mm256_loadu_si256(&BSWAP16_MASK_AVX)
+    } else {
+        _mm256_loadu_si256(&BSWAP32_MASK_AVX)
+    };
+    let n = src.len().min(dst.len());
+    let mut i = 0;
+    while i + 32 <= n {
+        let s: &[u8; 32] = src[i..i + 32].try_into().unwrap();
+        let v = _mm256_loadu_si256(s);
+        let shuffled = _mm256_shuffle_epi8(v, mask);
+        let d: &mut [u8; 32] = (&mut dst[i..i + 32]).try_into().unwrap();
+        _mm256_storeu_si256(d, shuffled);
+        i += 32;
+    }
+    for (s, d) in src[i..].chunks_exact(N).zip(dst[i..].chunks_exact_mut(N)) {
+        for (j, b) in d.iter_mut().enumerate() {
+            *b = s[N - 1 - j];
+        }
+    }
+}
+
+#[arcane]
+pub(super) fn swap_bytes16_impl_v3(t: X64V3Token, b: &mut [u8]) {
+    swap_bytes_row_v3::<2>(t, b);
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes16_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
+    copy_swap_bytes_row_v3::<2>(t, s, d);
+}
+
+#[arcane]
+pub(super) fn swap_bytes16_strided_v3(
+    t: X64V3Token,
+    buf: &mut [u8],
+    w: usize,
+    h: usize,
+    stride: usize,
+) {
+    for y in 0..h {
+        swap_bytes_row_v3::<2>(t, &mut buf[y * stride..][..w * 2]);
+    }
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes16_strided_v3(
+    t: X64V3Token,
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+) {
+    for y in 0..h {
+        copy_swap_bytes_row_v3::<2>(t, &src[y * ss..][..w * 2], &mut dst[y * ds..][..w * 2]);
+    }
+}
+
+#[arcane]
+pub(super) fn swap_bytes32_impl_v3(t: X64V3Token, b: &mut [u8]) {
+    swap_bytes_row_v3::<4>(t, b);
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes32_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
+    copy_swap_bytes_row_v3::<4>(t, s, d);
+}
+
+#[arcane]
+pub(super) fn swap_bytes32_strided_v3(
+    t: X64V3Token,
+    buf: &mut [u8],
+    w: usize,
+    h: usize,
+    stride: usize,
+) {
+    for y in 0..h {
+        swap_bytes_row_v3::<4>(t, &mut buf[y * stride..][..w * 4]);
+    }
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes32_strided_v3(
+    t: X64V3Token,
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+) {
+    for y in 0..h {
+        copy_swap_bytes_row_v3::<4>(t, &src[y * ss..][..w * 4], &mut dst[y * ds..][..w * 4]);
+    }
+}
+
+// ===========================================================================
+// Palette expansion (≤ 16 entries)
+// ===========================================================================
+//
+// `table` is the palette already in output byte order, zero past the last
+// entry. With at most 16 live entries each output byte is one `pshufb` of a
+// 16-byte plane. Indices ≥ 16 are pushed to ≥ 0x80 by a saturating add so the
+// shuffle returns 0, matching `table[i]` for the zeroed entries.
+
+#[rite]
+pub(super) fn indexed_to_4bpp_small_row_v3(
+    _token: X64V3Token,
+    src: &[u8],
+    dst: &mut [u8],
+    table: &[[u8; 4]; 256],
+) {
+    let mut planes = [[0u8; 32]; 4];
+    for (c, plane) in planes.iter_mut().enumerate() {
+        for (j, entry) in table[..16].iter().enumerate() {
+            plane[j] = entry[c];
+            plane[j + 16] = entry[c];
+        }
+    }
+    let p0 = _mm256_loadu_si256(&planes[0]);
+    let p1 = _mm256_loadu_si256(&planes[1]);
+    let p2 = _mm256_loadu_si256(&planes[2]);
+    let p3 = _mm256_loadu_si256(&planes[3]);
+    let bias = _mm256_set1_epi8(0x70);
+    let (slen, dlen) = (src.len(), dst.len());
+    let (mut is, mut id) = (0, 0);
+    while is + 32 <= slen && id + 128 <= dlen {
+        let s: &[u8; 32] = src[is..is + 32].try_into().unwrap();
+        let idx = _mm256_adds_epu8(_mm256_loadu_si256(s), bias);
+        let c0 = _mm256_shuffle_epi8(p0, idx);
+        let c1 = _mm256_shuffle_epi8(p1, idx);
+        let c2 = _mm256_shuffle_epi8(p2, idx);
+        let c3 = _mm256_shuffle_epi8(p3, idx);
+        // Per 128-bit lane: pixels 0-7 / 8-15 as byte pairs, then 4-byte pixels.
+        let lo01 = _mm256_unpacklo_epi8(c0, c1);
+        let hi01 = _mm256_unpackhi_epi8(c0, c1);
+        let lo23 = _mm256_unpacklo_epi8(c2, c3);
+        let hi23 = _mm256_unpackhi_epi8(c2, c3);
+        let q0 = _mm256_unpacklo_epi16(lo01, lo23); // px 0-3   | 16-19
+        let q1 = _mm256_unpackhi_epi16(lo01, lo23); // px 4-7   | 20-23
+        let q2 = _mm256_unpacklo_epi16(hi01, hi23); // px 8-11  | 24-27
+        let q3 = _mm256_unpackhi_epi16(hi01, hi23); // px 12-15 | 28-31
+        let out = [
+            _mm256_permute2x128_si256::<0x20>(q0, q1),
+            _mm256_permute2x128_si256::<0x20>(q2, q3),
+            _mm256_permute2x128_si256::<0x31>(q0, q1),
+            _mm256_permute2x128_si256::<0x31>(q2, q3),
+        ];
+        for (k, v) in out.into_iter().enumerate() {
+            let d: &mut [u8; 32] = (&mut dst[id + k * 32..id + k * 32 + 32])
+                .try_into()
+                .unwrap();
+            _mm256_storeu_si256(d, v);
+        }
+        is += 32;
+        id += 128;
+    }
+    for (&i, d) in src[is..].iter().zip(dst[id..].chunks_exact_mut(4)) {
+        d.copy_from_slice(&table[i as usize]);
+    }
+}
+
+#[arcane]
+pub(super) fn indexed_to_4bpp_small_impl_v3(
+    t: X64V3Token,
+    s: &[u8],
+    d: &mut [u8],
+    table: &[[u8; 4]; 256],
+) {
+    indexed_to_4bpp_small_row_v3(t, s, d, table);
+}
+
+#[arcane]
+#[allow(clippy::too_many_arguments)]
+pub(super) fn indexed_to_4bpp_small_strided_v3(
+    t: X64V3Token,
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+    table: &[[u8; 4]; 256],
+) {
+    for y in 0..h {
+        indexed_to_4bpp_small_row_v3(t, &src[y * ss..][..w], &mut dst[y * ds..][..w * 4], table);
+    }
+}
+
+// ===========================================================================
+// Orientation transforms (4bpp, fused swizzle)
+// ===========================================================================
+
+/// `pshufb` mask applying `order` to each of the eight pixels in a register.
+fn swizzle_mask(order: [u8; 4]) -> [i8; 32] {
+    core::array::from_fn(|i| ((i & !3) + order[i & 3] as usize) as i8)
+}
+
+/// Transpose an 8×8 block of 32-bit pixels held one row per register.
+#[rite]
+fn transpose_8x8_v3(_token: X64V3Token, r: [__m256i; 8]) -> [__m256i; 8] {
+    let t0 = _mm256_unpacklo_epi32(r[0], r[1]);
+    let t1 = _mm256_unpackhi_epi32(r[0], r[1]);
+    let t2 = _mm256_unpacklo_epi32(r[2], r[3]);
+    let t3 = _mm256_unpackhi_epi32(r[2], r[3]);
+    let t4 = _mm256_unpacklo_epi32(r[4], r[5]);
+    let t5 = _mm256_unpackhi_epi32(r[4], r[5]);
+    let t6 = _mm256_unpacklo_epi32(r[6], r[7]);
+    let t7 = _mm256_unpackhi_epi32(r[6], r[7]);
+    let u0 = _mm256_unpacklo_epi64(t0, t2);
+    let u1 = _mm256_unpackhi_epi64(t0, t2);
+    let u2 = _mm256_unpacklo_epi64(t1, t3);
+    let u3 = _mm256_unpackhi_epi64(t1, t3);
+    let u4 = _mm256_unpacklo_epi64(t4, t6);
+    let u5 = _mm256_unpackhi_epi64(t4, t6);
+    let u6 = _mm256_unpacklo_epi64(t5, t7);
+    let u7 = _mm256_unpackhi_epi64(t5, t7);
+    [
+        _mm256_permute2x128_si256::<0x20>(u0, u4),
+        _mm256_permute2x128_si256::<0x20>(u1, u5),
+        _mm256_permute2x128_si256::<0x20>(u2, u6),
+        _mm256_permute2x128_si256::<0x20>(u3, u7),
+        _mm256_permute2x128_si256::<0x31>(u0, u4),
+        _mm256_permute2x128_si256::<0x31>(u1, u5),
+        _mm256_permute2x128_si256::<0x31>(u2, u6),
+        _mm256_permute2x128_si256::<0x31>(u3, u7),
+    ]
+}
+
+#[arcane]
+pub(super) fn transform_4bpp_v3(
+    t: X64V3Token,
+    src: &[u8],
+    dst: &mut [u8],
+    p: super::orient::Plan,
+    order: [u8; 4],
+) {
+    use super::orient::{TILE, swizzle_px};
+
+    let mask = _mm256_loadu_si256(&swizzle_mask(order));
+    let reverse = _mm256_setr_epi32(7, 6, 5, 4, 3, 2, 1, 0);
+    let px = |x: usize, y: usize| -> [u8; 4] {
+        swizzle_px(src[y * p.ss + x * 4..][..4].try_into().unwrap(), order)
+    };
+
+    if !p.transpose {
+        for y in 0..p.h {
+            let srow = &src[p.sy(y) * p.ss..][..p.w * 4];
+            let drow = &mut dst[y * p.ds..][..p.w * 4];
+            let mut x = 0;
+            while x + 8 <= p.w {
+                // Destination pixels x..x+8 come from source pixels sx(x+7)..=sx(x)
+                // (reversed) or x..x+8.
+                let sx = if p.flip_x { p.w - 8 - x } else { x };
+                let s: &[u8; 32] = srow[sx * 4..][..32].try_into().unwrap();
+                let mut v = _mm256_loadu_si256(s);
+                if p.flip_x {
+                    v = _mm256_permutevar8x32_epi32(v, reverse);
+                }
+                let d: &mut [u8; 32] = (&mut drow[x * 4..][..32]).try_into().unwrap();
+                _mm256_storeu_si256(d, _mm256_shuffle_epi8(v, mask));
+                x += 8;
+            }
+            for x in x..p.w {
+                drow[x * 4..][..4].copy_from_slice(&px(p.sx(x), p.sy(y)));
+            }
+        }
+        return;
+    }
+
+    // Output is `h` wide and `w` tall: output (x, y) reads source (sx(y), sy(x)).
+    // Each 8×8 block loads eight source rows, transposes them, and stores
+    // eight output rows; tiles keep the strided source rows in cache.
+    let (ow, oh) = (p.h, p.w);
+    for y0 in (0..oh).step_by(TILE) {
+        let y1 = (y0 + TILE).min(oh);
+        for x0 in (0..ow).step_by(TILE) {
+            let x1 = (x0 + TILE).min(ow);
+            let mut y = y0;
+            while y + 8 <= y1 {
+                let sx = if p.flip_x { p.w - 8 - y } else { y };
+                let mut x = x0;
+                while x + 8 <= x1 {
+                    let rows: [__m256i; 8] = core::array::from_fn(|k| {
+                        let s: &[u8; 32] =
+                            src[p.sy(x + k) * p.ss + sx * 4..][..32].try_into().unwrap();
+                        _mm256_loadu_si256(s)
+                    });
+                    let cols = transpose_8x8_v3(t, rows);
+                    for (k, c) in cols.into_iter().enumerate() {
+                        let oy = if p.flip_x { y + 7 - k } else { y + k };
+                        let d: &mut [u8; 32] =
+                            (&mut dst[oy * p.ds + x * 4..][..32]).try_into().unwrap();
+                        _mm256_storeu_si256(d, _mm256_shuffle_epi8(c, mask));
+                    }
+                    x += 8;
+                }
+                for yy in y..y + 8 {
+                    for xx in x..x1 {
+                        dst[yy * p.ds + xx * 4..][..4].copy_from_slice(&px(p.sx(yy), p.sy(xx)));
+                    }
+                }
+                y += 8;
+            }
+            for yy in y..y1 {
+                for xx in x0..x1 {
+                    dst[yy * p.ds + xx * 4..][..4].copy_from_slice(&px(p.sx(yy), p.sy(xx)));
+                }
+            }
+        }
+    }
+}
+
 // ===========================================================================
 // Experimental: depth, luma, premul (feature = "experimental")
 // ===========================================================================
@@ -1107,38 +1466,74 @@ pub(super) fn gray_alpha_to_4bpp_alpha_first_strided_v3(
 mod experimental {
     use archmage::prelude::*;
 
+    use super::BSWAP16_MASK_AVX;
+    use crate::bytes::quantize::PalettePlanes;
+
     // -----------------------------------------------------------------------
     // Depth conversions — AVX2 rite row implementations
     // -----------------------------------------------------------------------
+    //
+    // u16 kernels take `BE`: false = native-endian u16, true = big-endian.
+    // The swap is one pshufb on the loaded/stored vector.
+
+    #[inline(always)]
+    fn read_u16<const BE: bool>(b: &[u8]) -> u16 {
+        if BE {
+            u16::from_be_bytes([b[0], b[1]])
+        } else {
+            u16::from_ne_bytes([b[0], b[1]])
+        }
+    }
+
+    #[inline(always)]
+    fn u16_bytes<const BE: bool>(v: u16) -> [u8; 2] {
+        if BE { v.to_be_bytes() } else { v.to_ne_bytes() }
+    }
 
     #[rite]
-    pub(in crate::bytes) fn convert_u8_to_u16_row_v3(_t: X64V3Token, src: &[u8], dst: &mut [u8]) {
+    pub(in crate::bytes) fn convert_u8_to_u16_row_v3<const BE: bool>(
+        _t: X64V3Token,
+        src: &[u8],
+        dst: &mut [u8],
+    ) {
         let mul257 = _mm256_set1_epi16(257);
+        let bswap = _mm256_loadu_si256(&BSWAP16_MASK_AVX);
         let n = src.len();
         let mut i = 0;
         while i + 16 <= n {
             let s: &[u8; 16] = src[i..i + 16].try_into().unwrap();
             let v = _mm_loadu_si128(s);
             let wide = _mm256_cvtepu8_epi16(v);
-            let result = _mm256_mullo_epi16(wide, mul257);
+            let mut result = _mm256_mullo_epi16(wide, mul257);
+            if BE {
+                result = _mm256_shuffle_epi8(result, bswap);
+            }
             let d: &mut [u8; 32] = (&mut dst[i * 2..i * 2 + 32]).try_into().unwrap();
             _mm256_storeu_si256(d, result);
             i += 16;
         }
         for j in i..n {
-            dst[j * 2..j * 2 + 2].copy_from_slice(&((src[j] as u16 * 257).to_ne_bytes()));
+            dst[j * 2..j * 2 + 2].copy_from_slice(&u16_bytes::<BE>(src[j] as u16 * 257));
         }
     }
 
     #[rite]
-    pub(in crate::bytes) fn convert_u16_to_u8_row_v3(_t: X64V3Token, src: &[u8], dst: &mut [u8]) {
+    pub(in crate::bytes) fn convert_u16_to_u8_row_v3<const BE: bool>(
+        _t: X64V3Token,
+        src: &[u8],
+        dst: &mut [u8],
+    ) {
         let mul255 = _mm256_set1_epi32(255);
         let add_half = _mm256_set1_epi32(32768);
+        let bswap = _mm256_castsi256_si128(_mm256_loadu_si256(&BSWAP16_MASK_AVX));
         let n = src.len() / 2;
         let mut i = 0;
         while i + 8 <= n {
             let s: &[u8; 16] = src[i * 2..i * 2 + 16].try_into().unwrap();
-            let v = _mm_loadu_si128(s);
+            let mut v = _mm_loadu_si128(s);
+            if BE {
+                v = _mm_shuffle_epi8(v, bswap);
+            }
             let wide = _mm256_cvtepu16_epi32(v);
             let prod = _mm256_add_epi32(_mm256_mullo_epi32(wide, mul255), add_half);
             let shifted = _mm256_srli_epi32::<16>(prod);
@@ -1153,7 +1548,7 @@ mod experimental {
             i += 8;
         }
         for j in i..n {
-            let v = u16::from_ne_bytes([src[j * 2], src[j * 2 + 1]]);
+            let v = read_u16::<BE>(&src[j * 2..]);
             dst[j] = ((v as u32 * 255 + 32768) >> 16) as u8;
         }
     }
@@ -1211,13 +1606,21 @@ mod experimental {
     }
 
     #[rite]
-    pub(in crate::bytes) fn convert_u16_to_f32_row_v3(_t: X64V3Token, src: &[u8], dst: &mut [u8]) {
+    pub(in crate::bytes) fn convert_u16_to_f32_row_v3<const BE: bool>(
+        _t: X64V3Token,
+        src: &[u8],
+        dst: &mut [u8],
+    ) {
         let scale = _mm256_set1_ps(1.0 / 65535.0);
+        let bswap = _mm256_castsi256_si128(_mm256_loadu_si256(&BSWAP16_MASK_AVX));
         let n = src.len() / 2;
         let mut i = 0;
         while i + 8 <= n {
             let s: &[u8; 16] = src[i * 2..i * 2 + 16].try_into().unwrap();
-            let v = _mm_loadu_si128(s);
+            let mut v = _mm_loadu_si128(s);
+            if BE {
+                v = _mm_shuffle_epi8(v, bswap);
+            }
             let wide32 = _mm256_cvtepu16_epi32(v);
             let floats = _mm256_cvtepi32_ps(wide32);
             let result = _mm256_mul_ps(floats, scale);
@@ -1226,14 +1629,19 @@ mod experimental {
             i += 8;
         }
         for j in i..n {
-            let v = u16::from_ne_bytes([src[j * 2], src[j * 2 + 1]]);
+            let v = read_u16::<BE>(&src[j * 2..]);
             dst[j * 4..j * 4 + 4].copy_from_slice(&(v as f32 / 65535.0).to_ne_bytes());
         }
     }
 
     #[rite]
-    pub(in crate::bytes) fn convert_f32_to_u16_row_v3(_t: X64V3Token, src: &[u8], dst: &mut [u8]) {
+    pub(in crate::bytes) fn convert_f32_to_u16_row_v3<const BE: bool>(
+        _t: X64V3Token,
+        src: &[u8],
+        dst: &mut [u8],
+    ) {
         let scale = _mm256_set1_ps(65535.0);
+        let bswap = _mm256_loadu_si256(&BSWAP16_MASK_AVX);
         let half = _mm256_set1_ps(0.5);
         let zero = _mm256_setzero_ps();
         let one = _mm256_set1_ps(1.0);
@@ -1246,7 +1654,10 @@ mod experimental {
             let scaled = _mm256_add_ps(_mm256_mul_ps(clamped, scale), half);
             let ints = _mm256_cvttps_epi32(scaled);
             let packed16 = _mm256_packus_epi32(ints, ints);
-            let perm = _mm256_permute4x64_epi64::<0b00_00_10_00>(packed16);
+            let mut perm = _mm256_permute4x64_epi64::<0b00_00_10_00>(packed16);
+            if BE {
+                perm = _mm256_shuffle_epi8(perm, bswap);
+            }
             let mut tmp = [0u8; 32];
             _mm256_storeu_si256(&mut tmp, perm);
             dst[i * 2..i * 2 + 16].copy_from_slice(&tmp[..16]);
@@ -1256,7 +1667,7 @@ mod experimental {
             let v =
                 f32::from_ne_bytes([src[j * 4], src[j * 4 + 1], src[j * 4 + 2], src[j * 4 + 3]]);
             let u16_val = (v.clamp(0.0, 1.0) * 65535.0 + 0.5) as u16;
-            dst[j * 2..j * 2 + 2].copy_from_slice(&u16_val.to_ne_bytes());
+            dst[j * 2..j * 2 + 2].copy_from_slice(&u16_bytes::<BE>(u16_val));
         }
     }
 
@@ -1629,11 +2040,11 @@ mod experimental {
     // Depth conversion arcane contiguous wrappers
     #[arcane]
     pub(in crate::bytes) fn convert_u8_to_u16_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
-        convert_u8_to_u16_row_v3(t, s, d);
+        convert_u8_to_u16_row_v3::<false>(t, s, d);
     }
     #[arcane]
     pub(in crate::bytes) fn convert_u16_to_u8_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
-        convert_u16_to_u8_row_v3(t, s, d);
+        convert_u16_to_u8_row_v3::<false>(t, s, d);
     }
     #[arcane]
     pub(in crate::bytes) fn convert_u8_to_f32_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
@@ -1645,11 +2056,11 @@ mod experimental {
     }
     #[arcane]
     pub(in crate::bytes) fn convert_u16_to_f32_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
-        convert_u16_to_f32_row_v3(t, s, d);
+        convert_u16_to_f32_row_v3::<false>(t, s, d);
     }
     #[arcane]
     pub(in crate::bytes) fn convert_f32_to_u16_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
-        convert_f32_to_u16_row_v3(t, s, d);
+        convert_f32_to_u16_row_v3::<false>(t, s, d);
     }
 
     // Depth conversion strided wrappers
@@ -1664,7 +2075,7 @@ mod experimental {
         ds: usize,
     ) {
         for y in 0..h {
-            convert_u8_to_u16_row_v3(t, &src[y * ss..][..w], &mut dst[y * ds..][..w * 2]);
+            convert_u8_to_u16_row_v3::<false>(t, &src[y * ss..][..w], &mut dst[y * ds..][..w * 2]);
         }
     }
     #[arcane]
@@ -1678,7 +2089,7 @@ mod experimental {
         ds: usize,
     ) {
         for y in 0..h {
-            convert_u16_to_u8_row_v3(t, &src[y * ss..][..w * 2], &mut dst[y * ds..][..w]);
+            convert_u16_to_u8_row_v3::<false>(t, &src[y * ss..][..w * 2], &mut dst[y * ds..][..w]);
         }
     }
     #[arcane]
@@ -1720,7 +2131,11 @@ mod experimental {
         ds: usize,
     ) {
         for y in 0..h {
-            convert_u16_to_f32_row_v3(t, &src[y * ss..][..w * 2], &mut dst[y * ds..][..w * 4]);
+            convert_u16_to_f32_row_v3::<false>(
+                t,
+                &src[y * ss..][..w * 2],
+                &mut dst[y * ds..][..w * 4],
+            );
         }
     }
     #[arcane]
@@ -1734,7 +2149,166 @@ mod experimental {
         ds: usize,
     ) {
         for y in 0..h {
-            convert_f32_to_u16_row_v3(t, &src[y * ss..][..w * 4], &mut dst[y * ds..][..w * 2]);
+            convert_f32_to_u16_row_v3::<false>(
+                t,
+                &src[y * ss..][..w * 4],
+                &mut dst[y * ds..][..w * 2],
+            );
+        }
+    }
+
+    // Big-endian u16 depth wrappers (same row kernels, BE = true)
+    #[arcane]
+    pub(in crate::bytes) fn convert_u8_to_u16be_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
+        convert_u8_to_u16_row_v3::<true>(t, s, d);
+    }
+    #[arcane]
+    pub(in crate::bytes) fn convert_u16be_to_u8_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
+        convert_u16_to_u8_row_v3::<true>(t, s, d);
+    }
+    #[arcane]
+    pub(in crate::bytes) fn convert_u16be_to_f32_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
+        convert_u16_to_f32_row_v3::<true>(t, s, d);
+    }
+    #[arcane]
+    pub(in crate::bytes) fn convert_f32_to_u16be_impl_v3(t: X64V3Token, s: &[u8], d: &mut [u8]) {
+        convert_f32_to_u16_row_v3::<true>(t, s, d);
+    }
+    #[arcane]
+    pub(in crate::bytes) fn convert_u8_to_u16be_strided_v3(
+        t: X64V3Token,
+        src: &[u8],
+        dst: &mut [u8],
+        w: usize,
+        h: usize,
+        ss: usize,
+        ds: usize,
+    ) {
+        for y in 0..h {
+            convert_u8_to_u16_row_v3::<true>(t, &src[y * ss..][..w], &mut dst[y * ds..][..w * 2]);
+        }
+    }
+    #[arcane]
+    pub(in crate::bytes) fn convert_u16be_to_u8_strided_v3(
+        t: X64V3Token,
+        src: &[u8],
+        dst: &mut [u8],
+        w: usize,
+        h: usize,
+        ss: usize,
+        ds: usize,
+    ) {
+        for y in 0..h {
+            convert_u16_to_u8_row_v3::<true>(t, &src[y * ss..][..w * 2], &mut dst[y * ds..][..w]);
+        }
+    }
+    #[arcane]
+    pub(in crate::bytes) fn convert_u16be_to_f32_strided_v3(
+        t: X64V3Token,
+        src: &[u8],
+        dst: &mut [u8],
+        w: usize,
+        h: usize,
+        ss: usize,
+        ds: usize,
+    ) {
+        for y in 0..h {
+            convert_u16_to_f32_row_v3::<true>(
+                t,
+                &src[y * ss..][..w * 2],
+                &mut dst[y * ds..][..w * 4],
+            );
+        }
+    }
+    #[arcane]
+    pub(in crate::bytes) fn convert_f32_to_u16be_strided_v3(
+        t: X64V3Token,
+        src: &[u8],
+        dst: &mut [u8],
+        w: usize,
+        h: usize,
+        ss: usize,
+        ds: usize,
+    ) {
+        for y in 0..h {
+            convert_f32_to_u16_row_v3::<true>(
+                t,
+                &src[y * ss..][..w * 4],
+                &mut dst[y * ds..][..w * 2],
+            );
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Palette mapping — nearest entry, 8 entries per step
+    // -----------------------------------------------------------------------
+    //
+    // Same key as the scalar search (`dist << 8 | index`), so ties resolve to
+    // the lowest index and results match bit for bit.
+
+    #[rite]
+    pub(in crate::bytes) fn rgba_to_indexed_row_v3(
+        _token: X64V3Token,
+        src: &[u8],
+        dst: &mut [u8],
+        pal: &PalettePlanes,
+    ) {
+        let lanes = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+        for (s, d) in src.chunks_exact(4).zip(dst.iter_mut()) {
+            let px = [
+                _mm256_set1_epi32(s[0] as i32),
+                _mm256_set1_epi32(s[1] as i32),
+                _mm256_set1_epi32(s[2] as i32),
+                _mm256_set1_epi32(s[3] as i32),
+            ];
+            let mut best = _mm256_set1_epi32(i32::MAX);
+            let mut j = 0;
+            while j < pal.n {
+                let mut dist = _mm256_setzero_si256();
+                for (plane, &p) in pal.c.iter().zip(&px) {
+                    let entries: &[i32; 8] = plane[j..j + 8].try_into().unwrap();
+                    let diff = _mm256_sub_epi32(p, _mm256_loadu_si256(entries));
+                    dist = _mm256_add_epi32(dist, _mm256_mullo_epi32(diff, diff));
+                }
+                let index = _mm256_add_epi32(lanes, _mm256_set1_epi32(j as i32));
+                let key = _mm256_or_si256(_mm256_slli_epi32::<8>(dist), index);
+                best = _mm256_min_epi32(best, key);
+                j += 8;
+            }
+            let m = _mm_min_epi32(
+                _mm256_castsi256_si128(best),
+                _mm256_extracti128_si256::<1>(best),
+            );
+            let m = _mm_min_epi32(m, _mm_shuffle_epi32::<0b01_00_11_10>(m));
+            let m = _mm_min_epi32(m, _mm_shuffle_epi32::<0b10_11_00_01>(m));
+            *d = (_mm_cvtsi128_si32(m) & 0xFF) as u8;
+        }
+    }
+
+    #[arcane]
+    pub(in crate::bytes) fn rgba_to_indexed_impl_v3(
+        t: X64V3Token,
+        s: &[u8],
+        d: &mut [u8],
+        pal: &PalettePlanes,
+    ) {
+        rgba_to_indexed_row_v3(t, s, d, pal);
+    }
+
+    #[arcane]
+    #[allow(clippy::too_many_arguments)]
+    pub(in crate::bytes) fn rgba_to_indexed_strided_v3(
+        t: X64V3Token,
+        src: &[u8],
+        dst: &mut [u8],
+        w: usize,
+        h: usize,
+        ss: usize,
+        ds: usize,
+        pal: &PalettePlanes,
+    ) {
+        for y in 0..h {
+            rgba_to_indexed_row_v3(t, &src[y * ss..][..w * 4], &mut dst[y * ds..][..w], pal);
         }
     }
 }
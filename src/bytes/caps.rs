@@ -0,0 +1,239 @@
+//! Conversion capability and cost queries.
+//!
+//! [`row_capability`] reports, for a [`PixelLayout`] pair and a
+//! [`SimdTier`], whether [`RowConverter`] has a kernel, what kind of code it
+//! runs, and a relative per-pixel cost. Everything is `const`, so tables of
+//! candidate output formats can be ranked at compile time for a fixed tier,
+//! or at startup with [`SimdTier::detect`].
+//!
+//! ```rust
+//! use garb::bytes::{row_capability, KernelKind, PixelLayout, SimdTier};
+//!
+//! // On AArch64, a 4→4 swizzle has a NEON kernel; a 3→4 expansion doesn't yet.
+//! let shuffle = row_capability(PixelLayout::Bgra, PixelLayout::Rgba, SimdTier::Neon).unwrap();
+//! let expand = row_capability(PixelLayout::Rgb, PixelLayout::Rgba, SimdTier::Neon).unwrap();
+//! assert_eq!(shuffle.kind, KernelKind::Simd);
+//! assert_eq!(expand.kind, KernelKind::Scalar);
+//! assert!(shuffle.cost < expand.cost);
+//!
+//! // RGB input costs the same whichever 4-byte order the surface wants.
+//! let to_bgra = row_capability(PixelLayout::Rgb, PixelLayout::Bgra, SimdTier::Neon).unwrap();
+//! assert_eq!(to_bgra.cost, expand.cost);
+//! ```
+
+use super::*;
+
+/// Kind of code a conversion pass runs, fastest first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum KernelKind {
+    /// Plain row copy.
+    Copy,
+    /// Hand-written SIMD kernel for the tier.
+    Simd,
+    /// Scalar loop compiled per tier (`#[autoversion]`) and left to the
+    /// compiler's vectorizer.
+    Autovectorized,
+    /// Portable scalar loop.
+    Scalar,
+}
+
+impl KernelKind {
+    /// Cost multiplier per byte moved: 1 for copies and SIMD, 2 for
+    /// autovectorized, 4 for scalar.
+    pub const fn weight(self) -> u32 {
+        match self {
+            Self::Copy | Self::Simd => 1,
+            Self::Autovectorized => 2,
+            Self::Scalar => 4,
+        }
+    }
+}
+
+/// How a conversion runs on one tier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Capability {
+    /// Slowest kind among the passes.
+    pub kind: KernelKind,
+    /// Passes over the data; more than one means a chained conversion.
+    pub passes: u8,
+    /// Source bytes per pixel.
+    pub src_bytes_per_pixel: usize,
+    /// Destination bytes per pixel.
+    pub dst_bytes_per_pixel: usize,
+    /// Relative cost per pixel: bytes read and written by each pass times
+    /// its [`KernelKind::weight`], summed. Only meaningful for comparisons.
+    pub cost: u32,
+}
+
+impl Capability {
+    /// One pass of `kind` from `src_bpp` to `dst_bpp` bytes per pixel.
+    pub const fn pass(kind: KernelKind, src_bpp: usize, dst_bpp: usize) -> Self {
+        Self {
+            kind,
+            passes: 1,
+            src_bytes_per_pixel: src_bpp,
+            dst_bytes_per_pixel: dst_bpp,
+            cost: (src_bpp + dst_bpp) as u32 * kind.weight(),
+        }
+    }
+
+    /// `self` followed by `next` (whose source is `self`'s destination).
+    pub const fn then(self, next: Self) -> Self {
+        Self {
+            kind: if next.kind as u8 > self.kind as u8 {
+                next.kind
+            } else {
+                self.kind
+            },
+            passes: self.passes + next.passes,
+            src_bytes_per_pixel: self.src_bytes_per_pixel,
+            dst_bytes_per_pixel: next.dst_bytes_per_pixel,
+            cost: self.cost + next.cost,
+        }
+    }
+
+    /// A single pass.
+    pub const fn is_direct(self) -> bool {
+        self.passes == 1
+    }
+}
+
+/// Kind for a kernel dispatched to `tiers` (a [`Kernels`] method's list).
+const fn simd(tier: SimdTier, tiers: &[SimdTier]) -> KernelKind {
+    if let SimdTier::Scalar = tier {
+        return KernelKind::Scalar;
+    }
+    let mut i = 0;
+    while i < tiers.len() {
+        if tiers[i] as u8 == tier as u8 {
+            return KernelKind::Simd;
+        }
+        i += 1;
+    }
+    KernelKind::Scalar
+}
+
+/// Kind for `#[autoversion(v3, neon, wasm128)]` loops.
+#[cfg(feature = "experimental")]
+pub(crate) const fn autoversion(tier: SimdTier) -> KernelKind {
+    match tier {
+        SimdTier::Scalar => KernelKind::Scalar,
+        _ => KernelKind::Autovectorized,
+    }
+}
+
+/// What [`RowConverter::new(src, dst, _)`](RowConverter::new) runs on
+/// `tier`, or `None` if it has no kernel for the pair.
+pub const fn row_capability(
+    src: PixelLayout,
+    dst: PixelLayout,
+    tier: SimdTier,
+) -> Option<Capability> {
+    let kind = match row::select(src, dst) {
+        None => return None,
+        Some(None) => KernelKind::Copy,
+        Some(Some((_, tiers))) => simd(tier, tiers),
+    };
+    Some(Capability::pass(
+        kind,
+        src.bytes_per_pixel(),
+        dst.bytes_per_pixel(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [PixelLayout; 8] = [
+        PixelLayout::Rgba,
+        PixelLayout::Bgra,
+        PixelLayout::Argb,
+        PixelLayout::Abgr,
+        PixelLayout::Rgb,
+        PixelLayout::Bgr,
+        PixelLayout::Gray,
+        PixelLayout::GrayAlpha,
+    ];
+
+    #[test]
+    fn row_capability_matches_row_converter() {
+        for s in ALL {
+            for d in ALL {
+                let cap = row_capability(s, d, SimdTier::V3);
+                assert_eq!(
+                    cap.is_some(),
+                    RowConverter::new(s, d, 1).is_some(),
+                    "{s:?} -> {d:?}"
+                );
+                if let Some(c) = cap {
+                    assert!(c.is_direct());
+                    assert_eq!(c.src_bytes_per_pixel, s.bytes_per_pixel());
+                    assert_eq!(
+                        row_capability(s, d, SimdTier::Scalar).unwrap().kind,
+                        if s == d {
+                            KernelKind::Copy
+                        } else {
+                            KernelKind::Scalar
+                        }
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn row_capability_kind_per_tier() {
+        use PixelLayout::*;
+        for s in ALL {
+            for d in ALL {
+                let Some(v3) = row_capability(s, d, SimdTier::V3) else {
+                    continue;
+                };
+                let kind = |tier| row_capability(s, d, tier).unwrap().kind;
+                if s == d {
+                    assert_eq!(v3.kind, KernelKind::Copy);
+                    continue;
+                }
+                // Every row kernel has AVX2 and SIMD128 forms; NEON covers
+                // the 4 → 4 byte shuffles and gray expansion only.
+                let neon = matches!(
+                    (s, d),
+                    (
+                        Rgba | Bgra | Argb | Abgr | Gray | GrayAlpha,
+                        Rgba | Bgra | Argb | Abgr
+                    )
+                );
+                assert_eq!(v3.kind, KernelKind::Simd, "{s:?} -> {d:?}");
+                assert_eq!(kind(SimdTier::Wasm128), KernelKind::Simd, "{s:?} -> {d:?}");
+                assert_eq!(
+                    kind(SimdTier::Neon),
+                    if neon {
+                        KernelKind::Simd
+                    } else {
+                        KernelKind::Scalar
+                    },
+                    "{s:?} -> {d:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn chaining_adds_up() {
+        let a = Capability::pass(KernelKind::Simd, 3, 4);
+        let b = Capability::pass(KernelKind::Autovectorized, 4, 2);
+        let c = a.then(b);
+        assert_eq!(
+            (
+                c.kind,
+                c.passes,
+                c.src_bytes_per_pixel,
+                c.dst_bytes_per_pixel
+            ),
+            (KernelKind::Autovectorized, 2, 3, 2)
+        );
+        assert_eq!(c.cost, 7 + 12);
+    }
+}
@@ -0,0 +1,1088 @@
+//! Dithered narrowing conversions (RGB565, RGBA4444, u16 → u8).
+//!
+//! The plain narrowing functions ([`rgba_to_rgb565`](super::rgba_to_rgb565),
+//! [`convert_u16_to_u8`](super::convert_u16_to_u8), …) round each channel to
+//! the nearest level, which bands visibly on smooth gradients. The functions
+//! here trade that banding for fine, stable noise.
+//!
+//! Two families are provided:
+//!
+//! - **Ordered dither** (`_dithered`, `_dithered_strided`): a per-pixel
+//!   threshold from a tiled matrix ([`DitherMatrix`]). Stateless, so rows and
+//!   tiles can be converted independently. [`OrderedDither::with_origin`]
+//!   places the matrix so that separately converted tiles line up without
+//!   seams — pass the tile's position in the full image.
+//! - **Floyd–Steinberg** (`_floyd_steinberg_strided`): error diffusion over a
+//!   whole image. Higher quality but sequential; the caller supplies an `i16`
+//!   scratch row so nothing is allocated.
+//!
+//! Both preserve the endpoints: 0 always maps to 0 and full scale to full
+//! scale, so fully opaque alpha stays opaque.
+
+#![cfg_attr(target_arch = "x86", allow(unused_imports))]
+
+use archmage::prelude::*;
+
+use super::{SignedStride, check_copy, check_scratch, check_strided};
+use crate::{Operand, SizeError, SizeErrorKind};
+
+// ===========================================================================
+// Threshold matrices
+// ===========================================================================
+//
+// Each table holds thresholds in [0, 256) that are compared against the
+// fractional part of the scaled value. Bayer ranks r in [0, n²) map to
+// `(r * 256 + 128) / n²` so the thresholds are centred in their bins.
+// The blue-noise table is a 16×16 void-and-cluster rank map (σ = 1.5,
+// toroidal), so it tiles without seams.
+
+#[rustfmt::skip]
+static BAYER4: [u8; 16] = [
+      8, 136,  40, 168,
+    200,  72, 232, 104,
+     56, 184,  24, 152,
+    248, 120, 216,  88,
+];
+
+#[rustfmt::skip]
+static BAYER8: [u8; 64] = [
+      2, 130,  34, 162,  10, 138,  42, 170,
+    194,  66, 226,  98, 202,  74, 234, 106,
+     50, 178,  18, 146,  58, 186,  26, 154,
+    242, 114, 210,  82, 250, 122, 218,  90,
+     14, 142,  46, 174,   6, 134,  38, 166,
+    206,  78, 238, 110, 198,  70, 230, 102,
+     62, 190,  30, 158,  54, 182,  22, 150,
+    254, 126, 222,  94, 246, 118, 214,  86,
+];
+
+#[rustfmt::skip]
+static BLUE_NOISE16: [u8; 256] = [
+    234,  50, 188,  19,  58, 171, 121,  47, 163,   1, 247, 104,  22, 132,  14,  65,
+    209,   8, 118,  97, 240, 205,  23, 228, 138,  64, 123, 170,  72, 224,  99, 149,
+     85, 139, 229, 165,  78, 146, 111,  84, 176, 216,  30, 231, 153, 201,  42, 180,
+     25,  62, 195,  29,  43, 185,   7, 249,  41, 100, 191,  48,  87,   5, 128, 243,
+    221, 152, 101, 253, 130, 220,  59, 200, 156,  12, 136, 112, 255, 174,  69, 109,
+     46, 189,   0,  73, 172,  90, 142, 116,  80, 237, 210,  61, 147,  33, 206, 160,
+     81, 124, 217, 113, 208,  15, 241,  27, 168,  45, 178,  20, 193,  96, 225,  18,
+    242, 164,  60,  35, 157,  53, 181,  68, 223, 105, 125,  83, 236, 131,  55, 141,
+    197,  10, 227, 134, 246,  95, 126, 198, 148,   3, 244, 161,  71,   9, 182, 106,
+     40,  93, 179,  75, 192,   6, 218,  36,  91,  57, 202,  34, 215, 155, 233,  74,
+    252, 120, 150,  24, 110,  63, 166, 119, 232, 183, 133, 103,  49, 117,  31, 167,
+     16, 212,  51, 238, 207, 137, 254,  21,  76, 151,  13, 250, 190,  88, 203, 135,
+    102, 184,  82, 169,  38,  89, 187,  52, 204,  98, 173,  67, 129,   4, 222,  56,
+    230, 144,   2, 127, 226,  11, 154, 114, 239,  39, 219,  28, 235, 145, 175,  77,
+    196,  37, 248,  70, 107, 199,  66, 177,  17, 143, 115, 159,  86,  44, 108,  26,
+    122,  92, 158, 214, 140,  32, 245,  94, 213,  79, 194,  54, 211, 186, 251, 162,
+];
+
+/// Threshold matrix used by ordered dithering.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DitherMatrix {
+    /// 4×4 Bayer matrix. Cheapest, most visible cross-hatch pattern.
+    Bayer4,
+    /// 8×8 Bayer matrix. Finer pattern, 64 threshold levels.
+    #[default]
+    Bayer8,
+    /// 16×16 blue-noise matrix. No regular pattern; best for photographs.
+    BlueNoise16,
+}
+
+impl DitherMatrix {
+    /// Side length of the (square) matrix in pixels.
+    pub const fn size(self) -> usize {
+        match self {
+            DitherMatrix::Bayer4 => 4,
+            DitherMatrix::Bayer8 => 8,
+            DitherMatrix::BlueNoise16 => 16,
+        }
+    }
+
+    fn table(self) -> &'static [u8] {
+        match self {
+            DitherMatrix::Bayer4 => &BAYER4,
+            DitherMatrix::Bayer8 => &BAYER8,
+            DitherMatrix::BlueNoise16 => &BLUE_NOISE16,
+        }
+    }
+}
+
+/// Ordered-dither settings: the matrix and where its origin sits.
+///
+/// The threshold for pixel `(x, y)` of a call is taken from matrix cell
+/// `(self.x + x, self.y + y)`, wrapping. When converting an image in tiles or
+/// row bands, give each call the tile's offset within the full image via
+/// [`with_origin`](Self::with_origin) and the result is identical to a single
+/// whole-image call.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OrderedDither {
+    /// Threshold matrix.
+    pub matrix: DitherMatrix,
+    /// Column of the first pixel within the full image.
+    pub x: usize,
+    /// Row of the first pixel within the full image.
+    pub y: usize,
+}
+
+impl OrderedDither {
+    /// Ordered dither with the matrix anchored at the first pixel.
+    pub const fn new(matrix: DitherMatrix) -> Self {
+        Self { matrix, x: 0, y: 0 }
+    }
+
+    /// Ordered dither for a region whose first pixel is at `(x, y)` in the
+    /// full image.
+    pub const fn with_origin(matrix: DitherMatrix, x: usize, y: usize) -> Self {
+        Self { matrix, x, y }
+    }
+
+    /// Thresholds for row `y` of the call (matrix-width slice, power of two).
+    #[inline(always)]
+    pub(super) fn row(&self, y: usize) -> &'static [u8] {
+        let n = self.matrix.size();
+        &self.matrix.table()[((self.y + y) & (n - 1)) * n..][..n]
+    }
+}
+
+// ===========================================================================
+// Quantizers
+// ===========================================================================
+//
+// Ordered: channels expand back to 8 bits by MSB replication, so the N-bit
+// levels sit at E(k) = k << (8 - N) | k >> (2N - 8), which is not exactly
+// k * 255 / max for N = 5, 6 (for N = 1, 2, 4 replication is exact). For
+// each 8-bit v the tables below hold the level k with E(k) <= v < E(k + 1)
+// in the high byte and the position of v between the two,
+// f = (v - E(k)) * 256 / (E(k + 1) - E(k)), in the low byte. The output
+// rounds up when f > t, with t in [0, 256) from the matrix, so flat areas
+// average to the source level and exact levels (f = 0, including 0 and 255)
+// are never dithered.
+//
+// u16 → u8 does the same against E(k) = k * 257 without a table.
+//
+// Floyd–Steinberg quantizes to nearest with the same rounding as the plain
+// narrowing functions and diffuses the difference to the expanded level.
+
+/// N-bit level `k` expanded to 8 bits by MSB replication.
+const fn expand_level(k: u32, bits: u32) -> u32 {
+    if 8 % bits == 0 {
+        // Widths dividing 8 replicate exactly: 0x55 * k for 2-bit, 0x11 * k for 4-bit.
+        k * (255 / ((1 << bits) - 1))
+    } else {
+        (k << (8 - bits)) | (k >> (2 * bits - 8))
+    }
+}
+
+const fn level_table(bits: u32) -> [u16; 256] {
+    let max = (1u32 << bits) - 1;
+    let mut lut = [0u16; 256];
+    let mut k = 0;
+    while k < max {
+        let lo = expand_level(k, bits);
+        let hi = expand_level(k + 1, bits);
+        let mut v = lo;
+        while v < hi {
+            lut[v as usize] = ((k << 8) | ((v - lo) * 256 / (hi - lo))) as u16;
+            v += 1;
+        }
+        k += 1;
+    }
+    lut[255] = (max << 8) as u16;
+    lut
+}
+
+pub(super) static LEVELS1: [u16; 256] = level_table(1);
+pub(super) static LEVELS2: [u16; 256] = level_table(2);
+pub(super) static LEVELS4: [u16; 256] = level_table(4);
+static LEVELS5: [u16; 256] = level_table(5);
+static LEVELS6: [u16; 256] = level_table(6);
+
+#[inline(always)]
+pub(super) fn ordered_u8(levels: &[u16; 256], v: u8, t: u32) -> u16 {
+    let e = levels[v as usize];
+    (e >> 8) + ((e & 0xFF) as u32 > t) as u16
+}
+
+#[inline(always)]
+fn ordered_u16(v: u16, t: u32) -> u8 {
+    let k = v / 257;
+    let r = (v % 257) as u32;
+    (k + (r * 256 > t * 257) as u16) as u8
+}
+
+/// Nearest N-bit level for an 8-bit value, and that level expanded back to 8 bits.
+#[inline(always)]
+fn nearest_u8(v: i32, bits: u32) -> (u16, i32) {
+    let max = (1u32 << bits) - 1;
+    let q = (v as u32 * max + 128) >> 8;
+    let expanded = (q << (8 - bits)) | (q >> (2 * bits - 8));
+    (q as u16, expanded as i32)
+}
+// ===========================================================================
+// Ordered dither kernels
+// ===========================================================================
+//
+// One row per call; `y` is the row index within the call, combined with the
+// dither origin to pick the matrix row. Strided callers loop over rows.
+
+#[autoversion(v3, neon, wasm128)]
+fn rgba_to_rgb565_dither_row(s: &[u8], d: &mut [u8], dither: OrderedDither, y: usize) {
+    let thr = dither.row(y);
+    let mask = thr.len() - 1;
+    for (i, (s, d)) in s.chunks_exact(4).zip(d.chunks_exact_mut(2)).enumerate() {
+        let t = thr[(dither.x + i) & mask] as u32;
+        let r5 = ordered_u8(&LEVELS5, s[0], t);
+        let g6 = ordered_u8(&LEVELS6, s[1], t);
+        let b5 = ordered_u8(&LEVELS5, s[2], t);
+        d.copy_from_slice(&((r5 << 11) | (g6 << 5) | b5).to_le_bytes());
+    }
+}
+
+#[autoversion(v3, neon, wasm128)]
+fn bgra_to_rgb565_dither_row(s: &[u8], d: &mut [u8], dither: OrderedDither, y: usize) {
+    let thr = dither.row(y);
+    let mask = thr.len() - 1;
+    for (i, (s, d)) in s.chunks_exact(4).zip(d.chunks_exact_mut(2)).enumerate() {
+        let t = thr[(dither.x + i) & mask] as u32;
+        let r5 = ordered_u8(&LEVELS5, s[2], t);
+        let g6 = ordered_u8(&LEVELS6, s[1], t);
+        let b5 = ordered_u8(&LEVELS5, s[0], t);
+        d.copy_from_slice(&((r5 << 11) | (g6 << 5) | b5).to_le_bytes());
+    }
+}
+
+#[autoversion(v3, neon, wasm128)]
+fn rgba_to_rgba4444_dither_row(s: &[u8], d: &mut [u8], dither: OrderedDither, y: usize) {
+    let thr = dither.row(y);
+    let mask = thr.len() - 1;
+    for (i, (s, d)) in s.chunks_exact(4).zip(d.chunks_exact_mut(2)).enumerate() {
+        let t = thr[(dither.x + i) & mask] as u32;
+        let r4 = ordered_u8(&LEVELS4, s[0], t);
+        let g4 = ordered_u8(&LEVELS4, s[1], t);
+        let b4 = ordered_u8(&LEVELS4, s[2], t);
+        let a4 = ordered_u8(&LEVELS4, s[3], t);
+        d.copy_from_slice(&((r4 << 12) | (g4 << 8) | (b4 << 4) | a4).to_le_bytes());
+    }
+}
+
+#[autoversion(v3, neon, wasm128)]
+fn bgra_to_rgba4444_dither_row(s: &[u8], d: &mut [u8], dither: OrderedDither, y: usize) {
+    let thr = dither.row(y);
+    let mask = thr.len() - 1;
+    for (i, (s, d)) in s.chunks_exact(4).zip(d.chunks_exact_mut(2)).enumerate() {
+        let t = thr[(dither.x + i) & mask] as u32;
+        let r4 = ordered_u8(&LEVELS4, s[2], t);
+        let g4 = ordered_u8(&LEVELS4, s[1], t);
+        let b4 = ordered_u8(&LEVELS4, s[0], t);
+        let a4 = ordered_u8(&LEVELS4, s[3], t);
+        d.copy_from_slice(&((r4 << 12) | (g4 << 8) | (b4 << 4) | a4).to_le_bytes());
+    }
+}
+
+#[autoversion(v3, neon, wasm128)]
+fn u16_to_u8_dither_row(s: &[u8], d: &mut [u8], channels: usize, dither: OrderedDither, y: usize) {
+    let thr = dither.row(y);
+    let mask = thr.len() - 1;
+    for (i, (s, d)) in s.chunks_exact(2).zip(d.iter_mut()).enumerate() {
+        let t = thr[(dither.x + i / channels) & mask] as u32;
+        *d = ordered_u16(u16::from_ne_bytes([s[0], s[1]]), t);
+    }
+}
+
+// ===========================================================================
+// Floyd–Steinberg engine
+// ===========================================================================
+//
+// Classic 7/16 right, 3/16 below-left, 5/16 below, 1/16 below-right,
+// left-to-right on every row. Errors are kept in 1/16 units; the next row's
+// error lives in one `i16` row (`err`), updated one pixel behind the cursor
+// so it is read before being overwritten.
+//
+// `quantize(x, y, v)` receives the diffused error for pixel (x, y) in `v`
+// (already divided by 16), adds it to the source, writes the output and
+// leaves the quantization error (source + error − reconstructed level) in `v`.
+
+pub(super) fn error_diffuse<const C: usize>(
+    w: usize,
+    h: usize,
+    err: &mut [i16],
+    mut quantize: impl FnMut(usize, usize, &mut [i32; C]),
+) {
+    let err = &mut err[..w * C];
+    err.fill(0);
+    for y in 0..h {
+        let mut right = [0i32; C];
+        let mut below_left = [0i32; C];
+        let mut below = [0i32; C];
+        for x in 0..w {
+            let mut v = [0i32; C];
+            for c in 0..C {
+                v[c] = (err[x * C + c] as i32 + right[c] + 8) >> 4;
+            }
+            quantize(x, y, &mut v);
+            for c in 0..C {
+                let e = v[c];
+                right[c] = 7 * e;
+                if x > 0 {
+                    err[(x - 1) * C + c] = (below_left[c] + 3 * e) as i16;
+                }
+                below_left[c] = below[c] + 5 * e;
+                below[c] = e;
+            }
+        }
+        for c in 0..C {
+            err[(w - 1) * C + c] = below_left[c] as i16;
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn to_rgb565_fs(
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: SignedStride,
+    ds: SignedStride,
+    err: &mut [i16],
+    order: [usize; 3],
+) {
+    error_diffuse::<3>(w, h, err, |x, y, v| {
+        let s = &src[ss.row(y) + x * 4..][..4];
+        let mut q = [0u16; 3];
+        for c in 0..3 {
+            let bits = if c == 1 { 6 } else { 5 };
+            let val = (s[order[c]] as i32 + v[c]).clamp(0, 255);
+            let (level, expanded) = nearest_u8(val, bits);
+            q[c] = level;
+            v[c] = val - expanded;
+        }
+        dst[ds.row(y) + x * 2..][..2]
+            .copy_from_slice(&((q[0] << 11) | (q[1] << 5) | q[2]).to_le_bytes());
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn to_rgba4444_fs(
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: SignedStride,
+    ds: SignedStride,
+    err: &mut [i16],
+    order: [usize; 4],
+) {
+    error_diffuse::<4>(w, h, err, |x, y, v| {
+        let s = &src[ss.row(y) + x * 4..][..4];
+        let mut q = [0u16; 4];
+        for c in 0..4 {
+            let val = (s[order[c]] as i32 + v[c]).clamp(0, 255);
+            let (level, expanded) = nearest_u8(val, 4);
+            q[c] = level;
+            v[c] = val - expanded;
+        }
+        dst[ds.row(y) + x * 2..][..2]
+            .copy_from_slice(&((q[0] << 12) | (q[1] << 8) | (q[2] << 4) | q[3]).to_le_bytes());
+    });
+}
+
+pub(super) fn u16_to_u8_fs<const C: usize>(
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: SignedStride,
+    ds: SignedStride,
+    err: &mut [i16],
+) {
+    error_diffuse::<C>(w, h, err, |x, y, v| {
+        let s = &src[ss.row(y) + x * C * 2..][..C * 2];
+        let d = &mut dst[ds.row(y) + x * C..][..C];
+        for c in 0..C {
+            let raw = u16::from_ne_bytes([s[c * 2], s[c * 2 + 1]]) as i32;
+            let val = (raw + v[c]).clamp(0, 65535);
+            let q = (val as u32 * 255 + 32768) >> 16;
+            d[c] = q as u8;
+            v[c] = val - (q * 257) as i32;
+        }
+    });
+}
+
+// ===========================================================================
+// Public API — ordered dither
+// ===========================================================================
+
+/// RGBA (4 bytes/px) → RGB565 (little-endian u16, 2 bytes/px) with ordered dither.
+/// Alpha dropped.
+///
+/// Same bit layout as [`rgba_to_rgb565`](super::rgba_to_rgb565); the matrix
+/// origin is `(dither.x, dither.y)`.
+pub fn rgba_to_rgb565_dithered(
+    src: &[u8],
+    dst: &mut [u8],
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 2)?;
+    rgba_to_rgb565_dither_row(src, dst, dither, 0);
+    Ok(())
+}
+
+/// BGRA (4 bytes/px) → RGB565 (little-endian u16, 2 bytes/px) with ordered dither.
+/// Alpha dropped.
+pub fn bgra_to_rgb565_dithered(
+    src: &[u8],
+    dst: &mut [u8],
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 2)?;
+    bgra_to_rgb565_dither_row(src, dst, dither, 0);
+    Ok(())
+}
+
+/// RGBA (4 bytes/px) → RGBA4444 (little-endian u16, 2 bytes/px) with ordered dither.
+///
+/// Alpha is dithered too; 0 and 255 are preserved exactly.
+pub fn rgba_to_rgba4444_dithered(
+    src: &[u8],
+    dst: &mut [u8],
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 2)?;
+    rgba_to_rgba4444_dither_row(src, dst, dither, 0);
+    Ok(())
+}
+
+/// BGRA (4 bytes/px) → RGBA4444 (little-endian u16, 2 bytes/px) with ordered dither.
+///
+/// Alpha is dithered too; 0 and 255 are preserved exactly.
+pub fn bgra_to_rgba4444_dithered(
+    src: &[u8],
+    dst: &mut [u8],
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 2)?;
+    bgra_to_rgba4444_dither_row(src, dst, dither, 0);
+    Ok(())
+}
+
+/// Convert u16 elements to u8 elements with ordered dither.
+///
+/// `src` holds native-endian u16 values, `channels` per pixel (1–4); all
+/// channels of a pixel share one threshold. The element count must be a
+/// multiple of `channels`.
+pub fn convert_u16_to_u8_dithered(
+    src: &[u8],
+    dst: &mut [u8],
+    channels: usize,
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    if !(1..=4).contains(&channels) {
+        return Err(SizeErrorKind::NotPixelAligned.into());
+    }
+    check_copy(src.len(), 2 * channels, dst.len(), channels)?;
+    u16_to_u8_dither_row(src, dst, channels, dither, 0);
+    Ok(())
+}
+
+/// RGBA → RGB565 with ordered dither between strided buffers. Alpha dropped.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn rgba_to_rgb565_dithered_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    for y in 0..height {
+        rgba_to_rgb565_dither_row(
+            &src[y * src_stride..][..width * 4],
+            &mut dst[y * dst_stride..][..width * 2],
+            dither,
+            y,
+        );
+    }
+    Ok(())
+}
+
+/// BGRA → RGB565 with ordered dither between strided buffers. Alpha dropped.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn bgra_to_rgb565_dithered_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    for y in 0..height {
+        bgra_to_rgb565_dither_row(
+            &src[y * src_stride..][..width * 4],
+            &mut dst[y * dst_stride..][..width * 2],
+            dither,
+            y,
+        );
+    }
+    Ok(())
+}
+
+/// RGBA → RGBA4444 with ordered dither between strided buffers.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn rgba_to_rgba4444_dithered_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    for y in 0..height {
+        rgba_to_rgba4444_dither_row(
+            &src[y * src_stride..][..width * 4],
+            &mut dst[y * dst_stride..][..width * 2],
+            dither,
+            y,
+        );
+    }
+    Ok(())
+}
+
+/// BGRA → RGBA4444 with ordered dither between strided buffers.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn bgra_to_rgba4444_dithered_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    for y in 0..height {
+        bgra_to_rgba4444_dither_row(
+            &src[y * src_stride..][..width * 4],
+            &mut dst[y * dst_stride..][..width * 2],
+            dither,
+            y,
+        );
+    }
+    Ok(())
+}
+
+/// Convert u16→u8 with ordered dither between strided buffers.
+///
+/// `width` is element count per row (a multiple of `channels`, 1–4).
+#[allow(clippy::too_many_arguments)]
+pub fn convert_u16_to_u8_dithered_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    channels: usize,
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    if !(1..=4).contains(&channels) || !width.is_multiple_of(channels) {
+        return Err(SizeErrorKind::NotPixelAligned.into());
+    }
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
+    for y in 0..height {
+        u16_to_u8_dither_row(
+            &src[y * src_stride..][..width * 2],
+            &mut dst[y * dst_stride..][..width],
+            channels,
+            dither,
+            y,
+        );
+    }
+    Ok(())
+}
+
+// ===========================================================================
+// Public API — Floyd–Steinberg
+// ===========================================================================
+//
+// `scratch` carries the error for the next row. It must hold at least
+// `width × channels` elements (3 for RGB565, 4 for RGBA4444; u16→u8 takes
+// `width` in elements already); a shorter slice is rejected with `InvalidStride`. Its contents on
+// entry are ignored and on return are unspecified.
+
+/// RGBA → RGB565 with Floyd–Steinberg error diffusion between strided buffers.
+/// Alpha dropped.
+///
+/// `scratch` must hold at least `width * 3` elements.
+#[allow(clippy::too_many_arguments)]
+pub fn rgba_to_rgb565_floyd_steinberg_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    scratch: &mut [i16],
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    check_scratch(scratch.len(), width * 3)?;
+    to_rgb565_fs(
+        src,
+        dst,
+        width,
+        height,
+        SignedStride::top_down(src_stride),
+        SignedStride::top_down(dst_stride),
+        scratch,
+        [0, 1, 2],
+    );
+    Ok(())
+}
+
+/// BGRA → RGB565 with Floyd–Steinberg error diffusion between strided buffers.
+/// Alpha dropped.
+///
+/// `scratch` must hold at least `width * 3` elements.
+#[allow(clippy::too_many_arguments)]
+pub fn bgra_to_rgb565_floyd_steinberg_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    scratch: &mut [i16],
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    check_scratch(scratch.len(), width * 3)?;
+    to_rgb565_fs(
+        src,
+        dst,
+        width,
+        height,
+        SignedStride::top_down(src_stride),
+        SignedStride::top_down(dst_stride),
+        scratch,
+        [2, 1, 0],
+    );
+    Ok(())
+}
+
+/// RGBA → RGBA4444 with Floyd–Steinberg error diffusion between strided buffers.
+///
+/// `scratch` must hold at least `width * 4` elements.
+#[allow(clippy::too_many_arguments)]
+pub fn rgba_to_rgba4444_floyd_steinberg_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    scratch: &mut [i16],
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    check_scratch(scratch.len(), width * 4)?;
+    to_rgba4444_fs(
+        src,
+        dst,
+        width,
+        height,
+        SignedStride::top_down(src_stride),
+        SignedStride::top_down(dst_stride),
+        scratch,
+        [0, 1, 2, 3],
+    );
+    Ok(())
+}
+
+/// BGRA → RGBA4444 with Floyd–Steinberg error diffusion between strided buffers.
+///
+/// `scratch` must hold at least `width * 4` elements.
+#[allow(clippy::too_many_arguments)]
+pub fn bgra_to_rgba4444_floyd_steinberg_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    scratch: &mut [i16],
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    check_scratch(scratch.len(), width * 4)?;
+    to_rgba4444_fs(
+        src,
+        dst,
+        width,
+        height,
+        SignedStride::top_down(src_stride),
+        SignedStride::top_down(dst_stride),
+        scratch,
+        [2, 1, 0, 3],
+    );
+    Ok(())
+}
+
+/// Convert u16→u8 with Floyd–Steinberg error diffusion between strided buffers.
+///
+/// `width` is element count per row (a multiple of `channels`, 1–4), matching
+/// [`convert_u16_to_u8_strided`](super::convert_u16_to_u8_strided). Each
+/// channel diffuses independently. `scratch` must hold at least `width`
+/// elements.
+#[allow(clippy::too_many_arguments)]
+pub fn convert_u16_to_u8_floyd_steinberg_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    channels: usize,
+    scratch: &mut [i16],
+) -> Result<(), SizeError> {
+    if !(1..=4).contains(&channels) || !width.is_multiple_of(channels) {
+        return Err(SizeErrorKind::NotPixelAligned.into());
+    }
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
+    check_scratch(scratch.len(), width)?;
+    let (w, h) = (width / channels, height);
+    let (ss, ds) = (
+        SignedStride::top_down(src_stride),
+        SignedStride::top_down(dst_stride),
+    );
+    match channels {
+        1 => u16_to_u8_fs::<1>(src, dst, w, h, ss, ds, scratch),
+        2 => u16_to_u8_fs::<2>(src, dst, w, h, ss, ds, scratch),
+        3 => u16_to_u8_fs::<3>(src, dst, w, h, ss, ds, scratch),
+        _ => u16_to_u8_fs::<4>(src, dst, w, h, ss, ds, scratch),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn gradient_rgba(w: usize, h: usize) -> Vec<u8> {
+        let mut v = vec![0u8; w * h * 4];
+        for (i, px) in v.chunks_exact_mut(4).enumerate() {
+            let (x, y) = (i % w, i / w);
+            px.copy_from_slice(&[
+                (x * 7 + y) as u8,
+                (x * 3 + y * 5) as u8,
+                (y * 11) as u8,
+                (x ^ y) as u8,
+            ]);
+        }
+        v
+    }
+
+    fn decode565(d: &[u8]) -> [u16; 3] {
+        let v = u16::from_le_bytes([d[0], d[1]]);
+        [v >> 11, (v >> 5) & 0x3F, v & 0x1F]
+    }
+
+    #[test]
+    fn bayer_tables_are_permutations_of_centred_bins() {
+        let mut b4: Vec<u8> = BAYER4.to_vec();
+        b4.sort_unstable();
+        assert_eq!(b4, (0..16).map(|r| (r * 16 + 8) as u8).collect::<Vec<_>>());
+        let mut b8: Vec<u8> = BAYER8.to_vec();
+        b8.sort_unstable();
+        assert_eq!(b8, (0..64).map(|r| (r * 4 + 2) as u8).collect::<Vec<_>>());
+        let mut bn: Vec<u8> = BLUE_NOISE16.to_vec();
+        bn.sort_unstable();
+        assert_eq!(bn, (0..=255).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn endpoints_preserved_all_matrices() {
+        for m in [
+            DitherMatrix::Bayer4,
+            DitherMatrix::Bayer8,
+            DitherMatrix::BlueNoise16,
+        ] {
+            let d = OrderedDither::new(m);
+            let src = [0u8, 0, 0, 0, 255, 255, 255, 255].repeat(16);
+            let mut dst = vec![0u8; src.len() / 2];
+            rgba_to_rgba4444_dithered(&src, &mut dst, d).unwrap();
+            for px in dst.chunks_exact(2).step_by(2) {
+                assert_eq!(px, [0, 0]);
+            }
+            for px in dst.chunks_exact(2).skip(1).step_by(2) {
+                assert_eq!(px, [0xFF, 0xFF]);
+            }
+            rgba_to_rgb565_dithered(&src, &mut dst, d).unwrap();
+            for (i, px) in dst.chunks_exact(2).enumerate() {
+                let expect = if i % 2 == 0 { [0, 0] } else { [0xFF, 0xFF] };
+                assert_eq!(px, expect);
+            }
+            let words: Vec<u8> = [0u16, 65535]
+                .repeat(8)
+                .iter()
+                .flat_map(|v| v.to_ne_bytes())
+                .collect();
+            let mut out = vec![0u8; 16];
+            convert_u16_to_u8_dithered(&words, &mut out, 1, d).unwrap();
+            for (i, &v) in out.iter().enumerate() {
+                assert_eq!(v, if i % 2 == 0 { 0 } else { 255 });
+            }
+        }
+    }
+
+    #[test]
+    fn levels_exactly_representable_are_not_dithered() {
+        // An 8-bit value that is an expanded 5-bit level must stay on that level.
+        for q in 0..32u16 {
+            let v = ((q << 3) | (q >> 2)) as u8;
+            let src = [v, v, v, 255].repeat(64);
+            let mut dst = vec![0u8; 128];
+            rgba_to_rgb565_dithered(
+                &src,
+                &mut dst,
+                OrderedDither::new(DitherMatrix::BlueNoise16),
+            )
+            .unwrap();
+            for px in dst.chunks_exact(2) {
+                let [r, _, b] = decode565(px);
+                assert_eq!(r, q);
+                assert_eq!(r, b);
+            }
+        }
+    }
+
+    #[test]
+    fn level_tables_match_expansion() {
+        for (bits, lut) in [(4u32, &LEVELS4), (5, &LEVELS5), (6, &LEVELS6)] {
+            for v in 0..=255u32 {
+                let k = (lut[v as usize] >> 8) as u32;
+                let e = (k << (8 - bits)) | (k >> (2 * bits - 8));
+                assert!(e <= v, "bits={bits} v={v} k={k}");
+                if v == e {
+                    assert_eq!(lut[v as usize] & 0xFF, 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn flat_area_averages_to_source() {
+        // 100 lies between the 5-bit levels 12 (→ 99) and 13 (→ 107): expect
+        // mostly 12 with enough 13s that the expanded mean comes back to 100.
+        let (w, h) = (16, 16);
+        let src = [100u8, 100, 100, 255].repeat(w * h);
+        let mut dst = vec![0u8; w * h * 2];
+        rgba_to_rgb565_dithered_strided(
+            &src,
+            &mut dst,
+            w,
+            h,
+            w * 4,
+            w * 2,
+            OrderedDither::new(DitherMatrix::BlueNoise16),
+        )
+        .unwrap();
+        let sum: u32 = dst
+            .chunks_exact(2)
+            .map(|p| {
+                let r = decode565(p)[0] as u32;
+                r << 3 | r >> 2
+            })
+            .sum();
+        let mean = sum as f64 / (w * h) as f64;
+        assert!((mean - 100.0).abs() < 0.1, "mean {mean}");
+    }
+
+    #[test]
+    fn origin_makes_tiles_seamless() {
+        let (w, h) = (37, 23);
+        let src = gradient_rgba(w, h);
+        for m in [
+            DitherMatrix::Bayer4,
+            DitherMatrix::Bayer8,
+            DitherMatrix::BlueNoise16,
+        ] {
+            let mut whole = vec![0u8; w * h * 2];
+            rgba_to_rgb565_dithered_strided(
+                &src,
+                &mut whole,
+                w,
+                h,
+                w * 4,
+                w * 2,
+                OrderedDither::new(m),
+            )
+            .unwrap();
+            // Convert a 10×7 tile at (13, 5) on its own.
+            let (tx, ty, tw, th) = (13, 5, 10, 7);
+            let mut tile = vec![0u8; tw * th * 2];
+            rgba_to_rgb565_dithered_strided(
+                &src[ty * w * 4 + tx * 4..],
+                &mut tile,
+                tw,
+                th,
+                w * 4,
+                tw * 2,
+                OrderedDither::with_origin(m, tx, ty),
+            )
+            .unwrap();
+            for y in 0..th {
+                assert_eq!(
+                    &tile[y * tw * 2..][..tw * 2],
+                    &whole[(ty + y) * w * 2 + tx * 2..][..tw * 2]
+                );
+            }
+            // Row-by-row contiguous calls match the strided call too.
+            let mut rows = vec![0u8; w * h * 2];
+            for y in 0..h {
+                rgba_to_rgb565_dithered(
+                    &src[y * w * 4..][..w * 4],
+                    &mut rows[y * w * 2..][..w * 2],
+                    OrderedDither::with_origin(m, 0, y),
+                )
+                .unwrap();
+            }
+            assert_eq!(rows, whole);
+        }
+    }
+
+    #[test]
+    fn bgra_matches_rgba_with_swapped_input() {
+        let (w, h) = (19, 5);
+        let rgba = gradient_rgba(w, h);
+        let mut bgra = rgba.clone();
+        for px in bgra.chunks_exact_mut(4) {
+            px.swap(0, 2);
+        }
+        let d = OrderedDither::new(DitherMatrix::Bayer8);
+        let (mut a, mut b) = (vec![0u8; w * h * 2], vec![0u8; w * h * 2]);
+        rgba_to_rgb565_dithered(&rgba, &mut a, d).unwrap();
+        bgra_to_rgb565_dithered(&bgra, &mut b, d).unwrap();
+        assert_eq!(a, b);
+        rgba_to_rgba4444_dithered(&rgba, &mut a, d).unwrap();
+        bgra_to_rgba4444_dithered(&bgra, &mut b, d).unwrap();
+        assert_eq!(a, b);
+        let mut scratch = vec![0i16; w * 4];
+        rgba_to_rgb565_floyd_steinberg_strided(&rgba, &mut a, w, h, w * 4, w * 2, &mut scratch)
+            .unwrap();
+        bgra_to_rgb565_floyd_steinberg_strided(&bgra, &mut b, w, h, w * 4, w * 2, &mut scratch)
+            .unwrap();
+        assert_eq!(a, b);
+        rgba_to_rgba4444_floyd_steinberg_strided(&rgba, &mut a, w, h, w * 4, w * 2, &mut scratch)
+            .unwrap();
+        bgra_to_rgba4444_floyd_steinberg_strided(&bgra, &mut b, w, h, w * 4, w * 2, &mut scratch)
+            .unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn floyd_steinberg_preserves_mean_and_endpoints() {
+        let (w, h) = (64, 32);
+        let src = [77u8, 0, 255, 200].repeat(w * h);
+        let mut dst = vec![0u8; w * h * 2];
+        let mut scratch = vec![i16::MAX; w * 4]; // garbage on entry is ignored
+        rgba_to_rgba4444_floyd_steinberg_strided(&src, &mut dst, w, h, w * 4, w * 2, &mut scratch)
+            .unwrap();
+        let mut sums = [0u32; 4];
+        for px in dst.chunks_exact(2) {
+            let v = u16::from_le_bytes([px[0], px[1]]);
+            sums[0] += (v >> 12) as u32 * 17;
+            sums[1] += ((v >> 8) & 0xF) as u32 * 17;
+            sums[2] += ((v >> 4) & 0xF) as u32 * 17;
+            sums[3] += (v & 0xF) as u32 * 17;
+        }
+        let n = (w * h) as f64;
+        assert!((sums[0] as f64 / n - 77.0).abs() < 0.5);
+        assert_eq!(sums[1], 0);
+        assert_eq!(sums[2] as f64 / n, 255.0);
+        assert!((sums[3] as f64 / n - 200.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn floyd_steinberg_u16_mean_and_padding() {
+        let (w, h, ch) = (30, 9, 3); // w is elements: 10 pixels × 3 channels
+        let ss = w * 2 + 6;
+        let ds = w + 5;
+        let mut src = vec![0u8; ss * h];
+        for y in 0..h {
+            for x in 0..w {
+                src[y * ss + x * 2..][..2].copy_from_slice(&(1000u16 + x as u16).to_ne_bytes());
+            }
+        }
+        let mut dst = vec![0xAAu8; ds * h];
+        let mut scratch = vec![0i16; w];
+        convert_u16_to_u8_floyd_steinberg_strided(&src, &mut dst, w, h, ss, ds, ch, &mut scratch)
+            .unwrap();
+        for y in 0..h {
+            assert!(
+                dst[y * ds + w..y * ds + ds].iter().all(|&b| b == 0xAA),
+                "padding clobbered"
+            );
+        }
+        let total: u32 = (0..h)
+            .flat_map(|y| dst[y * ds..][..w].to_vec())
+            .map(|b| b as u32 * 257)
+            .sum();
+        let expect: u32 = (0..w as u32).map(|x| 1000 + x).sum::<u32>() * h as u32;
+        assert!((total as f64 - expect as f64).abs() / (w * h) as f64 <= 257.0 / 8.0);
+    }
+
+    #[test]
+    fn rejects_bad_sizes() {
+        let src = [0u8; 64];
+        let mut dst = [0u8; 64];
+        let d = OrderedDither::default();
+        assert_eq!(
+            convert_u16_to_u8_dithered(&src, &mut dst, 0, d).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
+        );
+        assert_eq!(
+            convert_u16_to_u8_dithered(&src, &mut dst, 5, d).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
+        );
+        assert_eq!(
+            convert_u16_to_u8_dithered(&src[..12], &mut dst, 4, d).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
+        );
+        assert_eq!(
+            convert_u16_to_u8_dithered_strided(&src, &mut dst, 5, 2, 16, 8, 2, d)
+                .map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
+        );
+        let mut scratch = [0i16; 5];
+        assert_eq!(
+            rgba_to_rgb565_floyd_steinberg_strided(&src, &mut dst, 2, 2, 8, 4, &mut scratch)
+                .map_err(|e| e.kind()),
+            Err(SizeErrorKind::InvalidStride)
+        );
+        assert_eq!(
+            rgba_to_rgb565_dithered(&src[..3], &mut dst, d).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
+        );
+    }
+}
@@ -0,0 +1,480 @@
+//! Row-band execution on a caller-provided [`Executor`].
+//!
+//! Large strided conversions are split into horizontal bands of whole rows,
+//! and each band runs the ordinary `_strided` function. garb owns the split;
+//! the [`Executor`] decides where and when each [`Band`] runs — a thread
+//! pool, a firmware job system, or [`Parallelism`](super::Parallelism) with
+//! the `parallel` feature. Bands never share rows, so the output is identical
+//! to a single-threaded call. Nothing here allocates.
+//!
+//! Below [`MIN_BAND_BYTES`] per band the split is not worth a dispatch, so
+//! small images run on the calling thread without touching the executor.
+//!
+//! The `par_…` functions cover the core conversions. Anything else with a
+//! `_strided` form — packed, depth, dithered — goes through [`par_bands`] or
+//! [`par_bands_inplace`], which hand each band's rows to a closure.
+
+use core::mem;
+
+use super::*;
+use crate::{Operand, SizeError, SizeErrorKind};
+
+/// Smallest band worth dispatching, in bytes of rows (larger stride of
+/// source and destination × rows). Images under twice this size always run on
+/// the calling thread.
+pub const MIN_BAND_BYTES: usize = 256 * 1024;
+
+/// Most bands a single call is split into.
+pub const MAX_BANDS: usize = 64;
+
+type CopyFn<'a> = dyn Fn(usize, &[u8], &mut [u8], usize) -> Result<(), SizeError> + Sync + 'a;
+type InplaceFn<'a> = dyn Fn(usize, &mut [u8], usize) -> Result<(), SizeError> + Sync + 'a;
+
+enum Work<'a> {
+    Done,
+    Copy {
+        y: usize,
+        rows: usize,
+        src: &'a [u8],
+        dst: &'a mut [u8],
+        f: &'a CopyFn<'a>,
+    },
+    Inplace {
+        y: usize,
+        rows: usize,
+        buf: &'a mut [u8],
+        f: &'a InplaceFn<'a>,
+    },
+}
+
+/// One row band of a conversion, ready to run on any thread.
+///
+/// Bands own disjoint slices of the image, so an executor may run them in any
+/// order and concurrently. Each is `Send`.
+pub struct Band<'a> {
+    work: Work<'a>,
+    result: Result<(), SizeError>,
+}
+
+impl Band<'_> {
+    const DONE: Self = Band {
+        work: Work::Done,
+        result: Ok(()),
+    };
+
+    /// Convert this band's rows. Running a band twice is a no-op.
+    pub fn run(&mut self) {
+        self.result = match mem::replace(&mut self.work, Work::Done) {
+            Work::Done => return,
+            Work::Copy {
+                y,
+                rows,
+                src,
+                dst,
+                f,
+            } => f(y, src, dst, rows),
+            Work::Inplace { y, rows, buf, f } => f(y, buf, rows),
+        };
+    }
+
+    /// Number of rows in this band (0 once it has run).
+    pub fn rows(&self) -> usize {
+        match self.work {
+            Work::Done => 0,
+            Work::Copy { rows, .. } | Work::Inplace { rows, .. } => rows,
+        }
+    }
+}
+
+/// Schedules row bands for the `par_…` functions.
+///
+/// A `Fn(usize) + Sync` callback can't hand out disjoint `&mut` bands without
+/// `unsafe` or a lock, which this `no_std`, `forbid(unsafe_code)` crate has
+/// neither of. Instead the executor receives the bands themselves: it may
+/// split the slice (`split_at_mut`, `iter_mut`) and send each `&mut Band` to
+/// a worker, calling [`Band::run`] once per band, and must return only when
+/// all have finished. Bands it leaves unrun are run on the calling thread
+/// afterwards.
+///
+/// ```rust
+/// use garb::bytes::{Band, Executor};
+///
+/// struct Scoped(usize);
+///
+/// impl Executor for Scoped {
+///     fn max_bands(&self) -> usize {
+///         self.0
+///     }
+///     fn run_bands(&self, bands: &mut [Band<'_>]) {
+///         std::thread::scope(|s| {
+///             for band in bands {
+///                 s.spawn(|| band.run());
+///             }
+///         });
+///     }
+/// }
+/// ```
+pub trait Executor {
+    /// Upper bound on useful bands, usually the worker count. garb splits
+    /// into at most this many (and at most [`MAX_BANDS`]).
+    fn max_bands(&self) -> usize;
+
+    /// Run every band once and return when all have finished.
+    fn run_bands(&self, bands: &mut [Band<'_>]);
+}
+
+impl<E: Executor + ?Sized> Executor for &E {
+    fn max_bands(&self) -> usize {
+        (**self).max_bands()
+    }
+
+    fn run_bands(&self, bands: &mut [Band<'_>]) {
+        (**self).run_bands(bands)
+    }
+}
+
+/// Number of bands for `height` rows of `row_bytes` each.
+fn band_count(max_bands: usize, height: usize, row_bytes: usize) -> usize {
+    let by_size = height.saturating_mul(row_bytes) / MIN_BAND_BYTES;
+    max_bands.min(MAX_BANDS).min(height).min(by_size).max(1)
+}
+
+/// Hand `bands` to `exec`, run any it skipped, and return the first error.
+fn dispatch(exec: &impl Executor, bands: &mut [Band<'_>]) -> Result<(), SizeError> {
+    exec.run_bands(bands);
+    bands.iter_mut().try_for_each(|b| {
+        b.run();
+        b.result
+    })
+}
+
+/// Run `f` on row bands of a strided copy, scheduled by `exec`.
+///
+/// `f(y, src_band, dst_band, band_height)` receives the first row index of
+/// the band, the source and destination starting at that row, and the number
+/// of rows; it should call the matching `_strided` function with the original
+/// width and strides. Bands other than the last are cut to exactly
+/// `band_height × stride` bytes. Returns the first band error, if any.
+///
+/// ```rust
+/// use garb::bytes::{par_bands, rgba_to_rgb_strided};
+/// # use garb::bytes::{Band, Executor};
+/// # struct Pool;
+/// # impl Executor for Pool {
+/// #     fn max_bands(&self) -> usize { 4 }
+/// #     fn run_bands(&self, bands: &mut [Band<'_>]) { bands.iter_mut().for_each(Band::run) }
+/// # }
+/// # fn main() -> Result<(), garb::SizeError> {
+/// # let pool = Pool;
+/// # let (width, height, ss, ds) = (64, 64, 256, 192);
+/// # let src = vec![0u8; ss * height];
+/// # let mut dst = vec![0u8; ds * height];
+/// par_bands(&src, &mut dst, height, ss, ds, &pool, |_y, s, d, h| {
+///     rgba_to_rgb_strided(s, d, width, h, ss, ds)
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn par_bands<E, F>(
+    src: &[u8],
+    dst: &mut [u8],
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    exec: E,
+    f: F,
+) -> Result<(), SizeError>
+where
+    E: Executor,
+    F: Fn(usize, &[u8], &mut [u8], usize) -> Result<(), SizeError> + Sync,
+{
+    let n = band_count(exec.max_bands(), height, src_stride.max(dst_stride));
+    if n <= 1 {
+        return f(0, src, dst, height);
+    }
+    let rows = height.div_ceil(n);
+    let mut bands: [Band<'_>; MAX_BANDS] = [Band::DONE; MAX_BANDS];
+    let (mut src, mut dst) = (src, dst);
+    let (mut y, mut count) = (0, 0);
+    while y < height {
+        let h = rows.min(height - y);
+        let (s, d);
+        if y + h == height {
+            (s, d) = (src, mem::take(&mut dst));
+        } else {
+            let (sn, dn) = (h * src_stride, h * dst_stride);
+            if src.len() < sn {
+                return Err(SizeError::new(SizeErrorKind::InvalidStride)
+                    .of(Operand::Src)
+                    .lengths(Some(sn), src.len()));
+            }
+            if dst.len() < dn {
+                return Err(SizeError::new(SizeErrorKind::InvalidStride)
+                    .of(Operand::Dst)
+                    .lengths(Some(dn), dst.len()));
+            }
+            let (d_band, d_rest) = mem::take(&mut dst).split_at_mut(dn);
+            (s, src) = src.split_at(sn);
+            (d, dst) = (d_band, d_rest);
+        }
+        bands[count].work = Work::Copy {
+            y,
+            rows: h,
+            src: s,
+            dst: d,
+            f: &f,
+        };
+        count += 1;
+        y += h;
+    }
+    dispatch(&exec, &mut bands[..count])
+}
+
+/// Run `f` on row bands of a strided in-place buffer, scheduled by `exec`.
+///
+/// `f(y, band, band_height)` works like the [`par_bands`] callback.
+pub fn par_bands_inplace<E, F>(
+    buf: &mut [u8],
+    height: usize,
+    stride: usize,
+    exec: E,
+    f: F,
+) -> Result<(), SizeError>
+where
+    E: Executor,
+    F: Fn(usize, &mut [u8], usize) -> Result<(), SizeError> + Sync,
+{
+    let n = band_count(exec.max_bands(), height, stride);
+    if n <= 1 {
+        return f(0, buf, height);
+    }
+    let rows = height.div_ceil(n);
+    let mut bands: [Band<'_>; MAX_BANDS] = [Band::DONE; MAX_BANDS];
+    let mut buf = buf;
+    let (mut y, mut count) = (0, 0);
+    while y < height {
+        let h = rows.min(height - y);
+        let b;
+        if y + h == height {
+            b = mem::take(&mut buf);
+        } else {
+            if buf.len() < h * stride {
+                return Err(SizeError::new(SizeErrorKind::InvalidStride)
+                    .of(Operand::Buf)
+                    .lengths(Some(h * stride), buf.len()));
+            }
+            (b, buf) = mem::take(&mut buf).split_at_mut(h * stride);
+        }
+        bands[count].work = Work::Inplace {
+            y,
+            rows: h,
+            buf: b,
+            f: &f,
+        };
+        count += 1;
+        y += h;
+    }
+    dispatch(&exec, &mut bands[..count])
+}
+
+// ===========================================================================
+// par_ variants of the core strided conversions
+// ===========================================================================
+
+macro_rules! par_inplace {
+    ($($par:ident => $f:ident, $bpp:literal;)*) => {$(
+        #[doc = concat!("[`", stringify!($f), "`] split into row bands on `exec`.")]
+        pub fn $par<E: Executor>(
+            buf: &mut [u8],
+            width: usize,
+            height: usize,
+            stride: usize,
+            exec: E,
+        ) -> Result<(), SizeError> {
+            check_strided(Operand::Buf, buf.len(), width, height, stride, $bpp)?;
+            par_bands_inplace(buf, height, stride, exec, |_, b, h| {
+                $f(b, width, h, stride)
+            })
+        }
+    )*};
+}
+
+macro_rules! par_copy {
+    ($($par:ident => $f:ident, $src_bpp:literal, $dst_bpp:literal;)*) => {$(
+        #[doc = concat!("[`", stringify!($f), "`] split into row bands on `exec`.")]
+        #[allow(clippy::too_many_arguments)]
+        pub fn $par<E: Executor>(
+            src: &[u8],
+            dst: &mut [u8],
+            width: usize,
+            height: usize,
+            src_stride: usize,
+            dst_stride: usize,
+            exec: E,
+        ) -> Result<(), SizeError> {
+            check_strided(Operand::Src, src.len(), width, height, src_stride, $src_bpp)?;
+            check_strided(Operand::Dst, dst.len(), width, height, dst_stride, $dst_bpp)?;
+            par_bands(src, dst, height, src_stride, dst_stride, exec, |_, s, d, h| {
+                $f(s, d, width, h, src_stride, dst_stride)
+            })
+        }
+    )*};
+}
+
+par_inplace! {
+    par_rgba_to_bgra_inplace_strided => rgba_to_bgra_inplace_strided, 4;
+    par_rgb_to_bgr_inplace_strided => rgb_to_bgr_inplace_strided, 3;
+    par_fill_alpha_rgba_strided => fill_alpha_rgba_strided, 4;
+    par_fill_alpha_argb_strided => fill_alpha_argb_strided, 4;
+    par_argb_to_rgba_inplace_strided => argb_to_rgba_inplace_strided, 4;
+    par_rgba_to_argb_inplace_strided => rgba_to_argb_inplace_strided, 4;
+    par_argb_to_bgra_inplace_strided => argb_to_bgra_inplace_strided, 4;
+    par_swap_bytes_u16_inplace_strided => swap_bytes_u16_inplace_strided, 2;
+    par_swap_bytes_u32_inplace_strided => swap_bytes_u32_inplace_strided, 4;
+}
+
+par_copy! {
+    par_rgba_to_bgra_strided => rgba_to_bgra_strided, 4, 4;
+    par_rgb_to_bgra_strided => rgb_to_bgra_strided, 3, 4;
+    par_rgb_to_rgba_strided => rgb_to_rgba_strided, 3, 4;
+    par_gray_to_rgba_strided => gray_to_rgba_strided, 1, 4;
+    par_gray_alpha_to_rgba_strided => gray_alpha_to_rgba_strided, 2, 4;
+    par_rgb_to_bgr_strided => rgb_to_bgr_strided, 3, 3;
+    par_rgba_to_rgb_strided => rgba_to_rgb_strided, 4, 3;
+    par_bgra_to_rgb_strided => bgra_to_rgb_strided, 4, 3;
+    par_argb_to_rgba_strided => argb_to_rgba_strided, 4, 4;
+    par_rgba_to_argb_strided => rgba_to_argb_strided, 4, 4;
+    par_argb_to_bgra_strided => argb_to_bgra_strided, 4, 4;
+    par_rgb_to_argb_strided => rgb_to_argb_strided, 3, 4;
+    par_rgb_to_abgr_strided => rgb_to_abgr_strided, 3, 4;
+    par_argb_to_rgb_strided => argb_to_rgb_strided, 4, 3;
+    par_argb_to_bgr_strided => argb_to_bgr_strided, 4, 3;
+    par_gray_to_argb_strided => gray_to_argb_strided, 1, 4;
+    par_gray_alpha_to_argb_strided => gray_alpha_to_argb_strided, 2, 4;
+    par_swap_bytes_u16_strided => swap_bytes_u16_strided, 2, 2;
+    par_swap_bytes_u32_strided => swap_bytes_u32_strided, 4, 4;
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cell::Cell;
+
+    /// Runs bands in reverse order on the calling thread, skipping the first.
+    struct Reverse {
+        max: usize,
+        dispatched: Cell<usize>,
+    }
+
+    impl Executor for Reverse {
+        fn max_bands(&self) -> usize {
+            self.max
+        }
+
+        fn run_bands(&self, bands: &mut [Band<'_>]) {
+            self.dispatched.set(bands.len());
+            for band in bands.iter_mut().skip(1).rev() {
+                band.run();
+            }
+        }
+    }
+
+    fn reverse(max: usize) -> Reverse {
+        Reverse {
+            max,
+            dispatched: Cell::new(0),
+        }
+    }
+
+    #[test]
+    fn band_count_respects_threshold() {
+        assert_eq!(band_count(8, 100, 1024), 1);
+        assert_eq!(band_count(8, 1024, 1024), 4);
+        assert_eq!(band_count(8, 4096, 4096), 8);
+        assert_eq!(band_count(1000, 1 << 16, 1 << 16), MAX_BANDS);
+        assert_eq!(band_count(0, 4096, 4096), 1);
+    }
+
+    #[test]
+    fn executor_matches_sequential() {
+        // 700 × 1001 RGBA with row padding: several bands, uneven last band.
+        let (w, h) = (700, 1001);
+        let (ss, ds) = (w * 4 + 12, w * 3 + 5);
+        let src: Vec<u8> = (0..ss * h).map(|i| (i * 7 % 251) as u8).collect();
+        let mut seq = vec![0xAAu8; ds * h];
+        let mut par = vec![0xAAu8; ds * h];
+        rgba_to_rgb_strided(&src, &mut seq, w, h, ss, ds).unwrap();
+        let exec = reverse(5);
+        par_rgba_to_rgb_strided(&src, &mut par, w, h, ss, ds, &exec).unwrap();
+        assert_eq!(exec.dispatched.get(), 5);
+        assert!(seq == par);
+
+        let mut a = src.clone();
+        let mut b = src.clone();
+        rgba_to_bgra_inplace_strided(&mut a, w, h, ss).unwrap();
+        par_rgba_to_bgra_inplace_strided(&mut b, w, h, ss, reverse(3)).unwrap();
+        assert!(a == b);
+    }
+
+    #[test]
+    fn band_origin_and_errors() {
+        let (w, h, stride) = (1024, 512, 1024);
+        let mut buf = vec![0u8; stride * h];
+        par_bands_inplace(&mut buf, h, stride, reverse(4), |y0, b, bh| {
+            for (r, row) in b.chunks_mut(stride).take(bh).enumerate() {
+                row[0] = ((y0 + r) % 256) as u8;
+            }
+            Ok(())
+        })
+        .unwrap();
+        assert!((0..h).all(|y| buf[y * stride] == (y % 256) as u8));
+
+        let short = vec![0u8; stride * h - 1];
+        let mut dst = vec![0u8; w * 4 * h];
+        assert_eq!(
+            par_gray_to_rgba_strided(&short, &mut dst, w, h, stride, w * 4, reverse(4))
+                .map_err(|e| e.kind()),
+            Err(SizeErrorKind::InvalidStride)
+        );
+
+        // Bands past the end of a short buffer name it.
+        let f = |_, _: &[u8], _: &mut [u8], _| Ok(());
+        let err = par_bands(
+            &short[..stride * 100],
+            &mut dst,
+            h,
+            stride,
+            w * 4,
+            reverse(4),
+            f,
+        )
+        .unwrap_err();
+        assert_eq!(err.operand(), Some(Operand::Src));
+        assert_eq!(
+            (err.required(), err.actual()),
+            (Some(stride * 128), Some(stride * 100))
+        );
+        let err = par_bands_inplace(
+            &mut buf[..stride * 100],
+            h,
+            stride,
+            reverse(4),
+            |_, _, _| Ok(()),
+        )
+        .unwrap_err();
+        assert_eq!(err.operand(), Some(Operand::Buf));
+        assert_eq!(
+            (err.required(), err.actual()),
+            (Some(stride * 256), Some(stride * 100))
+        );
+        // Small images never reach the executor.
+        let exec = reverse(8);
+        let mut small = vec![0u8; 64 * 64 * 4];
+        par_rgba_to_bgra_inplace_strided(&mut small, 64, 64, 256, &exec).unwrap();
+        assert_eq!(exec.dispatched.get(), 0);
+    }
+}
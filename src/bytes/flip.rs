@@ -0,0 +1,97 @@
+//! Strided copy conversions that also flip the image vertically.
+//!
+//! BMP/DIB files and OpenGL `glReadPixels` store rows bottom-up. Each
+//! `…_strided_flip_y` function converts like its `…_strided` counterpart but
+//! writes destination row `y` from source row `height - 1 - y`, so the flip
+//! costs nothing beyond the conversion itself. Flipping is its own inverse:
+//! the same call turns top-down rows into bottom-up ones.
+//!
+//! To flip without converting, use
+//! [`transform_strided`](super::transform_strided) with
+//! [`Orientation::FlipVertical`](super::Orientation::FlipVertical).
+
+use super::*;
+use crate::SizeError;
+
+type RowFn = fn(&Kernels, &[u8], &mut [u8]) -> Result<(), SizeError>;
+
+#[allow(clippy::too_many_arguments)]
+fn flip_rows(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    sbpp: usize,
+    dbpp: usize,
+    f: RowFn,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, sbpp)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, dbpp)?;
+    let k = Kernels::detect();
+    for y in 0..height {
+        let s = &src[(height - 1 - y) * src_stride..][..width * sbpp];
+        f(&k, s, &mut dst[y * dst_stride..][..width * dbpp])?;
+    }
+    Ok(())
+}
+
+macro_rules! flip_y {
+    ($($name:ident => $base:ident, $k:ident, $sbpp:literal, $dbpp:literal;)*) => {
+        $(
+            #[doc = concat!("[`", stringify!($base), "`](super::", stringify!($base), ") with rows in reverse order (vertical flip).")]
+            pub fn $name(
+                src: &[u8],
+                dst: &mut [u8],
+                width: usize,
+                height: usize,
+                src_stride: usize,
+                dst_stride: usize,
+            ) -> Result<(), SizeError> {
+                flip_rows(src, dst, width, height, src_stride, dst_stride, $sbpp, $dbpp, Kernels::$k)
+            }
+        )*
+    };
+}
+
+flip_y! {
+    rgba_to_bgra_strided_flip_y => rgba_to_bgra_strided, rgba_to_bgra, 4, 4;
+    bgra_to_rgba_strided_flip_y => bgra_to_rgba_strided, rgba_to_bgra, 4, 4;
+    rgb_to_bgra_strided_flip_y => rgb_to_bgra_strided, rgb_to_bgra, 3, 4;
+    bgr_to_rgba_strided_flip_y => bgr_to_rgba_strided, rgb_to_bgra, 3, 4;
+    rgb_to_rgba_strided_flip_y => rgb_to_rgba_strided, rgb_to_rgba, 3, 4;
+    bgr_to_bgra_strided_flip_y => bgr_to_bgra_strided, rgb_to_rgba, 3, 4;
+    gray_to_rgba_strided_flip_y => gray_to_rgba_strided, gray_to_rgba, 1, 4;
+    gray_to_bgra_strided_flip_y => gray_to_bgra_strided, gray_to_rgba, 1, 4;
+    gray_alpha_to_rgba_strided_flip_y => gray_alpha_to_rgba_strided, gray_alpha_to_rgba, 2, 4;
+    gray_alpha_to_bgra_strided_flip_y => gray_alpha_to_bgra_strided, gray_alpha_to_rgba, 2, 4;
+    rgb_to_bgr_strided_flip_y => rgb_to_bgr_strided, rgb_to_bgr, 3, 3;
+    bgr_to_rgb_strided_flip_y => bgr_to_rgb_strided, rgb_to_bgr, 3, 3;
+    rgba_to_rgb_strided_flip_y => rgba_to_rgb_strided, rgba_to_rgb, 4, 3;
+    bgra_to_bgr_strided_flip_y => bgra_to_bgr_strided, rgba_to_rgb, 4, 3;
+    bgra_to_rgb_strided_flip_y => bgra_to_rgb_strided, bgra_to_rgb, 4, 3;
+    rgba_to_bgr_strided_flip_y => rgba_to_bgr_strided, bgra_to_rgb, 4, 3;
+    argb_to_rgba_strided_flip_y => argb_to_rgba_strided, argb_to_rgba, 4, 4;
+    abgr_to_bgra_strided_flip_y => abgr_to_bgra_strided, argb_to_rgba, 4, 4;
+    rgba_to_argb_strided_flip_y => rgba_to_argb_strided, rgba_to_argb, 4, 4;
+    bgra_to_abgr_strided_flip_y => bgra_to_abgr_strided, rgba_to_argb, 4, 4;
+    argb_to_bgra_strided_flip_y => argb_to_bgra_strided, argb_to_bgra, 4, 4;
+    bgra_to_argb_strided_flip_y => bgra_to_argb_strided, argb_to_bgra, 4, 4;
+    abgr_to_rgba_strided_flip_y => abgr_to_rgba_strided, argb_to_bgra, 4, 4;
+    rgba_to_abgr_strided_flip_y => rgba_to_abgr_strided, argb_to_bgra, 4, 4;
+    rgb_to_argb_strided_flip_y => rgb_to_argb_strided, rgb_to_argb, 3, 4;
+    bgr_to_abgr_strided_flip_y => bgr_to_abgr_strided, rgb_to_argb, 3, 4;
+    rgb_to_abgr_strided_flip_y => rgb_to_abgr_strided, rgb_to_abgr, 3, 4;
+    bgr_to_argb_strided_flip_y => bgr_to_argb_strided, rgb_to_abgr, 3, 4;
+    argb_to_rgb_strided_flip_y => argb_to_rgb_strided, argb_to_rgb, 4, 3;
+    abgr_to_bgr_strided_flip_y => abgr_to_bgr_strided, argb_to_rgb, 4, 3;
+    argb_to_bgr_strided_flip_y => argb_to_bgr_strided, argb_to_bgr, 4, 3;
+    abgr_to_rgb_strided_flip_y => abgr_to_rgb_strided, argb_to_bgr, 4, 3;
+    gray_to_argb_strided_flip_y => gray_to_argb_strided, gray_to_argb, 1, 4;
+    gray_to_abgr_strided_flip_y => gray_to_abgr_strided, gray_to_argb, 1, 4;
+    gray_alpha_to_argb_strided_flip_y => gray_alpha_to_argb_strided, gray_alpha_to_argb, 2, 4;
+    gray_alpha_to_abgr_strided_flip_y => gray_alpha_to_abgr_strided, gray_alpha_to_argb, 2, 4;
+    swap_bytes_u16_strided_flip_y => swap_bytes_u16_strided, swap_bytes_u16, 2, 2;
+    swap_bytes_u32_strided_flip_y => swap_bytes_u32_strided, swap_bytes_u32, 4, 4;
+}
@@ -0,0 +1,347 @@
+//! GPU texture format mapping and upload staging.
+//!
+//! [`TextureLayout`] names the garb byte layouts that GPUs sample directly and
+//! maps each to its Vulkan `VkFormat` and DXGI `DXGI_FORMAT` values (plain
+//! integers, no graphics API dependency) and, with the `wgpu` feature, to a
+//! `wgpu::TextureFormat`. The `from_*` lookups also accept the `…_SRGB`
+//! twins of the 8-bit formats, which store the same bytes.
+//!
+//! [`upload_plan`] picks the nearest uploadable layout for a
+//! [`PixelLayout`](super::PixelLayout) and a row pitch rounded up to the API's
+//! copy alignment; [`convert_for_upload`] writes the staging buffer in one
+//! pass with a [`RowConverter`](super::RowConverter).
+
+use super::*;
+use crate::{SizeError, SizeErrorKind};
+
+/// `bytes_per_row` alignment wgpu requires for buffer ↔ texture copies
+/// (`wgpu::COPY_BYTES_PER_ROW_ALIGNMENT`); also D3D12's
+/// `D3D12_TEXTURE_DATA_PITCH_ALIGNMENT`.
+pub const COPY_BYTES_PER_ROW_ALIGNMENT: usize = 256;
+
+/// A byte layout GPUs sample directly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TextureLayout {
+    /// `[R, G, B, A]` 8-bit.
+    Rgba8,
+    /// `[B, G, R, A]` 8-bit.
+    Bgra8,
+    /// One 8-bit channel (gray samples as red; swizzle in the shader).
+    R8,
+    /// Two 8-bit channels (gray + alpha sample as red + green).
+    Rg8,
+    /// Little-endian `u16`: `R[15:11] G[10:5] B[4:0]`.
+    Rgb565,
+    /// Little-endian `u32`: R in bits 0–9, G 10–19, B 20–29, A 30–31.
+    Rgba1010102,
+    /// `[R, G, B, A]` little-endian `u16` (unorm).
+    Rgba16,
+    /// `[R, G, B, A]` `f32`.
+    Rgba32F,
+}
+
+/// `(layout, VkFormat, DXGI_FORMAT)`; `0` means none (`VK_FORMAT_UNDEFINED`,
+/// `DXGI_FORMAT_UNKNOWN`).
+const TABLE: [(TextureLayout, u32, u32); 8] = [
+    (TextureLayout::Rgba8, 37, 28),       // R8G8B8A8_UNORM
+    (TextureLayout::Bgra8, 44, 87),       // B8G8R8A8_UNORM
+    (TextureLayout::R8, 9, 61),           // R8_UNORM
+    (TextureLayout::Rg8, 16, 49),         // R8G8_UNORM
+    (TextureLayout::Rgb565, 4, 85),       // R5G6B5_UNORM_PACK16 / B5G6R5_UNORM
+    (TextureLayout::Rgba1010102, 64, 24), // A2B10G10R10_UNORM_PACK32 / R10G10B10A2_UNORM
+    (TextureLayout::Rgba16, 91, 11),      // R16G16B16A16_UNORM
+    (TextureLayout::Rgba32F, 109, 2),     // R32G32B32A32_SFLOAT / _FLOAT
+];
+
+/// sRGB twins: `(layout, VkFormat, DXGI_FORMAT)`.
+const SRGB: [(TextureLayout, u32, u32); 2] = [
+    (TextureLayout::Rgba8, 43, 29), // R8G8B8A8_SRGB / _UNORM_SRGB
+    (TextureLayout::Bgra8, 50, 91), // B8G8R8A8_SRGB / _UNORM_SRGB
+];
+
+impl TextureLayout {
+    /// Bytes per pixel.
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            Self::R8 => 1,
+            Self::Rg8 | Self::Rgb565 => 2,
+            Self::Rgba8 | Self::Bgra8 | Self::Rgba1010102 => 4,
+            Self::Rgba16 => 8,
+            Self::Rgba32F => 16,
+        }
+    }
+
+    const fn entry(self) -> (TextureLayout, u32, u32) {
+        let mut i = 0;
+        while i < TABLE.len() {
+            if TABLE[i].0 as u8 == self as u8 {
+                return TABLE[i];
+            }
+            i += 1;
+        }
+        unreachable!()
+    }
+
+    /// `VkFormat` value (the UNORM / SFLOAT one).
+    pub const fn vk_format(self) -> u32 {
+        self.entry().1
+    }
+
+    /// `DXGI_FORMAT` value (the UNORM / FLOAT one).
+    pub const fn dxgi_format(self) -> u32 {
+        self.entry().2
+    }
+
+    /// Layout for a `VkFormat` value, including the 8-bit `…_SRGB` formats.
+    pub fn from_vk_format(format: u32) -> Option<Self> {
+        TABLE
+            .iter()
+            .chain(&SRGB)
+            .find(|e| e.1 == format)
+            .map(|e| e.0)
+    }
+
+    /// Layout for a `DXGI_FORMAT` value, including the 8-bit `…_UNORM_SRGB`
+    /// formats. `B5G6R5_UNORM` maps to [`Rgb565`](Self::Rgb565).
+    pub fn from_dxgi_format(format: u32) -> Option<Self> {
+        TABLE
+            .iter()
+            .chain(&SRGB)
+            .find(|e| e.2 == format)
+            .map(|e| e.0)
+    }
+
+    /// `wgpu::TextureFormat` (the non-sRGB one), or `None` for
+    /// [`Rgb565`](Self::Rgb565), which wgpu has no format for.
+    #[cfg(feature = "wgpu")]
+    pub fn wgpu_format(self) -> Option<wgpu_types::TextureFormat> {
+        use wgpu_types::TextureFormat as F;
+        Some(match self {
+            Self::Rgba8 => F::Rgba8Unorm,
+            Self::Bgra8 => F::Bgra8Unorm,
+            Self::R8 => F::R8Unorm,
+            Self::Rg8 => F::Rg8Unorm,
+            Self::Rgb565 => return None,
+            Self::Rgba1010102 => F::Rgb10a2Unorm,
+            Self::Rgba16 => F::Rgba16Unorm,
+            Self::Rgba32F => F::Rgba32Float,
+        })
+    }
+
+    /// Layout for a `wgpu::TextureFormat`, including the 8-bit sRGB formats.
+    #[cfg(feature = "wgpu")]
+    pub fn from_wgpu_format(format: wgpu_types::TextureFormat) -> Option<Self> {
+        use wgpu_types::TextureFormat as F;
+        Some(match format {
+            F::Rgba8Unorm | F::Rgba8UnormSrgb => Self::Rgba8,
+            F::Bgra8Unorm | F::Bgra8UnormSrgb => Self::Bgra8,
+            F::R8Unorm => Self::R8,
+            F::Rg8Unorm => Self::Rg8,
+            F::Rgb10a2Unorm => Self::Rgba1010102,
+            F::Rgba16Unorm => Self::Rgba16,
+            F::Rgba32Float => Self::Rgba32F,
+            _ => return None,
+        })
+    }
+}
+
+/// `width * bytes_per_pixel` rounded up to a multiple of `align`, or `None`
+/// on overflow or if `align` is zero.
+pub const fn aligned_row_pitch(
+    width: usize,
+    bytes_per_pixel: usize,
+    align: usize,
+) -> Option<usize> {
+    if align == 0 {
+        return None;
+    }
+    match width.checked_mul(bytes_per_pixel) {
+        Some(row) => match row.checked_next_multiple_of(align) {
+            Some(pitch) => Some(pitch),
+            None => None,
+        },
+        None => None,
+    }
+}
+
+/// Destination layout and staging-buffer geometry for an upload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UploadPlan {
+    /// Texture layout the staging buffer holds.
+    pub layout: TextureLayout,
+    /// Row pitch in bytes (`bytes_per_row` for wgpu, `RowPitch` for D3D12).
+    pub bytes_per_row: usize,
+    /// Staging buffer size: `bytes_per_row * height`.
+    pub len: usize,
+}
+
+/// Nearest uploadable layout for `src`: alpha-first and 3-byte layouts are
+/// expanded or rotated to [`Rgba8`](TextureLayout::Rgba8) /
+/// [`Bgra8`](TextureLayout::Bgra8) keeping their R/B order; gray stays one
+/// or two channels.
+pub const fn upload_layout(src: PixelLayout) -> TextureLayout {
+    match src {
+        PixelLayout::Rgba | PixelLayout::Argb | PixelLayout::Rgb => TextureLayout::Rgba8,
+        PixelLayout::Bgra | PixelLayout::Abgr | PixelLayout::Bgr => TextureLayout::Bgra8,
+        PixelLayout::Gray => TextureLayout::R8,
+        PixelLayout::GrayAlpha => TextureLayout::Rg8,
+    }
+}
+
+/// Layout and staging size for uploading a `width × height` image of `src`,
+/// with rows padded to `row_align` bytes (usually
+/// [`COPY_BYTES_PER_ROW_ALIGNMENT`]).
+///
+/// `None` if a dimension or `row_align` is zero, or on overflow.
+pub const fn upload_plan(
+    src: PixelLayout,
+    width: usize,
+    height: usize,
+    row_align: usize,
+) -> Option<UploadPlan> {
+    let layout = upload_layout(src);
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let Some(bytes_per_row) = aligned_row_pitch(width, layout.bytes_per_pixel(), row_align) else {
+        return None;
+    };
+    let Some(len) = bytes_per_row.checked_mul(height) else {
+        return None;
+    };
+    Some(UploadPlan {
+        layout,
+        bytes_per_row,
+        len,
+    })
+}
+
+/// Convert a strided `src` image into an upload staging buffer laid out by
+/// [`upload_plan`]; returns the plan.
+///
+/// `dst` must hold at least `plan.len` bytes (else `PixelCountMismatch`); a
+/// zero dimension or `row_align` is `InvalidStride`. Row padding is left
+/// untouched.
+///
+/// ```rust
+/// use garb::bytes::{convert_for_upload, PixelLayout, TextureLayout, COPY_BYTES_PER_ROW_ALIGNMENT};
+///
+/// let rgb = vec![10u8; 100 * 3 * 4]; // 100×4 RGB, packed
+/// let mut staging = vec![0u8; 512 * 4];
+/// let plan = convert_for_upload(&rgb, PixelLayout::Rgb, 100, 4, 300, &mut staging, COPY_BYTES_PER_ROW_ALIGNMENT)?;
+/// assert_eq!(plan.layout, TextureLayout::Rgba8);
+/// assert_eq!(plan.bytes_per_row, 512);
+/// assert_eq!(plan.layout.vk_format(), 37); // VK_FORMAT_R8G8B8A8_UNORM
+/// # Ok::<(), garb::SizeError>(())
+/// ```
+pub fn convert_for_upload(
+    src: &[u8],
+    src_layout: PixelLayout,
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst: &mut [u8],
+    row_align: usize,
+) -> Result<UploadPlan, SizeError> {
+    let plan =
+        upload_plan(src_layout, width, height, row_align).ok_or(SizeErrorKind::InvalidStride)?;
+    if dst.len() < plan.len {
+        return Err(SizeError::new(SizeErrorKind::PixelCountMismatch)
+            .of(Operand::Dst)
+            .lengths(Some(plan.len), dst.len()));
+    }
+    let dst_layout = match plan.layout {
+        TextureLayout::Rgba8 => PixelLayout::Rgba,
+        TextureLayout::Bgra8 => PixelLayout::Bgra,
+        TextureLayout::R8 => PixelLayout::Gray,
+        _ => PixelLayout::GrayAlpha,
+    };
+    let conv =
+        RowConverter::new(src_layout, dst_layout, width).ok_or(SizeErrorKind::InvalidStride)?;
+    conv.push_rows(src, dst, height, src_stride, plan.bytes_per_row)?;
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec;
+
+    const ALL: [TextureLayout; 8] = [
+        TextureLayout::Rgba8,
+        TextureLayout::Bgra8,
+        TextureLayout::R8,
+        TextureLayout::Rg8,
+        TextureLayout::Rgb565,
+        TextureLayout::Rgba1010102,
+        TextureLayout::Rgba16,
+        TextureLayout::Rgba32F,
+    ];
+
+    #[test]
+    fn format_tables_roundtrip() {
+        for l in ALL {
+            assert_eq!(TextureLayout::from_vk_format(l.vk_format()), Some(l));
+            assert_eq!(TextureLayout::from_dxgi_format(l.dxgi_format()), Some(l));
+            #[cfg(feature = "wgpu")]
+            if let Some(f) = l.wgpu_format() {
+                assert_eq!(TextureLayout::from_wgpu_format(f), Some(l));
+                assert_eq!(f.block_copy_size(None), Some(l.bytes_per_pixel() as u32));
+            }
+        }
+        assert_eq!(
+            TextureLayout::from_vk_format(50),
+            Some(TextureLayout::Bgra8)
+        );
+        assert_eq!(
+            TextureLayout::from_dxgi_format(29),
+            Some(TextureLayout::Rgba8)
+        );
+        assert_eq!(TextureLayout::from_vk_format(0), None);
+        assert_eq!(TextureLayout::from_dxgi_format(0), None);
+    }
+
+    #[test]
+    fn upload_pads_rows() {
+        // 3×2 BGR with a 10-byte source stride → BGRA, 16-byte pitch.
+        let src = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+        ];
+        let mut dst = vec![0xAAu8; 32];
+        let plan = convert_for_upload(&src, PixelLayout::Bgr, 3, 2, 10, &mut dst, 16).unwrap();
+        assert_eq!(
+            plan,
+            UploadPlan {
+                layout: TextureLayout::Bgra8,
+                bytes_per_row: 16,
+                len: 32
+            }
+        );
+        assert_eq!(
+            &dst[..16],
+            &[
+                1, 2, 3, 255, 4, 5, 6, 255, 7, 8, 9, 255, 0xAA, 0xAA, 0xAA, 0xAA
+            ]
+        );
+        assert_eq!(
+            &dst[16..28],
+            &[11, 12, 13, 255, 14, 15, 16, 255, 17, 18, 19, 255]
+        );
+
+        assert_eq!(
+            convert_for_upload(&src, PixelLayout::Bgr, 3, 2, 10, &mut dst[..31], 16)
+                .map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
+        );
+        assert_eq!(
+            upload_plan(PixelLayout::Gray, 300, 1, 256)
+                .unwrap()
+                .bytes_per_row,
+            512
+        );
+        assert_eq!(upload_plan(PixelLayout::Gray, 300, 1, 0), None);
+    }
+}
@@ -0,0 +1,281 @@
+//! Pre-resolved SIMD dispatch.
+//!
+//! Every public function runs `incant!` — a CPU feature check — on each
+//! call. For small rows (icons, 16×16 tiles, sprite atlases) that check can
+//! show up in profiles. [`Kernels::detect`] resolves the tier once and keeps
+//! its token; each method then calls the matching kernel directly, with the
+//! same validation as the free function of the same name.
+
+use archmage::prelude::*;
+
+use super::*;
+use crate::SizeError;
+
+/// SIMD tier resolved once, with its token.
+#[derive(Clone, Copy, Debug)]
+enum Tier {
+    #[cfg(target_arch = "x86_64")]
+    V3(X64V3Token),
+    #[cfg(target_arch = "aarch64")]
+    Neon(NeonToken),
+    #[cfg(target_arch = "wasm32")]
+    Wasm128(Wasm128Token),
+    Scalar(ScalarToken),
+}
+
+/// Instruction set a [`Kernels`] value dispatches to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SimdTier {
+    /// x86-64 AVX2 + FMA (`x86-64-v3`).
+    V3,
+    /// AArch64 NEON.
+    Neon,
+    /// WebAssembly SIMD128.
+    Wasm128,
+    /// Portable scalar code.
+    Scalar,
+}
+
+impl SimdTier {
+    /// Best tier for this CPU; same as `Kernels::detect().simd_tier()`.
+    pub fn detect() -> Self {
+        Kernels::detect().simd_tier()
+    }
+
+    /// `"v3"`, `"neon"`, `"wasm128"` or `"scalar"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::V3 => "v3",
+            Self::Neon => "neon",
+            Self::Wasm128 => "wasm128",
+            Self::Scalar => "scalar",
+        }
+    }
+}
+
+/// Call the `_impl` kernel `$f` for an already-resolved tier.
+///
+/// `$tiers` is the list the matching free function passes to `incant!`;
+/// tiers missing from it fall back to the scalar kernel.
+macro_rules! with_tier {
+    (@v3 $t:ident, $f:ident($($a:expr),*), [v3 $(, $rest:ident)*]) => {
+        incant!($f($($a),*) with $t, [v3, scalar])
+    };
+    (@neon $t:ident, $f:ident($($a:expr),*), [v3, neon $(, $rest:ident)*]) => {
+        incant!($f($($a),*) with $t, [neon, scalar])
+    };
+    (@wasm128 $t:ident, $f:ident($($a:expr),*), [v3, neon, wasm128, scalar]) => {
+        incant!($f($($a),*) with $t, [wasm128, scalar])
+    };
+    (@wasm128 $t:ident, $f:ident($($a:expr),*), [v3, wasm128, scalar]) => {
+        incant!($f($($a),*) with $t, [wasm128, scalar])
+    };
+    (@$tier:ident $t:ident, $f:ident($($a:expr),*), [$($x:ident),*]) => {{
+        let _ = $t;
+        incant!($f($($a),*) with ScalarToken, [scalar])
+    }};
+    ($tier:expr, $f:ident($($a:expr),*), $tiers:tt) => {
+        match $tier {
+            #[cfg(target_arch = "x86_64")]
+            Tier::V3(t) => with_tier!(@v3 t, $f($($a),*), $tiers),
+            #[cfg(target_arch = "aarch64")]
+            Tier::Neon(t) => with_tier!(@neon t, $f($($a),*), $tiers),
+            #[cfg(target_arch = "wasm32")]
+            Tier::Wasm128(t) => with_tier!(@wasm128 t, $f($($a),*), $tiers),
+            Tier::Scalar(t) => incant!($f($($a),*) with t, [scalar]),
+        }
+    };
+}
+
+/// Conversion kernels bound to the SIMD tier detected at construction.
+///
+/// Cheap to copy; detect once and keep it next to the hot loop.
+///
+/// ```rust
+/// use garb::bytes::Kernels;
+///
+/// let k = Kernels::detect();
+/// let mut tile = [0u8; 16 * 16 * 4];
+/// for _ in 0..1000 {
+///     k.rgba_to_bgra_inplace(&mut tile)?;
+/// }
+/// # Ok::<(), garb::SizeError>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Kernels {
+    tier: Tier,
+}
+
+impl Default for Kernels {
+    fn default() -> Self {
+        Self::detect()
+    }
+}
+
+impl Kernels {
+    /// Detect the best SIMD tier for this CPU.
+    pub fn detect() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        if let Some(t) = X64V3Token::summon() {
+            return Self { tier: Tier::V3(t) };
+        }
+        #[cfg(target_arch = "aarch64")]
+        if let Some(t) = NeonToken::summon() {
+            return Self {
+                tier: Tier::Neon(t),
+            };
+        }
+        #[cfg(target_arch = "wasm32")]
+        if let Some(t) = Wasm128Token::summon() {
+            return Self {
+                tier: Tier::Wasm128(t),
+            };
+        }
+        Self::scalar()
+    }
+
+    /// Portable scalar kernels, regardless of CPU.
+    pub fn scalar() -> Self {
+        Self {
+            tier: Tier::Scalar(ScalarToken),
+        }
+    }
+
+    /// The resolved tier.
+    pub fn simd_tier(&self) -> SimdTier {
+        match self.tier {
+            #[cfg(target_arch = "x86_64")]
+            Tier::V3(_) => SimdTier::V3,
+            #[cfg(target_arch = "aarch64")]
+            Tier::Neon(_) => SimdTier::Neon,
+            #[cfg(target_arch = "wasm32")]
+            Tier::Wasm128(_) => SimdTier::Wasm128,
+            Tier::Scalar(_) => SimdTier::Scalar,
+        }
+    }
+
+    /// Name of the resolved tier: `"v3"`, `"neon"`, `"wasm128"` or `"scalar"`.
+    pub fn tier_name(&self) -> &'static str {
+        self.simd_tier().name()
+    }
+}
+
+/// `[v3, neon, …]` → `&[SimdTier::V3, SimdTier::Neon, …]`.
+macro_rules! tier_list {
+    ([$($t:ident),*]) => { &[$(tier_list!(@$t)),*] };
+    (@v3) => { SimdTier::V3 };
+    (@neon) => { SimdTier::Neon };
+    (@wasm128) => { SimdTier::Wasm128 };
+    (@scalar) => { SimdTier::Scalar };
+}
+
+macro_rules! kernel_methods {
+    (
+        inplace { $($name:ident => $f:ident, $bpp:literal, $tiers:tt;)* }
+        copy { $($cname:ident => $cf:ident, $sbpp:literal, $dbpp:literal, $ctiers:tt;)* }
+    ) => {
+        impl Kernels {
+            $(
+                #[doc = concat!("[`", stringify!($name), "`](super::", stringify!($name), ") without per-call dispatch.")]
+                #[inline]
+                pub fn $name(&self, buf: &mut [u8]) -> Result<(), SizeError> {
+                    check_inplace(buf.len(), $bpp)?;
+                    with_tier!(self.tier, $f(buf), $tiers);
+                    Ok(())
+                }
+            )*
+            $(
+                #[doc = concat!("[`", stringify!($cname), "`](super::", stringify!($cname), ") without per-call dispatch.")]
+                #[inline]
+                pub fn $cname(&self, src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+                    check_copy(src.len(), $sbpp, dst.len(), $dbpp)?;
+                    with_tier!(self.tier, $cf(src, dst), $ctiers);
+                    Ok(())
+                }
+            )*
+        }
+
+        /// The tiers each copy method has a dedicated kernel for, as passed
+        /// to `incant!`; read by [`row_capability`] for the methods behind a
+        /// layout pair.
+        #[allow(non_upper_case_globals, dead_code)]
+        pub(crate) mod tiers {
+            use super::SimdTier;
+            $(pub(crate) const $cname: &[SimdTier] = tier_list!($ctiers);)*
+        }
+    };
+}
+
+kernel_methods! {
+    inplace {
+        rgba_to_bgra_inplace => swap_br_impl, 4, [v3, neon, wasm128, scalar];
+        fill_alpha_rgba => fill_alpha_impl, 4, [v3, neon, wasm128, scalar];
+        fill_alpha_bgra => fill_alpha_impl, 4, [v3, neon, wasm128, scalar];
+        rgb_to_bgr_inplace => swap_bgr_impl, 3, [v3, neon, wasm128, scalar];
+        argb_to_rgba_inplace => rotate_left_impl, 4, [v3, neon, wasm128, scalar];
+        rgba_to_argb_inplace => rotate_right_impl, 4, [v3, neon, wasm128, scalar];
+        argb_to_bgra_inplace => reverse_4bpp_impl, 4, [v3, neon, wasm128, scalar];
+        fill_alpha_argb => fill_alpha_first_impl, 4, [v3, neon, wasm128, scalar];
+        fill_alpha_xrgb => fill_alpha_first_impl, 4, [v3, neon, wasm128, scalar];
+        swap_bytes_u16_inplace => swap_bytes16_impl, 2, [v3, neon, wasm128, scalar];
+        swap_bytes_u32_inplace => swap_bytes32_impl, 4, [v3, neon, wasm128, scalar];
+    }
+    copy {
+        rgba_to_bgra => copy_swap_br_impl, 4, 4, [v3, neon, wasm128, scalar];
+        rgb_to_bgra => rgb_to_bgra_impl, 3, 4, [v3, wasm128, scalar];
+        rgb_to_rgba => rgb_to_rgba_impl, 3, 4, [v3, wasm128, scalar];
+        gray_to_rgba => gray_to_4bpp_impl, 1, 4, [v3, neon, wasm128, scalar];
+        gray_alpha_to_rgba => gray_alpha_to_4bpp_impl, 2, 4, [v3, neon, wasm128, scalar];
+        rgb_to_bgr => copy_swap_bgr_impl, 3, 3, [v3, wasm128, scalar];
+        rgba_to_rgb => rgba_to_rgb_impl, 4, 3, [v3, wasm128, scalar];
+        bgra_to_rgb => bgra_to_rgb_impl, 4, 3, [v3, wasm128, scalar];
+        argb_to_rgba => copy_rotate_left_impl, 4, 4, [v3, neon, wasm128, scalar];
+        rgba_to_argb => copy_rotate_right_impl, 4, 4, [v3, neon, wasm128, scalar];
+        argb_to_bgra => copy_reverse_4bpp_impl, 4, 4, [v3, neon, wasm128, scalar];
+        rgb_to_argb => rgb_to_argb_impl, 3, 4, [v3, wasm128, scalar];
+        rgb_to_abgr => rgb_to_abgr_impl, 3, 4, [v3, wasm128, scalar];
+        argb_to_rgb => argb_to_rgb_impl, 4, 3, [v3, wasm128, scalar];
+        argb_to_bgr => argb_to_bgr_impl, 4, 3, [v3, wasm128, scalar];
+        gray_to_argb => gray_to_4bpp_alpha_first_impl, 1, 4, [v3, neon, wasm128, scalar];
+        gray_alpha_to_argb => gray_alpha_to_4bpp_alpha_first_impl, 2, 4, [v3, neon, wasm128, scalar];
+        swap_bytes_u16 => copy_swap_bytes16_impl, 2, 2, [v3, neon, wasm128, scalar];
+        swap_bytes_u32 => copy_swap_bytes32_impl, 4, 4, [v3, neon, wasm128, scalar];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use crate::SizeErrorKind;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn kernels_match_free_functions() {
+        let src: Vec<u8> = (0..4 * 61).map(|i| (i * 13 + 5) as u8).collect();
+        for k in [Kernels::detect(), Kernels::scalar()] {
+            let (mut a, mut b) = (vec![0u8; 4 * 61], vec![0u8; 4 * 61]);
+            k.rgb_to_bgra(&src[..3 * 61], &mut a).unwrap();
+            rgb_to_bgra(&src[..3 * 61], &mut b).unwrap();
+            assert_eq!(a, b, "{}", k.tier_name());
+
+            k.gray_alpha_to_argb(&src[..2 * 61], &mut a).unwrap();
+            gray_alpha_to_argb(&src[..2 * 61], &mut b).unwrap();
+            assert_eq!(a, b, "{}", k.tier_name());
+
+            let (mut a, mut b) = (src.clone(), src.clone());
+            k.argb_to_bgra_inplace(&mut a).unwrap();
+            argb_to_bgra_inplace(&mut b).unwrap();
+            assert_eq!(a, b, "{}", k.tier_name());
+
+            assert_eq!(
+                k.rgba_to_rgb(&src[..7], &mut a).map_err(|e| e.kind()),
+                Err(SizeErrorKind::NotPixelAligned)
+            );
+        }
+        assert_eq!(Kernels::scalar().tier_name(), "scalar");
+    }
+}
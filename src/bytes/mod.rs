@@ -6,7 +6,7 @@
 // and strided (loop over rows, single dispatch).
 // ---------------------------------------------------------------------------
 
-use crate::SizeError;
+use crate::{Operand, SizeError, SizeErrorKind};
 use archmage::incant;
 
 mod scalar;
@@ -27,6 +27,41 @@ mod wasm;
 #[cfg(target_arch = "wasm32")]
 use wasm::*;
 
+mod palette;
+pub use palette::*;
+
+mod exec;
+pub use exec::*;
+
+mod kernels;
+pub use kernels::*;
+
+mod row;
+pub use row::*;
+
+mod gpu;
+pub use gpu::*;
+
+mod caps;
+pub use caps::*;
+
+mod repack;
+pub use repack::*;
+
+mod orient;
+pub use orient::*;
+
+mod flip;
+pub use flip::*;
+
+mod signed;
+pub use signed::*;
+
+#[cfg(feature = "parallel")]
+mod par;
+#[cfg(feature = "parallel")]
+pub use par::*;
+
 #[cfg(feature = "experimental")]
 mod packed;
 #[cfg(feature = "experimental")]
@@ -37,6 +72,21 @@ mod packed_1010102;
 #[cfg(feature = "experimental")]
 pub use packed_1010102::*;
 
+#[cfg(feature = "experimental")]
+mod dither;
+#[cfg(feature = "experimental")]
+pub use dither::*;
+
+#[cfg(feature = "experimental")]
+mod subbyte;
+#[cfg(feature = "experimental")]
+pub use subbyte::*;
+
+#[cfg(feature = "experimental")]
+mod quantize;
+#[cfg(feature = "experimental")]
+pub use quantize::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -47,7 +97,9 @@ mod tests;
 #[inline]
 fn check_inplace(len: usize, bpp: usize) -> Result<(), SizeError> {
     if len == 0 || !len.is_multiple_of(bpp) {
-        Err(SizeError::NotPixelAligned)
+        Err(SizeError::new(SizeErrorKind::NotPixelAligned)
+            .of(Operand::Buf)
+            .lengths(None, len))
     } else {
         Ok(())
     }
@@ -61,42 +113,108 @@ fn check_copy(
     dst_bpp: usize,
 ) -> Result<(), SizeError> {
     if src_len == 0 || !src_len.is_multiple_of(src_bpp) {
-        return Err(SizeError::NotPixelAligned);
+        return Err(SizeError::new(SizeErrorKind::NotPixelAligned)
+            .of(Operand::Src)
+            .lengths(None, src_len));
     }
-    if dst_len < (src_len / src_bpp) * dst_bpp {
-        return Err(SizeError::PixelCountMismatch);
+    let required = (src_len / src_bpp) * dst_bpp;
+    if dst_len < required {
+        return Err(SizeError::new(SizeErrorKind::PixelCountMismatch)
+            .of(Operand::Dst)
+            .lengths(Some(required), dst_len));
     }
     Ok(())
 }
 
+/// `op` is the buffer being checked; it is recorded in the error.
 #[inline]
-fn check_strided(
+pub(crate) fn check_strided(
+    op: Operand,
     len: usize,
     width: usize,
     height: usize,
     stride: usize,
     bpp: usize,
 ) -> Result<(), SizeError> {
+    let err = SizeError::new(SizeErrorKind::InvalidStride).of(op);
     if width == 0 || height == 0 {
-        return Err(SizeError::InvalidStride);
+        return Err(err);
     }
-    let row_bytes = width.checked_mul(bpp).ok_or(SizeError::InvalidStride)?;
+    let row_bytes = width.checked_mul(bpp).ok_or(err)?;
+    let err = err.row(row_bytes, stride);
     if row_bytes > stride {
-        return Err(SizeError::InvalidStride);
-    }
-    if height > 0 {
-        let total = (height - 1)
-            .checked_mul(stride)
-            .ok_or(SizeError::InvalidStride)?
-            .checked_add(row_bytes)
-            .ok_or(SizeError::InvalidStride)?;
-        if len < total {
-            return Err(SizeError::InvalidStride);
-        }
+        return Err(err);
+    }
+    let total = (height - 1)
+        .checked_mul(stride)
+        .and_then(|n| n.checked_add(row_bytes))
+        .ok_or(err)?;
+    if len < total {
+        return Err(err.lengths(Some(total), len));
     }
     Ok(())
 }
 
+/// Bytes needed for `width` pixels of `bits` each, rounded up to a whole byte.
+#[inline]
+const fn packed_row_bytes(width: usize, bits: u32) -> usize {
+    (width * bits as usize).div_ceil(8)
+}
+
+/// Sub-byte packed data (`bits` per pixel, rows byte-aligned): `pixels` is
+/// the 8-bit side's length and `packed` must hold all of them. `op` is the
+/// packed buffer; the 8-bit side is the other one.
+#[inline]
+fn check_packed(op: Operand, pixels: usize, packed: usize, bits: u32) -> Result<(), SizeError> {
+    if pixels == 0 {
+        let other = match op {
+            Operand::Src => Operand::Dst,
+            _ => Operand::Src,
+        };
+        return Err(SizeError::new(SizeErrorKind::NotPixelAligned)
+            .of(other)
+            .lengths(None, 0));
+    }
+    let err = SizeError::new(SizeErrorKind::PixelCountMismatch).of(op);
+    let need = pixels
+        .checked_mul(bits as usize)
+        .ok_or(err.lengths(None, packed))?
+        .div_ceil(8);
+    if packed < need {
+        return Err(err.lengths(Some(need), packed));
+    }
+    Ok(())
+}
+
+/// Floyd–Steinberg error rows: `scratch` must hold `need` elements.
+#[cfg(feature = "experimental")]
+#[inline]
+fn check_scratch(len: usize, need: usize) -> Result<(), SizeError> {
+    if len < need {
+        return Err(SizeError::new(SizeErrorKind::InvalidStride)
+            .of(Operand::Scratch)
+            .lengths(Some(need), len));
+    }
+    Ok(())
+}
+
+/// Strided packed data: `stride` must cover `ceil(width * bits / 8)` bytes.
+#[inline]
+fn check_packed_strided(
+    op: Operand,
+    len: usize,
+    width: usize,
+    height: usize,
+    stride: usize,
+    bits: u32,
+) -> Result<(), SizeError> {
+    let row_bytes = width
+        .checked_mul(bits as usize)
+        .ok_or(SizeError::new(SizeErrorKind::InvalidStride).of(op))?
+        .div_ceil(8);
+    check_strided(op, len, row_bytes, height, stride, 1)
+}
+
 // ===========================================================================
 // Utility
 // ===========================================================================
@@ -184,7 +302,7 @@ pub fn rgba_to_bgra_inplace_strided(
     height: usize,
     stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(buf.len(), width, height, stride, 4)?;
+    check_strided(Operand::Buf, buf.len(), width, height, stride, 4)?;
     incant!(
         swap_br_strided(buf, width, height, stride),
         [v3, neon, wasm128, scalar]
@@ -205,8 +323,8 @@ pub fn rgba_to_bgra_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         copy_swap_br_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, neon, wasm128, scalar]
@@ -225,7 +343,7 @@ pub fn fill_alpha_rgba_strided(
     height: usize,
     stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(buf.len(), width, height, stride, 4)?;
+    check_strided(Operand::Buf, buf.len(), width, height, stride, 4)?;
     incant!(
         fill_alpha_strided(buf, width, height, stride),
         [v3, neon, wasm128, scalar]
@@ -255,8 +373,8 @@ pub fn rgb_to_bgra_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 3)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 3)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         rgb_to_bgra_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, wasm128, scalar]
@@ -276,8 +394,8 @@ pub fn rgb_to_rgba_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 3)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 3)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         rgb_to_rgba_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, wasm128, scalar]
@@ -297,8 +415,8 @@ pub fn gray_to_rgba_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 1)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 1)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         gray_to_4bpp_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, neon, wasm128, scalar]
@@ -318,8 +436,8 @@ pub fn gray_alpha_to_rgba_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 2)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         gray_alpha_to_4bpp_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, neon, wasm128, scalar]
@@ -369,7 +487,7 @@ pub fn rgb_to_bgr_inplace_strided(
     height: usize,
     stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(buf.len(), width, height, stride, 3)?;
+    check_strided(Operand::Buf, buf.len(), width, height, stride, 3)?;
     incant!(
         swap_bgr_strided(buf, width, height, stride),
         [v3, neon, wasm128, scalar]
@@ -389,8 +507,8 @@ pub fn rgb_to_bgr_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 3)?;
-    check_strided(dst.len(), width, height, dst_stride, 3)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 3)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 3)?;
     incant!(
         copy_swap_bgr_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, wasm128, scalar]
@@ -410,8 +528,8 @@ pub fn rgba_to_rgb_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 3)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 3)?;
     incant!(
         rgba_to_rgb_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, wasm128, scalar]
@@ -431,8 +549,8 @@ pub fn bgra_to_rgb_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 3)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 3)?;
     incant!(
         bgra_to_rgb_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, wasm128, scalar]
@@ -571,7 +689,7 @@ pub fn argb_to_rgba_inplace_strided(
     height: usize,
     stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(buf.len(), width, height, stride, 4)?;
+    check_strided(Operand::Buf, buf.len(), width, height, stride, 4)?;
     incant!(
         rotate_left_strided(buf, width, height, stride),
         [v3, neon, wasm128, scalar]
@@ -588,8 +706,8 @@ pub fn argb_to_rgba_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         copy_rotate_left_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, neon, wasm128, scalar]
@@ -604,7 +722,7 @@ pub fn rgba_to_argb_inplace_strided(
     height: usize,
     stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(buf.len(), width, height, stride, 4)?;
+    check_strided(Operand::Buf, buf.len(), width, height, stride, 4)?;
     incant!(
         rotate_right_strided(buf, width, height, stride),
         [v3, neon, wasm128, scalar]
@@ -621,8 +739,8 @@ pub fn rgba_to_argb_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         copy_rotate_right_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, neon, wasm128, scalar]
@@ -637,7 +755,7 @@ pub fn argb_to_bgra_inplace_strided(
     height: usize,
     stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(buf.len(), width, height, stride, 4)?;
+    check_strided(Operand::Buf, buf.len(), width, height, stride, 4)?;
     incant!(
         reverse_4bpp_strided(buf, width, height, stride),
         [v3, neon, wasm128, scalar]
@@ -654,8 +772,8 @@ pub fn argb_to_bgra_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         copy_reverse_4bpp_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, neon, wasm128, scalar]
@@ -670,7 +788,7 @@ pub fn fill_alpha_argb_strided(
     height: usize,
     stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(buf.len(), width, height, stride, 4)?;
+    check_strided(Operand::Buf, buf.len(), width, height, stride, 4)?;
     incant!(
         fill_alpha_first_strided(buf, width, height, stride),
         [v3, neon, wasm128, scalar]
@@ -697,8 +815,8 @@ pub fn rgb_to_argb_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 3)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 3)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         rgb_to_argb_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, wasm128, scalar]
@@ -715,8 +833,8 @@ pub fn rgb_to_abgr_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 3)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 3)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         rgb_to_abgr_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, wasm128, scalar]
@@ -733,8 +851,8 @@ pub fn argb_to_rgb_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 3)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 3)?;
     incant!(
         argb_to_rgb_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, wasm128, scalar]
@@ -751,8 +869,8 @@ pub fn argb_to_bgr_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 3)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 3)?;
     incant!(
         argb_to_bgr_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, wasm128, scalar]
@@ -769,8 +887,8 @@ pub fn gray_to_argb_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 1)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 1)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         gray_to_4bpp_alpha_first_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, neon, wasm128, scalar]
@@ -787,8 +905,8 @@ pub fn gray_alpha_to_argb_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 2)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
     incant!(
         gray_alpha_to_4bpp_alpha_first_strided(src, dst, width, height, src_stride, dst_stride),
         [v3, neon, wasm128, scalar]
@@ -796,6 +914,124 @@ pub fn gray_alpha_to_argb_strided(
     Ok(())
 }
 
+// ===========================================================================
+// Byte-order swaps — 16-bit and 32-bit samples
+// ===========================================================================
+//
+// Reverse the bytes of every u16 / u32 element. Use these to turn big-endian
+// sample data (PNG 16-bit, TIFF/PNM "MM" files, BE float TIFF) into native
+// order, or back. The operation is its own inverse.
+
+/// Reverse the byte order of every 2-byte element in-place (u16 BE↔LE).
+pub fn swap_bytes_u16_inplace(buf: &mut [u8]) -> Result<(), SizeError> {
+    check_inplace(buf.len(), 2)?;
+    incant!(swap_bytes16_impl(buf), [v3, neon, wasm128, scalar]);
+    Ok(())
+}
+
+/// Reverse the byte order of every 4-byte element in-place (u32/f32 BE↔LE).
+pub fn swap_bytes_u32_inplace(buf: &mut [u8]) -> Result<(), SizeError> {
+    check_inplace(buf.len(), 4)?;
+    incant!(swap_bytes32_impl(buf), [v3, neon, wasm128, scalar]);
+    Ok(())
+}
+
+/// Copy 2-byte elements, reversing the byte order of each (u16 BE↔LE).
+pub fn swap_bytes_u16(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 2, dst.len(), 2)?;
+    incant!(
+        copy_swap_bytes16_impl(src, dst),
+        [v3, neon, wasm128, scalar]
+    );
+    Ok(())
+}
+
+/// Copy 4-byte elements, reversing the byte order of each (u32/f32 BE↔LE).
+pub fn swap_bytes_u32(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 4)?;
+    incant!(
+        copy_swap_bytes32_impl(src, dst),
+        [v3, neon, wasm128, scalar]
+    );
+    Ok(())
+}
+
+/// Reverse the byte order of every 2-byte element in a strided buffer.
+///
+/// `width` is the number of u16 elements per row (e.g. `pixels × 4` for RGBA64).
+/// `stride` is in bytes and must be ≥ `width × 2`. Padding is never touched.
+pub fn swap_bytes_u16_inplace_strided(
+    buf: &mut [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Buf, buf.len(), width, height, stride, 2)?;
+    incant!(
+        swap_bytes16_strided(buf, width, height, stride),
+        [v3, neon, wasm128, scalar]
+    );
+    Ok(())
+}
+
+/// Reverse the byte order of every 4-byte element in a strided buffer.
+///
+/// `width` is the number of u32/f32 elements per row.
+/// `stride` is in bytes and must be ≥ `width × 4`. Padding is never touched.
+pub fn swap_bytes_u32_inplace_strided(
+    buf: &mut [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Buf, buf.len(), width, height, stride, 4)?;
+    incant!(
+        swap_bytes32_strided(buf, width, height, stride),
+        [v3, neon, wasm128, scalar]
+    );
+    Ok(())
+}
+
+/// Copy 2-byte elements between strided buffers, reversing each one's bytes.
+///
+/// `width` is the number of u16 elements per row; strides are in bytes.
+pub fn swap_bytes_u16_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    incant!(
+        copy_swap_bytes16_strided(src, dst, width, height, src_stride, dst_stride),
+        [v3, neon, wasm128, scalar]
+    );
+    Ok(())
+}
+
+/// Copy 4-byte elements between strided buffers, reversing each one's bytes.
+///
+/// `width` is the number of u32/f32 elements per row; strides are in bytes.
+pub fn swap_bytes_u32_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    incant!(
+        copy_swap_bytes32_strided(src, dst, width, height, src_stride, dst_stride),
+        [v3, neon, wasm128, scalar]
+    );
+    Ok(())
+}
+
 #[cfg(feature = "experimental")]
 mod experimental_api {
     use super::*;
@@ -845,8 +1081,8 @@ mod experimental_api {
             pub fn [<rgb_to_gray_ $matrix _strided>](
                 src: &[u8], dst: &mut [u8], width: usize, height: usize, src_stride: usize, dst_stride: usize,
             ) -> Result<(), SizeError> {
-                check_strided(src.len(), width, height, src_stride, 3)?;
-                check_strided(dst.len(), width, height, dst_stride, 1)?;
+                check_strided(Operand::Src, src.len(), width, height, src_stride, 3)?;
+                check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
                 incant!([<rgb_to_gray_ $matrix _strided>](src, dst, width, height, src_stride, dst_stride), [v3, scalar]);
                 Ok(())
             }
@@ -855,8 +1091,8 @@ mod experimental_api {
             pub fn [<bgr_to_gray_ $matrix _strided>](
                 src: &[u8], dst: &mut [u8], width: usize, height: usize, src_stride: usize, dst_stride: usize,
             ) -> Result<(), SizeError> {
-                check_strided(src.len(), width, height, src_stride, 3)?;
-                check_strided(dst.len(), width, height, dst_stride, 1)?;
+                check_strided(Operand::Src, src.len(), width, height, src_stride, 3)?;
+                check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
                 incant!([<bgr_to_gray_ $matrix _strided>](src, dst, width, height, src_stride, dst_stride), [v3, scalar]);
                 Ok(())
             }
@@ -865,8 +1101,8 @@ mod experimental_api {
             pub fn [<rgba_to_gray_ $matrix _strided>](
                 src: &[u8], dst: &mut [u8], width: usize, height: usize, src_stride: usize, dst_stride: usize,
             ) -> Result<(), SizeError> {
-                check_strided(src.len(), width, height, src_stride, 4)?;
-                check_strided(dst.len(), width, height, dst_stride, 1)?;
+                check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+                check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
                 incant!([<rgba_to_gray_ $matrix _strided>](src, dst, width, height, src_stride, dst_stride), [v3, scalar]);
                 Ok(())
             }
@@ -875,8 +1111,8 @@ mod experimental_api {
             pub fn [<bgra_to_gray_ $matrix _strided>](
                 src: &[u8], dst: &mut [u8], width: usize, height: usize, src_stride: usize, dst_stride: usize,
             ) -> Result<(), SizeError> {
-                check_strided(src.len(), width, height, src_stride, 4)?;
-                check_strided(dst.len(), width, height, dst_stride, 1)?;
+                check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+                check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
                 incant!([<bgra_to_gray_ $matrix _strided>](src, dst, width, height, src_stride, dst_stride), [v3, scalar]);
                 Ok(())
             }
@@ -920,9 +1156,6 @@ mod experimental_api {
     ///
     /// `src` contains u8 values. `dst` must have at least `src.len() * 2` bytes.
     pub fn convert_u8_to_u16(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
-        if src.is_empty() {
-            return Err(SizeError::NotPixelAligned);
-        }
         check_copy(src.len(), 1, dst.len(), 2)?;
         incant!(convert_u8_to_u16_impl(src, dst), [v3, neon, scalar]);
         Ok(())
@@ -942,9 +1175,6 @@ mod experimental_api {
     ///
     /// `src` contains u8 values. `dst` must have at least `src.len() * 4` bytes.
     pub fn convert_u8_to_f32(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
-        if src.is_empty() {
-            return Err(SizeError::NotPixelAligned);
-        }
         check_copy(src.len(), 1, dst.len(), 4)?;
         incant!(convert_u8_to_f32_impl(src, dst), [v3, neon, scalar]);
         Ok(())
@@ -980,15 +1210,43 @@ mod experimental_api {
         Ok(())
     }
 
+    // Big-endian u16 variants. Same kernels with the byte swap folded in, for
+    // TIFF/PNG/network data stored high byte first.
+
+    /// Like [`convert_u8_to_u16`], but writes big-endian u16.
+    pub fn convert_u8_to_u16be(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+        check_copy(src.len(), 1, dst.len(), 2)?;
+        incant!(convert_u8_to_u16be_impl(src, dst), [v3, neon, scalar]);
+        Ok(())
+    }
+
+    /// Like [`convert_u16_to_u8`], but reads big-endian u16.
+    pub fn convert_u16be_to_u8(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+        check_copy(src.len(), 2, dst.len(), 1)?;
+        incant!(convert_u16be_to_u8_impl(src, dst), [v3, neon, scalar]);
+        Ok(())
+    }
+
+    /// Like [`convert_u16_to_f32`], but reads big-endian u16.
+    pub fn convert_u16be_to_f32(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+        check_copy(src.len(), 2, dst.len(), 4)?;
+        incant!(convert_u16be_to_f32_impl(src, dst), [v3, neon, scalar]);
+        Ok(())
+    }
+
+    /// Like [`convert_f32_to_u16`], but writes big-endian u16.
+    pub fn convert_f32_to_u16be(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+        check_copy(src.len(), 4, dst.len(), 2)?;
+        incant!(convert_f32_to_u16be_impl(src, dst), [v3, neon, scalar]);
+        Ok(())
+    }
+
     // ===========================================================================
     // Gray layout conversions — no luma weights
     // ===========================================================================
 
     /// Gray (1 byte/px) → RGB/BGR (3 bytes/px). R=G=B=gray.
     pub fn gray_to_rgb(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
-        if src.is_empty() {
-            return Err(SizeError::NotPixelAligned);
-        }
         check_copy(src.len(), 1, dst.len(), 3)?;
         incant!(gray_to_rgb_impl(src, dst), [scalar]);
         Ok(())
@@ -1003,9 +1261,6 @@ mod experimental_api {
 
     /// Gray (1 byte/px) → GrayAlpha (2 bytes/px). Alpha set to 255.
     pub fn gray_to_gray_alpha(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
-        if src.is_empty() {
-            return Err(SizeError::NotPixelAligned);
-        }
         check_copy(src.len(), 1, dst.len(), 2)?;
         incant!(gray_to_gray_alpha_impl(src, dst), [scalar]);
         Ok(())
@@ -1073,8 +1328,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 1)?;
-        check_strided(dst.len(), width, height, dst_stride, 3)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 1)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 3)?;
         incant!(
             gray_to_rgb_strided(src, dst, width, height, src_stride, dst_stride),
             [scalar]
@@ -1091,8 +1346,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 2)?;
-        check_strided(dst.len(), width, height, dst_stride, 3)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 3)?;
         incant!(
             gray_alpha_to_rgb_strided(src, dst, width, height, src_stride, dst_stride),
             [scalar]
@@ -1109,8 +1364,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 1)?;
-        check_strided(dst.len(), width, height, dst_stride, 2)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 1)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
         incant!(
             gray_to_gray_alpha_strided(src, dst, width, height, src_stride, dst_stride),
             [scalar]
@@ -1127,8 +1382,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 2)?;
-        check_strided(dst.len(), width, height, dst_stride, 1)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
         incant!(
             gray_alpha_to_gray_strided(src, dst, width, height, src_stride, dst_stride),
             [scalar]
@@ -1145,8 +1400,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 3)?;
-        check_strided(dst.len(), width, height, dst_stride, 1)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 3)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
         incant!(
             rgb_to_gray_identity_strided(src, dst, width, height, src_stride, dst_stride),
             [scalar]
@@ -1163,8 +1418,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 4)?;
-        check_strided(dst.len(), width, height, dst_stride, 1)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
         incant!(
             rgba_to_gray_identity_strided(src, dst, width, height, src_stride, dst_stride),
             [scalar]
@@ -1235,8 +1490,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 1)?;
-        check_strided(dst.len(), width, height, dst_stride, 2)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 1)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
         incant!(
             convert_u8_to_u16_strided(src, dst, width, height, src_stride, dst_stride),
             [v3, neon, scalar]
@@ -1253,8 +1508,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 2)?;
-        check_strided(dst.len(), width, height, dst_stride, 1)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
         incant!(
             convert_u16_to_u8_strided(src, dst, width, height, src_stride, dst_stride),
             [v3, neon, scalar]
@@ -1271,8 +1526,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 1)?;
-        check_strided(dst.len(), width, height, dst_stride, 4)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 1)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
         incant!(
             convert_u8_to_f32_strided(src, dst, width, height, src_stride, dst_stride),
             [v3, neon, scalar]
@@ -1289,8 +1544,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 4)?;
-        check_strided(dst.len(), width, height, dst_stride, 1)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
         incant!(
             convert_f32_to_u8_strided(src, dst, width, height, src_stride, dst_stride),
             [v3, neon, scalar]
@@ -1307,8 +1562,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 2)?;
-        check_strided(dst.len(), width, height, dst_stride, 4)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
         incant!(
             convert_u16_to_f32_strided(src, dst, width, height, src_stride, dst_stride),
             [v3, neon, scalar]
@@ -1325,8 +1580,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 4)?;
-        check_strided(dst.len(), width, height, dst_stride, 2)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
         incant!(
             convert_f32_to_u16_strided(src, dst, width, height, src_stride, dst_stride),
             [v3, neon, scalar]
@@ -1334,6 +1589,78 @@ mod experimental_api {
         Ok(())
     }
 
+    /// Like [`convert_u8_to_u16_strided`], but with big-endian u16. `width` is element count per row.
+    pub fn convert_u8_to_u16be_strided(
+        src: &[u8],
+        dst: &mut [u8],
+        width: usize,
+        height: usize,
+        src_stride: usize,
+        dst_stride: usize,
+    ) -> Result<(), SizeError> {
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 1)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+        incant!(
+            convert_u8_to_u16be_strided(src, dst, width, height, src_stride, dst_stride),
+            [v3, neon, scalar]
+        );
+        Ok(())
+    }
+
+    /// Like [`convert_u16_to_u8_strided`], but with big-endian u16. `width` is element count per row.
+    pub fn convert_u16be_to_u8_strided(
+        src: &[u8],
+        dst: &mut [u8],
+        width: usize,
+        height: usize,
+        src_stride: usize,
+        dst_stride: usize,
+    ) -> Result<(), SizeError> {
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
+        incant!(
+            convert_u16be_to_u8_strided(src, dst, width, height, src_stride, dst_stride),
+            [v3, neon, scalar]
+        );
+        Ok(())
+    }
+
+    /// Like [`convert_u16_to_f32_strided`], but with big-endian u16. `width` is element count per row.
+    pub fn convert_u16be_to_f32_strided(
+        src: &[u8],
+        dst: &mut [u8],
+        width: usize,
+        height: usize,
+        src_stride: usize,
+        dst_stride: usize,
+    ) -> Result<(), SizeError> {
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+        incant!(
+            convert_u16be_to_f32_strided(src, dst, width, height, src_stride, dst_stride),
+            [v3, neon, scalar]
+        );
+        Ok(())
+    }
+
+    /// Like [`convert_f32_to_u16_strided`], but with big-endian u16. `width` is element count per row.
+    pub fn convert_f32_to_u16be_strided(
+        src: &[u8],
+        dst: &mut [u8],
+        width: usize,
+        height: usize,
+        src_stride: usize,
+        dst_stride: usize,
+    ) -> Result<(), SizeError> {
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+        incant!(
+            convert_f32_to_u16be_strided(src, dst, width, height, src_stride, dst_stride),
+            [v3, neon, scalar]
+        );
+        Ok(())
+    }
+
     // ===========================================================================
     // f32 alpha premultiplication
     // ===========================================================================
@@ -1412,7 +1739,7 @@ mod experimental_api {
         height: usize,
         stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(buf.len(), width, height, stride, 16)?;
+        check_strided(Operand::Buf, buf.len(), width, height, stride, 16)?;
         incant!(
             premul_f32_strided(buf, width, height, stride),
             [v3, neon, scalar]
@@ -1429,8 +1756,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 16)?;
-        check_strided(dst.len(), width, height, dst_stride, 16)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 16)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 16)?;
         incant!(
             premul_f32_copy_strided(src, dst, width, height, src_stride, dst_stride),
             [v3, neon, scalar]
@@ -1449,7 +1776,7 @@ mod experimental_api {
         height: usize,
         stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(buf.len(), width, height, stride, 16)?;
+        check_strided(Operand::Buf, buf.len(), width, height, stride, 16)?;
         incant!(
             unpremul_f32_strided(buf, width, height, stride),
             [v3, neon, scalar]
@@ -1468,8 +1795,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 16)?;
-        check_strided(dst.len(), width, height, dst_stride, 16)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 16)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 16)?;
         incant!(
             unpremul_f32_copy_strided(src, dst, width, height, src_stride, dst_stride),
             [v3, neon, scalar]
@@ -1528,7 +1855,7 @@ mod experimental_api {
         height: usize,
         stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(buf.len(), width, height, stride, 4)?;
+        check_strided(Operand::Buf, buf.len(), width, height, stride, 4)?;
         premul_u8_strided_impl(buf, width, height, stride);
         Ok(())
     }
@@ -1553,8 +1880,8 @@ mod experimental_api {
         src_stride: usize,
         dst_stride: usize,
     ) -> Result<(), SizeError> {
-        check_strided(src.len(), width, height, src_stride, 4)?;
-        check_strided(dst.len(), width, height, dst_stride, 4)?;
+        check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
         premul_u8_copy_strided_impl(src, dst, width, height, src_stride, dst_stride);
         Ok(())
     }
@@ -704,6 +704,140 @@ pub(super) fn gray_alpha_to_4bpp_alpha_first_strided_neon(
     }
 }
 
+// ===========================================================================
+// Byte-order swaps (u16 / u32 samples)
+// ===========================================================================
+//
+// `N` is the sample width in bytes (2 or 4); every N-byte group is reversed.
+
+#[rite]
+pub(super) fn swap_bytes_row_neon<const N: usize>(_token: NeonToken, row: &mut [u8]) {
+    let mask_bytes: [u8; 16] = if N == 2 {
+        [1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14]
+    } else {
+        [3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12]
+    };
+    let mask = vld1q_u8(&mask_bytes);
+    let n = row.len();
+    let mut i = 0;
+    while i + 16 <= n {
+        let arr: &[u8; 16] = row[i..i + 16].try_into().unwrap();
+        let v = vld1q_u8(arr);
+        let shuffled = vqtbl1q_u8(v, mask);
+        let out: &mut [u8; 16] = (&mut row[i..i + 16]).try_into().unwrap();
+        vst1q_u8(out, shuffled);
+        i += 16;
+    }
+    for v in row[i..].chunks_exact_mut(N) {
+        v.reverse();
+    }
+}
+
+#[rite]
+pub(super) fn copy_swap_bytes_row_neon<const N: usize>(
+    _token: NeonToken,
+    src: &[u8],
+    dst: &mut [u8],
+) {
+    let mask_bytes: [u8; 16] = if N == 2 {
+        [1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14]
+    } else {
+        [3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12]
+    };
+    let mask = vld1q_u8(&mask_bytes);
+    let n = src.len().min(dst.len());
+    let mut i = 0;
+    while i + 16 <= n {
+        let s: &[u8; 16] = src[i..i + 16].try_into().unwrap();
+        let v = vld1q_u8(s);
+        let shuffled = vqtbl1q_u8(v, mask);
+        let d: &mut [u8; 16] = (&mut dst[i..i + 16]).try_into().unwrap();
+        vst1q_u8(d, shuffled);
+        i += 16;
+    }
+    for (s, d) in src[i..].chunks_exact(N).zip(dst[i..].chunks_exact_mut(N)) {
+        for (j, b) in d.iter_mut().enumerate() {
+            *b = s[N - 1 - j];
+        }
+    }
+}
+
+#[arcane]
+pub(super) fn swap_bytes16_impl_neon(t: NeonToken, b: &mut [u8]) {
+    swap_bytes_row_neon::<2>(t, b);
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes16_impl_neon(t: NeonToken, s: &[u8], d: &mut [u8]) {
+    copy_swap_bytes_row_neon::<2>(t, s, d);
+}
+
+#[arcane]
+pub(super) fn swap_bytes16_strided_neon(
+    t: NeonToken,
+    buf: &mut [u8],
+    w: usize,
+    h: usize,
+    stride: usize,
+) {
+    for y in 0..h {
+        swap_bytes_row_neon::<2>(t, &mut buf[y * stride..][..w * 2]);
+    }
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes16_strided_neon(
+    t: NeonToken,
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+) {
+    for y in 0..h {
+        copy_swap_bytes_row_neon::<2>(t, &src[y * ss..][..w * 2], &mut dst[y * ds..][..w * 2]);
+    }
+}
+
+#[arcane]
+pub(super) fn swap_bytes32_impl_neon(t: NeonToken, b: &mut [u8]) {
+    swap_bytes_row_neon::<4>(t, b);
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes32_impl_neon(t: NeonToken, s: &[u8], d: &mut [u8]) {
+    copy_swap_bytes_row_neon::<4>(t, s, d);
+}
+
+#[arcane]
+pub(super) fn swap_bytes32_strided_neon(
+    t: NeonToken,
+    buf: &mut [u8],
+    w: usize,
+    h: usize,
+    stride: usize,
+) {
+    for y in 0..h {
+        swap_bytes_row_neon::<4>(t, &mut buf[y * stride..][..w * 4]);
+    }
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes32_strided_neon(
+    t: NeonToken,
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+) {
+    for y in 0..h {
+        copy_swap_bytes_row_neon::<4>(t, &src[y * ss..][..w * 4], &mut dst[y * ds..][..w * 4]);
+    }
+}
+
 // ===========================================================================
 // Experimental: depth conversion + premultiply (feature = "experimental")
 //
@@ -732,11 +866,46 @@ mod experimental {
 
     // -----------------------------------------------------------------------
     // Depth conversions
+    //
+    // u16 kernels take `BE`: false = native-endian u16, true = big-endian.
+    // The swap is one vrev16q_u8 on the loaded/stored vector.
     // -----------------------------------------------------------------------
 
+    #[inline(always)]
+    fn read_u16<const BE: bool>(b: &[u8]) -> u16 {
+        if BE {
+            u16::from_be_bytes([b[0], b[1]])
+        } else {
+            u16::from_ne_bytes([b[0], b[1]])
+        }
+    }
+
+    #[inline(always)]
+    fn u16_bytes<const BE: bool>(v: u16) -> [u8; 2] {
+        if BE { v.to_be_bytes() } else { v.to_ne_bytes() }
+    }
+
+    /// Load 8 × u16, byte-swapping each lane when `BE`.
+    #[rite]
+    fn load_u16x8<const BE: bool>(_t: NeonToken, chunk: &[u8; 16]) -> uint16x8_t {
+        let b = vld1q_u8(chunk);
+        vreinterpretq_u16_u8(if BE { vrev16q_u8(b) } else { b })
+    }
+
+    /// Store 8 × u16, byte-swapping each lane when `BE`.
+    #[rite]
+    fn store_u16x8<const BE: bool>(_t: NeonToken, chunk: &mut [u8; 16], v: uint16x8_t) {
+        let b = vreinterpretq_u8_u16(v);
+        vst1q_u8(chunk, if BE { vrev16q_u8(b) } else { b });
+    }
+
     /// u8 → u16 via `v * 257`. 16 elements/iter.
     #[rite]
-    pub(in crate::bytes) fn convert_u8_to_u16_row_neon(_t: NeonToken, src: &[u8], dst: &mut [u8]) {
+    pub(in crate::bytes) fn convert_u8_to_u16_row_neon<const BE: bool>(
+        _t: NeonToken,
+        src: &[u8],
+        dst: &mut [u8],
+    ) {
         let n = src.len().min(dst.len() / 2);
         let mut i = 0;
         while i + 16 <= n {
@@ -745,24 +914,28 @@ mod experimental {
             let lo = vmulq_n_u16(vmovl_u8(vget_low_u8(v)), 257);
             let hi = vmulq_n_u16(vmovl_high_u8(v), 257);
             let d0: &mut [u8; 16] = (&mut dst[i * 2..i * 2 + 16]).try_into().unwrap();
-            vst1q_u8(d0, vreinterpretq_u8_u16(lo));
+            store_u16x8::<BE>(_t, d0, lo);
             let d1: &mut [u8; 16] = (&mut dst[i * 2 + 16..i * 2 + 32]).try_into().unwrap();
-            vst1q_u8(d1, vreinterpretq_u8_u16(hi));
+            store_u16x8::<BE>(_t, d1, hi);
             i += 16;
         }
         for j in i..n {
-            dst[j * 2..j * 2 + 2].copy_from_slice(&((src[j] as u16) * 257).to_ne_bytes());
+            dst[j * 2..j * 2 + 2].copy_from_slice(&u16_bytes::<BE>(src[j] as u16 * 257));
         }
     }
 
     /// u16 → u8 via `(v * 255 + 32768) >> 16`. 8 elements/iter.
     #[rite]
-    pub(in crate::bytes) fn convert_u16_to_u8_row_neon(_t: NeonToken, src: &[u8], dst: &mut [u8]) {
+    pub(in crate::bytes) fn convert_u16_to_u8_row_neon<const BE: bool>(
+        _t: NeonToken,
+        src: &[u8],
+        dst: &mut [u8],
+    ) {
         let n = (src.len() / 2).min(dst.len());
         let mut i = 0;
         while i + 8 <= n {
             let s: &[u8; 16] = src[i * 2..i * 2 + 16].try_into().unwrap();
-            let v = vreinterpretq_u16_u8(vld1q_u8(s));
+            let v = load_u16x8::<BE>(_t, s);
             let lo = vshrq_n_u32::<16>(vaddq_u32(
                 vmulq_n_u32(vmovl_u16(vget_low_u16(v)), 255),
                 vdupq_n_u32(32768),
@@ -778,7 +951,7 @@ mod experimental {
             i += 8;
         }
         for j in i..n {
-            let v = u16::from_ne_bytes([src[j * 2], src[j * 2 + 1]]);
+            let v = read_u16::<BE>(&src[j * 2..]);
             dst[j] = ((v as u32 * 255 + 32768) >> 16) as u8;
         }
     }
@@ -823,14 +996,18 @@ mod experimental {
 
     /// u16 → f32 via `v / 65535.0`. 8 elements/iter.
     #[rite]
-    pub(in crate::bytes) fn convert_u16_to_f32_row_neon(_t: NeonToken, src: &[u8], dst: &mut [u8]) {
+    pub(in crate::bytes) fn convert_u16_to_f32_row_neon<const BE: bool>(
+        _t: NeonToken,
+        src: &[u8],
+        dst: &mut [u8],
+    ) {
         // Reciprocal-multiply, matching the AVX2 tier. See convert_u8_to_f32.
         let scale = vdupq_n_f32(1.0 / 65535.0);
         let n = (src.len() / 2).min(dst.len() / 4);
         let mut i = 0;
         while i + 8 <= n {
             let s: &[u8; 16] = src[i * 2..i * 2 + 16].try_into().unwrap();
-            let v = vreinterpretq_u16_u8(vld1q_u8(s));
+            let v = load_u16x8::<BE>(_t, s);
             let f0 = vmulq_f32(vcvtq_f32_u32(vmovl_u16(vget_low_u16(v))), scale);
             let f1 = vmulq_f32(vcvtq_f32_u32(vmovl_high_u16(v)), scale);
             let base = i * 4;
@@ -841,7 +1018,7 @@ mod experimental {
             i += 8;
         }
         for j in i..n {
-            let v = u16::from_ne_bytes([src[j * 2], src[j * 2 + 1]]);
+            let v = read_u16::<BE>(&src[j * 2..]);
             dst[j * 4..j * 4 + 4].copy_from_slice(&(v as f32 / 65535.0).to_ne_bytes());
         }
     }
@@ -883,7 +1060,11 @@ mod experimental {
 
     /// f32 → u16 via `clamp(v,0,1) * 65535 + 0.5`, truncated. 8 elements/iter.
     #[rite]
-    pub(in crate::bytes) fn convert_f32_to_u16_row_neon(_t: NeonToken, src: &[u8], dst: &mut [u8]) {
+    pub(in crate::bytes) fn convert_f32_to_u16_row_neon<const BE: bool>(
+        _t: NeonToken,
+        src: &[u8],
+        dst: &mut [u8],
+    ) {
         let scale = vdupq_n_f32(65535.0);
         let n = (src.len() / 4).min(dst.len() / 2);
         let mut i = 0;
@@ -897,14 +1078,14 @@ mod experimental {
             }
             let packed = vcombine_u16(vmovn_u32(q[0]), vmovn_u32(q[1]));
             let out: &mut [u8; 16] = (&mut dst[i * 2..i * 2 + 16]).try_into().unwrap();
-            vst1q_u8(out, vreinterpretq_u8_u16(packed));
+            store_u16x8::<BE>(_t, out, packed);
             i += 8;
         }
         for j in i..n {
             let s: &[u8; 4] = src[j * 4..j * 4 + 4].try_into().unwrap();
             let v = f32::from_ne_bytes(*s);
             dst[j * 2..j * 2 + 2]
-                .copy_from_slice(&((v.clamp(0.0, 1.0) * 65535.0 + 0.5) as u16).to_ne_bytes());
+                .copy_from_slice(&u16_bytes::<BE>((v.clamp(0.0, 1.0) * 65535.0 + 0.5) as u16));
         }
     }
 
@@ -1057,7 +1238,7 @@ mod experimental {
     // -----------------------------------------------------------------------
 
     macro_rules! neon_copy_impl {
-        ($name:ident, $row:ident) => {
+        ($name:ident, $row:expr) => {
             #[arcane]
             pub(in crate::bytes) fn $name(t: NeonToken, s: &[u8], d: &mut [u8]) {
                 $row(t, s, d);
@@ -1073,12 +1254,40 @@ mod experimental {
         };
     }
 
-    neon_copy_impl!(convert_u8_to_u16_impl_neon, convert_u8_to_u16_row_neon);
-    neon_copy_impl!(convert_u16_to_u8_impl_neon, convert_u16_to_u8_row_neon);
+    neon_copy_impl!(
+        convert_u8_to_u16_impl_neon,
+        convert_u8_to_u16_row_neon::<false>
+    );
+    neon_copy_impl!(
+        convert_u16_to_u8_impl_neon,
+        convert_u16_to_u8_row_neon::<false>
+    );
     neon_copy_impl!(convert_u8_to_f32_impl_neon, convert_u8_to_f32_row_neon);
     neon_copy_impl!(convert_f32_to_u8_impl_neon, convert_f32_to_u8_row_neon);
-    neon_copy_impl!(convert_u16_to_f32_impl_neon, convert_u16_to_f32_row_neon);
-    neon_copy_impl!(convert_f32_to_u16_impl_neon, convert_f32_to_u16_row_neon);
+    neon_copy_impl!(
+        convert_u16_to_f32_impl_neon,
+        convert_u16_to_f32_row_neon::<false>
+    );
+    neon_copy_impl!(
+        convert_f32_to_u16_impl_neon,
+        convert_f32_to_u16_row_neon::<false>
+    );
+    neon_copy_impl!(
+        convert_u8_to_u16be_impl_neon,
+        convert_u8_to_u16_row_neon::<true>
+    );
+    neon_copy_impl!(
+        convert_u16be_to_u8_impl_neon,
+        convert_u16_to_u8_row_neon::<true>
+    );
+    neon_copy_impl!(
+        convert_u16be_to_f32_impl_neon,
+        convert_u16_to_f32_row_neon::<true>
+    );
+    neon_copy_impl!(
+        convert_f32_to_u16be_impl_neon,
+        convert_f32_to_u16_row_neon::<true>
+    );
     neon_copy_impl!(premul_f32_copy_impl_neon, premul_f32_copy_row_neon);
     neon_copy_impl!(unpremul_f32_copy_impl_neon, unpremul_f32_copy_row_neon);
     neon_inplace_impl!(premul_f32_impl_neon, premul_f32_row_neon);
@@ -1090,7 +1299,7 @@ mod experimental {
 
     /// Strided copy wrapper. `$sb`/`$db` are source/dest bytes per element.
     macro_rules! neon_copy_strided {
-        ($name:ident, $row:ident, $sb:expr, $db:expr) => {
+        ($name:ident, $row:expr, $sb:expr, $db:expr) => {
             #[arcane]
             pub(in crate::bytes) fn $name(
                 t: NeonToken,
@@ -1110,13 +1319,13 @@ mod experimental {
 
     neon_copy_strided!(
         convert_u8_to_u16_strided_neon,
-        convert_u8_to_u16_row_neon,
+        convert_u8_to_u16_row_neon::<false>,
         1,
         2
     );
     neon_copy_strided!(
         convert_u16_to_u8_strided_neon,
-        convert_u16_to_u8_row_neon,
+        convert_u16_to_u8_row_neon::<false>,
         2,
         1
     );
@@ -1134,13 +1343,37 @@ mod experimental {
     );
     neon_copy_strided!(
         convert_u16_to_f32_strided_neon,
-        convert_u16_to_f32_row_neon,
+        convert_u16_to_f32_row_neon::<false>,
         2,
         4
     );
     neon_copy_strided!(
         convert_f32_to_u16_strided_neon,
-        convert_f32_to_u16_row_neon,
+        convert_f32_to_u16_row_neon::<false>,
+        4,
+        2
+    );
+    neon_copy_strided!(
+        convert_u8_to_u16be_strided_neon,
+        convert_u8_to_u16_row_neon::<true>,
+        1,
+        2
+    );
+    neon_copy_strided!(
+        convert_u16be_to_u8_strided_neon,
+        convert_u16_to_u8_row_neon::<true>,
+        2,
+        1
+    );
+    neon_copy_strided!(
+        convert_u16be_to_f32_strided_neon,
+        convert_u16_to_f32_row_neon::<true>,
+        2,
+        4
+    );
+    neon_copy_strided!(
+        convert_f32_to_u16be_strided_neon,
+        convert_f32_to_u16_row_neon::<true>,
         4,
         2
     );
@@ -1186,4 +1419,3 @@ mod experimental {
 
 #[cfg(feature = "experimental")]
 pub(super) use experimental::*;
-
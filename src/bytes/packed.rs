@@ -5,7 +5,7 @@
 use archmage::prelude::*;
 
 use super::{check_copy, check_strided};
-use crate::SizeError;
+use crate::{Operand, SizeError};
 
 // ===========================================================================
 // Scalar implementations — expansion (2bpp → 4bpp)
@@ -24,10 +24,10 @@ use crate::SizeError;
 //
 // ## Big-endian data
 //
-// These functions expect **little-endian** u16 values in the byte slice.
-// If your data is big-endian (e.g. from a network protocol or a big-endian
-// file format), swap each pair of bytes before calling these functions.
-// A simple pre-pass: `for pair in src.chunks_exact_mut(2) { pair.swap(0, 1); }`
+// Every kernel takes `const BE: bool`. `false` reads/writes little-endian
+// u16 (the default functions); `true` backs the `…be` functions for data
+// stored high byte first (RFB/VNC, big-endian TIFF). The swap is folded into
+// the load/store, so there is no extra pass.
 //
 // ## Expansion rounding
 //
@@ -35,11 +35,25 @@ use crate::SizeError;
 // vacated low bits, which maps the full source range [0, max] to [0, 255]
 // exactly (e.g. 5-bit 31 → `31<<3 | 31>>2` = 255, 6-bit 63 → `63<<2 | 63>>4` = 255).
 
+#[inline(always)]
+fn read_u16<const BE: bool>(b: &[u8]) -> u16 {
+    if BE {
+        u16::from_be_bytes([b[0], b[1]])
+    } else {
+        u16::from_le_bytes([b[0], b[1]])
+    }
+}
+
+#[inline(always)]
+fn u16_bytes<const BE: bool>(v: u16) -> [u8; 2] {
+    if BE { v.to_be_bytes() } else { v.to_le_bytes() }
+}
+
 // Packed format contiguous dispatchers (autoversion generates per-tier variants)
 #[autoversion(v3, neon, wasm128)]
-fn rgb565_to_rgba_impl(s: &[u8], d: &mut [u8]) {
+fn rgb565_to_rgba_impl<const BE: bool>(s: &[u8], d: &mut [u8]) {
     for (s, d) in s.chunks_exact(2).zip(d.chunks_exact_mut(4)) {
-        let v = u16::from_le_bytes([s[0], s[1]]);
+        let v = read_u16::<BE>(s);
         let r5 = (v >> 11) & 0x1F;
         let g6 = (v >> 5) & 0x3F;
         let b5 = v & 0x1F;
@@ -50,9 +64,9 @@ fn rgb565_to_rgba_impl(s: &[u8], d: &mut [u8]) {
     }
 }
 #[autoversion(v3, neon, wasm128)]
-fn rgb565_to_bgra_impl(s: &[u8], d: &mut [u8]) {
+fn rgb565_to_bgra_impl<const BE: bool>(s: &[u8], d: &mut [u8]) {
     for (s, d) in s.chunks_exact(2).zip(d.chunks_exact_mut(4)) {
-        let v = u16::from_le_bytes([s[0], s[1]]);
+        let v = read_u16::<BE>(s);
         let r5 = (v >> 11) & 0x1F;
         let g6 = (v >> 5) & 0x3F;
         let b5 = v & 0x1F;
@@ -63,9 +77,9 @@ fn rgb565_to_bgra_impl(s: &[u8], d: &mut [u8]) {
     }
 }
 #[autoversion(v3, neon, wasm128)]
-fn rgba4444_to_rgba_impl(s: &[u8], d: &mut [u8]) {
+fn rgba4444_to_rgba_impl<const BE: bool>(s: &[u8], d: &mut [u8]) {
     for (s, d) in s.chunks_exact(2).zip(d.chunks_exact_mut(4)) {
-        let v = u16::from_le_bytes([s[0], s[1]]);
+        let v = read_u16::<BE>(s);
         let r4 = (v >> 12) & 0xF;
         let g4 = (v >> 8) & 0xF;
         let b4 = (v >> 4) & 0xF;
@@ -77,9 +91,9 @@ fn rgba4444_to_rgba_impl(s: &[u8], d: &mut [u8]) {
     }
 }
 #[autoversion(v3, neon, wasm128)]
-fn rgba4444_to_bgra_impl(s: &[u8], d: &mut [u8]) {
+fn rgba4444_to_bgra_impl<const BE: bool>(s: &[u8], d: &mut [u8]) {
     for (s, d) in s.chunks_exact(2).zip(d.chunks_exact_mut(4)) {
-        let v = u16::from_le_bytes([s[0], s[1]]);
+        let v = read_u16::<BE>(s);
         let r4 = (v >> 12) & 0xF;
         let g4 = (v >> 8) & 0xF;
         let b4 = (v >> 4) & 0xF;
@@ -93,7 +107,7 @@ fn rgba4444_to_bgra_impl(s: &[u8], d: &mut [u8]) {
 
 // Packed format strided dispatchers
 #[autoversion(v3, neon, wasm128)]
-fn rgb565_to_rgba_strided_impl(
+fn rgb565_to_rgba_strided_impl<const BE: bool>(
     src: &[u8],
     dst: &mut [u8],
     w: usize,
@@ -106,7 +120,7 @@ fn rgb565_to_rgba_strided_impl(
             .chunks_exact(2)
             .zip(dst[y * ds..][..w * 4].chunks_exact_mut(4))
         {
-            let v = u16::from_le_bytes([s[0], s[1]]);
+            let v = read_u16::<BE>(s);
             let r5 = (v >> 11) & 0x1F;
             let g6 = (v >> 5) & 0x3F;
             let b5 = v & 0x1F;
@@ -118,7 +132,7 @@ fn rgb565_to_rgba_strided_impl(
     }
 }
 #[autoversion(v3, neon, wasm128)]
-fn rgb565_to_bgra_strided_impl(
+fn rgb565_to_bgra_strided_impl<const BE: bool>(
     src: &[u8],
     dst: &mut [u8],
     w: usize,
@@ -131,7 +145,7 @@ fn rgb565_to_bgra_strided_impl(
             .chunks_exact(2)
             .zip(dst[y * ds..][..w * 4].chunks_exact_mut(4))
         {
-            let v = u16::from_le_bytes([s[0], s[1]]);
+            let v = read_u16::<BE>(s);
             let r5 = (v >> 11) & 0x1F;
             let g6 = (v >> 5) & 0x3F;
             let b5 = v & 0x1F;
@@ -143,7 +157,7 @@ fn rgb565_to_bgra_strided_impl(
     }
 }
 #[autoversion(v3, neon, wasm128)]
-fn rgba4444_to_rgba_strided_impl(
+fn rgba4444_to_rgba_strided_impl<const BE: bool>(
     src: &[u8],
     dst: &mut [u8],
     w: usize,
@@ -156,7 +170,7 @@ fn rgba4444_to_rgba_strided_impl(
             .chunks_exact(2)
             .zip(dst[y * ds..][..w * 4].chunks_exact_mut(4))
         {
-            let v = u16::from_le_bytes([s[0], s[1]]);
+            let v = read_u16::<BE>(s);
             let r4 = (v >> 12) & 0xF;
             let g4 = (v >> 8) & 0xF;
             let b4 = (v >> 4) & 0xF;
@@ -169,7 +183,7 @@ fn rgba4444_to_rgba_strided_impl(
     }
 }
 #[autoversion(v3, neon, wasm128)]
-fn rgba4444_to_bgra_strided_impl(
+fn rgba4444_to_bgra_strided_impl<const BE: bool>(
     src: &[u8],
     dst: &mut [u8],
     w: usize,
@@ -182,7 +196,7 @@ fn rgba4444_to_bgra_strided_impl(
             .chunks_exact(2)
             .zip(dst[y * ds..][..w * 4].chunks_exact_mut(4))
         {
-            let v = u16::from_le_bytes([s[0], s[1]]);
+            let v = read_u16::<BE>(s);
             let r4 = (v >> 12) & 0xF;
             let g4 = (v >> 8) & 0xF;
             let b4 = (v >> 4) & 0xF;
@@ -215,56 +229,56 @@ fn rgba4444_to_bgra_strided_impl(
 
 // Contiguous compress dispatchers
 
-/// RGBA (4bpp) → RGB565 (LE u16, 2bpp). Alpha dropped.
+/// RGBA (4bpp) → RGB565 (LE or BE u16, 2bpp). Alpha dropped.
 #[autoversion(v3, neon, wasm128)]
-fn rgba_to_rgb565_impl(s: &[u8], d: &mut [u8]) {
+fn rgba_to_rgb565_impl<const BE: bool>(s: &[u8], d: &mut [u8]) {
     for (s, d) in s.chunks_exact(4).zip(d.chunks_exact_mut(2)) {
         let r5 = (s[0] as u16 * 31 + 128) >> 8;
         let g6 = (s[1] as u16 * 63 + 128) >> 8;
         let b5 = (s[2] as u16 * 31 + 128) >> 8;
-        d.copy_from_slice(&((r5 << 11) | (g6 << 5) | b5).to_le_bytes());
+        d.copy_from_slice(&u16_bytes::<BE>((r5 << 11) | (g6 << 5) | b5));
     }
 }
 
-/// BGRA (4bpp) → RGB565 (LE u16, 2bpp). Alpha dropped.
+/// BGRA (4bpp) → RGB565 (LE or BE u16, 2bpp). Alpha dropped.
 #[autoversion(v3, neon, wasm128)]
-fn bgra_to_rgb565_impl(s: &[u8], d: &mut [u8]) {
+fn bgra_to_rgb565_impl<const BE: bool>(s: &[u8], d: &mut [u8]) {
     for (s, d) in s.chunks_exact(4).zip(d.chunks_exact_mut(2)) {
         let r5 = (s[2] as u16 * 31 + 128) >> 8;
         let g6 = (s[1] as u16 * 63 + 128) >> 8;
         let b5 = (s[0] as u16 * 31 + 128) >> 8;
-        d.copy_from_slice(&((r5 << 11) | (g6 << 5) | b5).to_le_bytes());
+        d.copy_from_slice(&u16_bytes::<BE>((r5 << 11) | (g6 << 5) | b5));
     }
 }
 
-/// RGBA (4bpp) → RGBA4444 (LE u16, 2bpp).
+/// RGBA (4bpp) → RGBA4444 (LE or BE u16, 2bpp).
 #[autoversion(v3, neon, wasm128)]
-fn rgba_to_rgba4444_impl(s: &[u8], d: &mut [u8]) {
+fn rgba_to_rgba4444_impl<const BE: bool>(s: &[u8], d: &mut [u8]) {
     for (s, d) in s.chunks_exact(4).zip(d.chunks_exact_mut(2)) {
         let r4 = (s[0] as u16 * 15 + 128) >> 8;
         let g4 = (s[1] as u16 * 15 + 128) >> 8;
         let b4 = (s[2] as u16 * 15 + 128) >> 8;
         let a4 = (s[3] as u16 * 15 + 128) >> 8;
-        d.copy_from_slice(&((r4 << 12) | (g4 << 8) | (b4 << 4) | a4).to_le_bytes());
+        d.copy_from_slice(&u16_bytes::<BE>((r4 << 12) | (g4 << 8) | (b4 << 4) | a4));
     }
 }
 
-/// BGRA (4bpp) → RGBA4444 (LE u16, 2bpp).
+/// BGRA (4bpp) → RGBA4444 (LE or BE u16, 2bpp).
 #[autoversion(v3, neon, wasm128)]
-fn bgra_to_rgba4444_impl(s: &[u8], d: &mut [u8]) {
+fn bgra_to_rgba4444_impl<const BE: bool>(s: &[u8], d: &mut [u8]) {
     for (s, d) in s.chunks_exact(4).zip(d.chunks_exact_mut(2)) {
         let r4 = (s[2] as u16 * 15 + 128) >> 8;
         let g4 = (s[1] as u16 * 15 + 128) >> 8;
         let b4 = (s[0] as u16 * 15 + 128) >> 8;
         let a4 = (s[3] as u16 * 15 + 128) >> 8;
-        d.copy_from_slice(&((r4 << 12) | (g4 << 8) | (b4 << 4) | a4).to_le_bytes());
+        d.copy_from_slice(&u16_bytes::<BE>((r4 << 12) | (g4 << 8) | (b4 << 4) | a4));
     }
 }
 
 // Strided compress dispatchers
 
 #[autoversion(v3, neon, wasm128)]
-fn rgba_to_rgb565_strided_impl(
+fn rgba_to_rgb565_strided_impl<const BE: bool>(
     src: &[u8],
     dst: &mut [u8],
     w: usize,
@@ -280,13 +294,13 @@ fn rgba_to_rgb565_strided_impl(
             let r5 = (s[0] as u16 * 31 + 128) >> 8;
             let g6 = (s[1] as u16 * 63 + 128) >> 8;
             let b5 = (s[2] as u16 * 31 + 128) >> 8;
-            d.copy_from_slice(&((r5 << 11) | (g6 << 5) | b5).to_le_bytes());
+            d.copy_from_slice(&u16_bytes::<BE>((r5 << 11) | (g6 << 5) | b5));
         }
     }
 }
 
 #[autoversion(v3, neon, wasm128)]
-fn bgra_to_rgb565_strided_impl(
+fn bgra_to_rgb565_strided_impl<const BE: bool>(
     src: &[u8],
     dst: &mut [u8],
     w: usize,
@@ -302,13 +316,13 @@ fn bgra_to_rgb565_strided_impl(
             let r5 = (s[2] as u16 * 31 + 128) >> 8;
             let g6 = (s[1] as u16 * 63 + 128) >> 8;
             let b5 = (s[0] as u16 * 31 + 128) >> 8;
-            d.copy_from_slice(&((r5 << 11) | (g6 << 5) | b5).to_le_bytes());
+            d.copy_from_slice(&u16_bytes::<BE>((r5 << 11) | (g6 << 5) | b5));
         }
     }
 }
 
 #[autoversion(v3, neon, wasm128)]
-fn rgba_to_rgba4444_strided_impl(
+fn rgba_to_rgba4444_strided_impl<const BE: bool>(
     src: &[u8],
     dst: &mut [u8],
     w: usize,
@@ -325,13 +339,13 @@ fn rgba_to_rgba4444_strided_impl(
             let g4 = (s[1] as u16 * 15 + 128) >> 8;
             let b4 = (s[2] as u16 * 15 + 128) >> 8;
             let a4 = (s[3] as u16 * 15 + 128) >> 8;
-            d.copy_from_slice(&((r4 << 12) | (g4 << 8) | (b4 << 4) | a4).to_le_bytes());
+            d.copy_from_slice(&u16_bytes::<BE>((r4 << 12) | (g4 << 8) | (b4 << 4) | a4));
         }
     }
 }
 
 #[autoversion(v3, neon, wasm128)]
-fn bgra_to_rgba4444_strided_impl(
+fn bgra_to_rgba4444_strided_impl<const BE: bool>(
     src: &[u8],
     dst: &mut [u8],
     w: usize,
@@ -348,7 +362,7 @@ fn bgra_to_rgba4444_strided_impl(
             let g4 = (s[1] as u16 * 15 + 128) >> 8;
             let b4 = (s[0] as u16 * 15 + 128) >> 8;
             let a4 = (s[3] as u16 * 15 + 128) >> 8;
-            d.copy_from_slice(&((r4 << 12) | (g4 << 8) | (b4 << 4) | a4).to_le_bytes());
+            d.copy_from_slice(&u16_bytes::<BE>((r4 << 12) | (g4 << 8) | (b4 << 4) | a4));
         }
     }
 }
@@ -383,12 +397,8 @@ fn bgra_to_rgba4444_strided_impl(
 // ## Big-endian source data
 //
 // If your source data stores the u16 values in big-endian byte order (high
-// byte first), swap each byte pair before calling:
-// ```rust,ignore
-// for pair in src.chunks_exact_mut(2) { pair.swap(0, 1); }
-// ```
-// Then call the conversion function as normal. A dedicated `_be` variant
-// may be added in a future release if there is demand.
+// byte first), use the `rgb565be_…` / `rgba4444be_…` variants below. They
+// run the same kernels with the byte swap folded into the load.
 //
 // ## Channel expansion
 //
@@ -404,7 +414,7 @@ fn bgra_to_rgba4444_strided_impl(
 /// Sub-byte channels are expanded to 8 bits by MSB replication.
 pub fn rgb565_to_rgba(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
     check_copy(src.len(), 2, dst.len(), 4)?;
-    rgb565_to_rgba_impl(src, dst);
+    rgb565_to_rgba_impl::<false>(src, dst);
     Ok(())
 }
 
@@ -414,7 +424,7 @@ pub fn rgb565_to_rgba(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
 /// Output byte order: `[B, G, R, A]`.
 pub fn rgb565_to_bgra(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
     check_copy(src.len(), 2, dst.len(), 4)?;
-    rgb565_to_bgra_impl(src, dst);
+    rgb565_to_bgra_impl::<false>(src, dst);
     Ok(())
 }
 
@@ -423,7 +433,7 @@ pub fn rgb565_to_bgra(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
 /// Source bit layout per u16: `R[15:12] G[11:8] B[7:4] A[3:0]`.
 pub fn rgba4444_to_rgba(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
     check_copy(src.len(), 2, dst.len(), 4)?;
-    rgba4444_to_rgba_impl(src, dst);
+    rgba4444_to_rgba_impl::<false>(src, dst);
     Ok(())
 }
 
@@ -433,7 +443,7 @@ pub fn rgba4444_to_rgba(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
 /// Output byte order: `[B, G, R, A]`.
 pub fn rgba4444_to_bgra(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
     check_copy(src.len(), 2, dst.len(), 4)?;
-    rgba4444_to_bgra_impl(src, dst);
+    rgba4444_to_bgra_impl::<false>(src, dst);
     Ok(())
 }
 
@@ -450,9 +460,9 @@ pub fn rgb565_to_rgba_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 2)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
-    rgb565_to_rgba_strided_impl(src, dst, width, height, src_stride, dst_stride);
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgb565_to_rgba_strided_impl::<false>(src, dst, width, height, src_stride, dst_stride);
     Ok(())
 }
 
@@ -467,9 +477,9 @@ pub fn rgb565_to_bgra_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 2)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
-    rgb565_to_bgra_strided_impl(src, dst, width, height, src_stride, dst_stride);
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgb565_to_bgra_strided_impl::<false>(src, dst, width, height, src_stride, dst_stride);
     Ok(())
 }
 
@@ -484,9 +494,9 @@ pub fn rgba4444_to_rgba_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 2)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
-    rgba4444_to_rgba_strided_impl(src, dst, width, height, src_stride, dst_stride);
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgba4444_to_rgba_strided_impl::<false>(src, dst, width, height, src_stride, dst_stride);
     Ok(())
 }
 
@@ -501,9 +511,9 @@ pub fn rgba4444_to_bgra_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 2)?;
-    check_strided(dst.len(), width, height, dst_stride, 4)?;
-    rgba4444_to_bgra_strided_impl(src, dst, width, height, src_stride, dst_stride);
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgba4444_to_bgra_strided_impl::<false>(src, dst, width, height, src_stride, dst_stride);
     Ok(())
 }
 // ===========================================================================
@@ -537,7 +547,7 @@ pub fn rgba4444_to_bgra_strided(
 /// Output bit layout per u16: `R[15:11] G[10:5] B[4:0]`.
 pub fn rgba_to_rgb565(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
     check_copy(src.len(), 4, dst.len(), 2)?;
-    rgba_to_rgb565_impl(src, dst);
+    rgba_to_rgb565_impl::<false>(src, dst);
     Ok(())
 }
 
@@ -547,7 +557,7 @@ pub fn rgba_to_rgb565(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
 /// Output bit layout per u16: `R[15:11] G[10:5] B[4:0]`.
 pub fn bgra_to_rgb565(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
     check_copy(src.len(), 4, dst.len(), 2)?;
-    bgra_to_rgb565_impl(src, dst);
+    bgra_to_rgb565_impl::<false>(src, dst);
     Ok(())
 }
 
@@ -557,7 +567,7 @@ pub fn bgra_to_rgb565(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
 /// Output bit layout per u16: `R[15:12] G[11:8] B[7:4] A[3:0]`.
 pub fn rgba_to_rgba4444(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
     check_copy(src.len(), 4, dst.len(), 2)?;
-    rgba_to_rgba4444_impl(src, dst);
+    rgba_to_rgba4444_impl::<false>(src, dst);
     Ok(())
 }
 
@@ -567,7 +577,7 @@ pub fn rgba_to_rgba4444(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
 /// Output bit layout per u16: `R[15:12] G[11:8] B[7:4] A[3:0]`.
 pub fn bgra_to_rgba4444(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
     check_copy(src.len(), 4, dst.len(), 2)?;
-    bgra_to_rgba4444_impl(src, dst);
+    bgra_to_rgba4444_impl::<false>(src, dst);
     Ok(())
 }
 
@@ -584,9 +594,9 @@ pub fn rgba_to_rgb565_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 2)?;
-    rgba_to_rgb565_strided_impl(src, dst, width, height, src_stride, dst_stride);
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    rgba_to_rgb565_strided_impl::<false>(src, dst, width, height, src_stride, dst_stride);
     Ok(())
 }
 
@@ -601,9 +611,9 @@ pub fn bgra_to_rgb565_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 2)?;
-    bgra_to_rgb565_strided_impl(src, dst, width, height, src_stride, dst_stride);
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    bgra_to_rgb565_strided_impl::<false>(src, dst, width, height, src_stride, dst_stride);
     Ok(())
 }
 
@@ -618,9 +628,9 @@ pub fn rgba_to_rgba4444_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 2)?;
-    rgba_to_rgba4444_strided_impl(src, dst, width, height, src_stride, dst_stride);
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    rgba_to_rgba4444_strided_impl::<false>(src, dst, width, height, src_stride, dst_stride);
     Ok(())
 }
 
@@ -635,8 +645,223 @@ pub fn bgra_to_rgba4444_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided(src.len(), width, height, src_stride, 4)?;
-    check_strided(dst.len(), width, height, dst_stride, 2)?;
-    bgra_to_rgba4444_strided_impl(src, dst, width, height, src_stride, dst_stride);
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    bgra_to_rgba4444_strided_impl::<false>(src, dst, width, height, src_stride, dst_stride);
+    Ok(())
+}
+
+// ===========================================================================
+// Public API — big-endian packed formats
+// ===========================================================================
+//
+// Same bit layouts and rounding as above; only the byte order of each u16
+// differs (high byte first).
+
+/// RGB565 (big-endian u16, 2 bytes/px) → RGBA (4 bytes/px).
+///
+/// Like [`rgb565_to_rgba`], with the source u16 values stored high byte first.
+pub fn rgb565be_to_rgba(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 2, dst.len(), 4)?;
+    rgb565_to_rgba_impl::<true>(src, dst);
+    Ok(())
+}
+
+/// RGB565 (big-endian u16, 2 bytes/px) → BGRA (4 bytes/px).
+///
+/// Like [`rgb565_to_bgra`], with the source u16 values stored high byte first.
+pub fn rgb565be_to_bgra(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 2, dst.len(), 4)?;
+    rgb565_to_bgra_impl::<true>(src, dst);
+    Ok(())
+}
+
+/// RGBA4444 (big-endian u16, 2 bytes/px) → RGBA (4 bytes/px).
+///
+/// Like [`rgba4444_to_rgba`], with the source u16 values stored high byte first.
+pub fn rgba4444be_to_rgba(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 2, dst.len(), 4)?;
+    rgba4444_to_rgba_impl::<true>(src, dst);
+    Ok(())
+}
+
+/// RGBA4444 (big-endian u16, 2 bytes/px) → BGRA (4 bytes/px).
+///
+/// Like [`rgba4444_to_bgra`], with the source u16 values stored high byte first.
+pub fn rgba4444be_to_bgra(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 2, dst.len(), 4)?;
+    rgba4444_to_bgra_impl::<true>(src, dst);
+    Ok(())
+}
+
+/// RGBA (4 bytes/px) → RGB565 (big-endian u16, 2 bytes/px).
+///
+/// Like [`rgba_to_rgb565`], with the output u16 values stored high byte first.
+pub fn rgba_to_rgb565be(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 2)?;
+    rgba_to_rgb565_impl::<true>(src, dst);
+    Ok(())
+}
+
+/// BGRA (4 bytes/px) → RGB565 (big-endian u16, 2 bytes/px).
+///
+/// Like [`bgra_to_rgb565`], with the output u16 values stored high byte first.
+pub fn bgra_to_rgb565be(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 2)?;
+    bgra_to_rgb565_impl::<true>(src, dst);
+    Ok(())
+}
+
+/// RGBA (4 bytes/px) → RGBA4444 (big-endian u16, 2 bytes/px).
+///
+/// Like [`rgba_to_rgba4444`], with the output u16 values stored high byte first.
+pub fn rgba_to_rgba4444be(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 2)?;
+    rgba_to_rgba4444_impl::<true>(src, dst);
+    Ok(())
+}
+
+/// BGRA (4 bytes/px) → RGBA4444 (big-endian u16, 2 bytes/px).
+///
+/// Like [`bgra_to_rgba4444`], with the output u16 values stored high byte first.
+pub fn bgra_to_rgba4444be(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 2)?;
+    bgra_to_rgba4444_impl::<true>(src, dst);
+    Ok(())
+}
+
+/// RGB565 (BE, 2 bytes/px) → RGBA (4 bytes/px) between strided buffers.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn rgb565be_to_rgba_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgb565_to_rgba_strided_impl::<true>(src, dst, width, height, src_stride, dst_stride);
+    Ok(())
+}
+
+/// RGB565 (BE, 2 bytes/px) → BGRA (4 bytes/px) between strided buffers.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn rgb565be_to_bgra_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgb565_to_bgra_strided_impl::<true>(src, dst, width, height, src_stride, dst_stride);
+    Ok(())
+}
+
+/// RGBA4444 (BE, 2 bytes/px) → RGBA (4 bytes/px) between strided buffers.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn rgba4444be_to_rgba_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgba4444_to_rgba_strided_impl::<true>(src, dst, width, height, src_stride, dst_stride);
+    Ok(())
+}
+
+/// RGBA4444 (BE, 2 bytes/px) → BGRA (4 bytes/px) between strided buffers.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn rgba4444be_to_bgra_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 2)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgba4444_to_bgra_strided_impl::<true>(src, dst, width, height, src_stride, dst_stride);
+    Ok(())
+}
+
+/// RGBA (4 bytes/px) → RGB565 (BE, 2 bytes/px) between strided buffers.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn rgba_to_rgb565be_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    rgba_to_rgb565_strided_impl::<true>(src, dst, width, height, src_stride, dst_stride);
+    Ok(())
+}
+
+/// BGRA (4 bytes/px) → RGB565 (BE, 2 bytes/px) between strided buffers.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn bgra_to_rgb565be_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    bgra_to_rgb565_strided_impl::<true>(src, dst, width, height, src_stride, dst_stride);
+    Ok(())
+}
+
+/// RGBA (4 bytes/px) → RGBA4444 (BE, 2 bytes/px) between strided buffers.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn rgba_to_rgba4444be_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    rgba_to_rgba4444_strided_impl::<true>(src, dst, width, height, src_stride, dst_stride);
+    Ok(())
+}
+
+/// BGRA (4 bytes/px) → RGBA4444 (BE, 2 bytes/px) between strided buffers.
+///
+/// `width` is pixels per row. `src_stride`/`dst_stride` are bytes between row starts.
+pub fn bgra_to_rgba4444be_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 2)?;
+    bgra_to_rgba4444_strided_impl::<true>(src, dst, width, height, src_stride, dst_stride);
     Ok(())
 }
@@ -34,9 +34,9 @@
 //!
 //! ## Big-endian source data
 //!
-//! These functions expect the four packed bytes in **little-endian** order
-//! (low byte first). If your data is big-endian, swap each group of four bytes
-//! before calling these functions.
+//! The plain functions expect the four packed bytes in **little-endian** order
+//! (low byte first). For big-endian data use the `rgba1010102be` variants,
+//! which fold the byte swap into the same kernels.
 //!
 //! ## Channel expansion
 //!
@@ -76,7 +76,8 @@
 
 use archmage::prelude::*;
 
-use crate::SizeError;
+use super::{check_copy, check_strided};
+use crate::{Operand, SizeError};
 
 // ===========================================================================
 // Channel expansion / compression helpers
@@ -120,8 +121,12 @@ fn compress_10_to_2(a10: u16) -> u32 {
 // ===========================================================================
 
 #[inline(always)]
-fn unpack_one_to_rgba16(src: &[u8; 4], dst: &mut [u16; 4]) {
-    let v = u32::from_le_bytes(*src);
+fn unpack_one_to_rgba16<const BE: bool>(src: &[u8; 4], dst: &mut [u16; 4]) {
+    let v = if BE {
+        u32::from_be_bytes(*src)
+    } else {
+        u32::from_le_bytes(*src)
+    };
     dst[0] = (v & 0x3FF) as u16;
     dst[1] = ((v >> 10) & 0x3FF) as u16;
     dst[2] = ((v >> 20) & 0x3FF) as u16;
@@ -129,16 +134,16 @@ fn unpack_one_to_rgba16(src: &[u8; 4], dst: &mut [u16; 4]) {
 }
 
 #[autoversion(v3, neon, wasm128)]
-fn rgba1010102_to_rgba16_impl(src: &[u8], dst: &mut [u16]) {
+fn rgba1010102_to_rgba16_impl<const BE: bool>(src: &[u8], dst: &mut [u16]) {
     for (s, d) in src.chunks_exact(4).zip(dst.chunks_exact_mut(4)) {
         let s4: &[u8; 4] = s.try_into().unwrap();
         let d4: &mut [u16; 4] = d.try_into().unwrap();
-        unpack_one_to_rgba16(s4, d4);
+        unpack_one_to_rgba16::<BE>(s4, d4);
     }
 }
 
 #[autoversion(v3, neon, wasm128)]
-fn rgba1010102_to_rgba16_strided_impl(
+fn rgba1010102_to_rgba16_strided_impl<const BE: bool>(
     src: &[u8],
     dst: &mut [u16],
     w: usize,
@@ -153,7 +158,7 @@ fn rgba1010102_to_rgba16_strided_impl(
         {
             let s4: &[u8; 4] = s.try_into().unwrap();
             let d4: &mut [u16; 4] = d.try_into().unwrap();
-            unpack_one_to_rgba16(s4, d4);
+            unpack_one_to_rgba16::<BE>(s4, d4);
         }
     }
 }
@@ -163,26 +168,26 @@ fn rgba1010102_to_rgba16_strided_impl(
 // ===========================================================================
 
 #[inline(always)]
-fn pack_one_from_rgba16(src: &[u16; 4], dst: &mut [u8; 4]) {
+fn pack_one_from_rgba16<const BE: bool>(src: &[u16; 4], dst: &mut [u8; 4]) {
     let r = (src[0] as u32) & 0x3FF;
     let g = (src[1] as u32) & 0x3FF;
     let b = (src[2] as u32) & 0x3FF;
     let a = compress_10_to_2(src[3]);
     let v = r | (g << 10) | (b << 20) | (a << 30);
-    *dst = v.to_le_bytes();
+    *dst = if BE { v.to_be_bytes() } else { v.to_le_bytes() };
 }
 
 #[autoversion(v3, neon, wasm128)]
-fn rgba16_to_rgba1010102_impl(src: &[u16], dst: &mut [u8]) {
+fn rgba16_to_rgba1010102_impl<const BE: bool>(src: &[u16], dst: &mut [u8]) {
     for (s, d) in src.chunks_exact(4).zip(dst.chunks_exact_mut(4)) {
         let s4: &[u16; 4] = s.try_into().unwrap();
         let d4: &mut [u8; 4] = d.try_into().unwrap();
-        pack_one_from_rgba16(s4, d4);
+        pack_one_from_rgba16::<BE>(s4, d4);
     }
 }
 
 #[autoversion(v3, neon, wasm128)]
-fn rgba16_to_rgba1010102_strided_impl(
+fn rgba16_to_rgba1010102_strided_impl<const BE: bool>(
     src: &[u16],
     dst: &mut [u8],
     w: usize,
@@ -197,68 +202,11 @@ fn rgba16_to_rgba1010102_strided_impl(
         {
             let s4: &[u16; 4] = s.try_into().unwrap();
             let d4: &mut [u8; 4] = d.try_into().unwrap();
-            pack_one_from_rgba16(s4, d4);
+            pack_one_from_rgba16::<BE>(s4, d4);
         }
     }
 }
 
-// ===========================================================================
-// Validation helpers
-// ===========================================================================
-
-/// Validate a `&[u8]` packed source / `&mut [u16]` interleaved destination
-/// pair. Each pixel is 4 packed bytes ↔ 4 u16 channels.
-#[inline]
-fn check_unpack(src_bytes: usize, dst_u16: usize) -> Result<(), SizeError> {
-    if src_bytes == 0 || !src_bytes.is_multiple_of(4) {
-        return Err(SizeError::NotPixelAligned);
-    }
-    let pixels = src_bytes / 4;
-    if dst_u16 < pixels * 4 {
-        return Err(SizeError::PixelCountMismatch);
-    }
-    Ok(())
-}
-
-/// Validate a `&[u16]` interleaved source / `&mut [u8]` packed destination pair.
-#[inline]
-fn check_pack(src_u16: usize, dst_bytes: usize) -> Result<(), SizeError> {
-    if src_u16 == 0 || !src_u16.is_multiple_of(4) {
-        return Err(SizeError::NotPixelAligned);
-    }
-    let pixels = src_u16 / 4;
-    if dst_bytes < pixels * 4 {
-        return Err(SizeError::PixelCountMismatch);
-    }
-    Ok(())
-}
-
-#[inline]
-fn check_strided_bytes(
-    len: usize,
-    width: usize,
-    height: usize,
-    stride: usize,
-    bpp: usize,
-) -> Result<(), SizeError> {
-    if width == 0 || height == 0 {
-        return Err(SizeError::InvalidStride);
-    }
-    let row_bytes = width.checked_mul(bpp).ok_or(SizeError::InvalidStride)?;
-    if row_bytes > stride {
-        return Err(SizeError::InvalidStride);
-    }
-    let total = (height - 1)
-        .checked_mul(stride)
-        .ok_or(SizeError::InvalidStride)?
-        .checked_add(row_bytes)
-        .ok_or(SizeError::InvalidStride)?;
-    if len < total {
-        return Err(SizeError::InvalidStride);
-    }
-    Ok(())
-}
-
 // ===========================================================================
 // Public API — contiguous unpack
 // ===========================================================================
@@ -280,8 +228,8 @@ fn check_strided_bytes(
 /// assert_eq!(dst, [1023, 0, 0, 1023]);
 /// ```
 pub fn rgba1010102_to_rgba16(src: &[u8], dst: &mut [u16]) -> Result<(), SizeError> {
-    check_unpack(src.len(), dst.len())?;
-    rgba1010102_to_rgba16_impl(src, dst);
+    check_copy(src.len(), 4, dst.len(), 4)?;
+    rgba1010102_to_rgba16_impl::<false>(src, dst);
     Ok(())
 }
 
@@ -307,8 +255,8 @@ pub fn rgba1010102_to_rgba16(src: &[u8], dst: &mut [u16]) -> Result<(), SizeErro
 /// assert_eq!(dst, [0xFF, 0x03, 0x00, 0xC0]);
 /// ```
 pub fn rgba16_to_rgba1010102(src: &[u16], dst: &mut [u8]) -> Result<(), SizeError> {
-    check_pack(src.len(), dst.len())?;
-    rgba16_to_rgba1010102_impl(src, dst);
+    check_copy(src.len(), 4, dst.len(), 4)?;
+    rgba16_to_rgba1010102_impl::<false>(src, dst);
     Ok(())
 }
 
@@ -330,9 +278,9 @@ pub fn rgba1010102_to_rgba16_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided_bytes(src.len(), width, height, src_stride, 4)?;
-    check_strided_bytes(dst.len(), width, height, dst_stride, 4)?;
-    rgba1010102_to_rgba16_strided_impl(src, dst, width, height, src_stride, dst_stride);
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgba1010102_to_rgba16_strided_impl::<false>(src, dst, width, height, src_stride, dst_stride);
     Ok(())
 }
 
@@ -352,9 +300,57 @@ pub fn rgba16_to_rgba1010102_strided(
     src_stride: usize,
     dst_stride: usize,
 ) -> Result<(), SizeError> {
-    check_strided_bytes(src.len(), width, height, src_stride, 4)?;
-    check_strided_bytes(dst.len(), width, height, dst_stride, 4)?;
-    rgba16_to_rgba1010102_strided_impl(src, dst, width, height, src_stride, dst_stride);
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgba16_to_rgba1010102_strided_impl::<false>(src, dst, width, height, src_stride, dst_stride);
+    Ok(())
+}
+
+// ===========================================================================
+// Public API — big-endian
+// ===========================================================================
+
+/// Like [`rgba1010102_to_rgba16`], with each packed u32 stored high byte first.
+pub fn rgba1010102be_to_rgba16(src: &[u8], dst: &mut [u16]) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 4)?;
+    rgba1010102_to_rgba16_impl::<true>(src, dst);
+    Ok(())
+}
+
+/// Like [`rgba16_to_rgba1010102`], writing each packed u32 high byte first.
+pub fn rgba16_to_rgba1010102be(src: &[u16], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 4)?;
+    rgba16_to_rgba1010102_impl::<true>(src, dst);
+    Ok(())
+}
+
+/// Like [`rgba1010102_to_rgba16_strided`], with big-endian packed source.
+pub fn rgba1010102be_to_rgba16_strided(
+    src: &[u8],
+    dst: &mut [u16],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgba1010102_to_rgba16_strided_impl::<true>(src, dst, width, height, src_stride, dst_stride);
+    Ok(())
+}
+
+/// Like [`rgba16_to_rgba1010102_strided`], with big-endian packed output.
+pub fn rgba16_to_rgba1010102be_strided(
+    src: &[u16],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 4)?;
+    rgba16_to_rgba1010102_strided_impl::<true>(src, dst, width, height, src_stride, dst_stride);
     Ok(())
 }
 
@@ -366,6 +362,7 @@ pub fn rgba16_to_rgba1010102_strided(
 mod tests {
     extern crate alloc;
     use super::*;
+    use crate::SizeErrorKind;
     use alloc::vec;
 
     // ---- bit-level helpers ----
@@ -542,13 +539,13 @@ mod tests {
     fn zero_length_rejected() {
         let mut dst = [0u16; 0];
         assert_eq!(
-            rgba1010102_to_rgba16(&[], &mut dst),
-            Err(SizeError::NotPixelAligned)
+            rgba1010102_to_rgba16(&[], &mut dst).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         let mut dst_bytes = [0u8; 0];
         assert_eq!(
-            rgba16_to_rgba1010102(&[], &mut dst_bytes),
-            Err(SizeError::NotPixelAligned)
+            rgba16_to_rgba1010102(&[], &mut dst_bytes).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
     }
 
@@ -557,14 +554,14 @@ mod tests {
         // Source not multiple of 4 bytes
         let mut dst = [0u16; 4];
         assert_eq!(
-            rgba1010102_to_rgba16(&[0, 0, 0], &mut dst),
-            Err(SizeError::NotPixelAligned)
+            rgba1010102_to_rgba16(&[0, 0, 0], &mut dst).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Source not multiple of 4 u16
         let mut dst_bytes = [0u8; 4];
         assert_eq!(
-            rgba16_to_rgba1010102(&[0u16, 0, 0], &mut dst_bytes),
-            Err(SizeError::NotPixelAligned)
+            rgba16_to_rgba1010102(&[0u16, 0, 0], &mut dst_bytes).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
     }
 
@@ -573,14 +570,14 @@ mod tests {
         let src = [0u8; 8]; // 2 pixels
         let mut dst = [0u16; 7]; // need 8
         assert_eq!(
-            rgba1010102_to_rgba16(&src, &mut dst),
-            Err(SizeError::PixelCountMismatch)
+            rgba1010102_to_rgba16(&src, &mut dst).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
         let src_chans = [0u16; 8]; // 2 pixels
         let mut dst_bytes = [0u8; 7]; // need 8
         assert_eq!(
-            rgba16_to_rgba1010102(&src_chans, &mut dst_bytes),
-            Err(SizeError::PixelCountMismatch)
+            rgba16_to_rgba1010102(&src_chans, &mut dst_bytes).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
     }
 
@@ -704,24 +701,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn big_endian_matches_byte_swapped_little_endian() {
+        let mut rng = Lcg::new(0xBE_1010102);
+        let mut le = vec![0u8; 64 * 4];
+        for chunk in le.chunks_exact_mut(4) {
+            chunk.copy_from_slice(&rng.next_u32().to_le_bytes());
+        }
+        let mut be = le.clone();
+        for chunk in be.chunks_exact_mut(4) {
+            chunk.reverse();
+        }
+        let mut from_le = vec![0u16; 64 * 4];
+        let mut from_be = vec![0u16; 64 * 4];
+        rgba1010102_to_rgba16(&le, &mut from_le).unwrap();
+        rgba1010102be_to_rgba16(&be, &mut from_be).unwrap();
+        assert_eq!(from_le, from_be);
+
+        let mut packed_be = vec![0u8; 64 * 4];
+        rgba16_to_rgba1010102be(&from_be, &mut packed_be).unwrap();
+        assert_eq!(packed_be, be);
+
+        // Strided: 8x8 with padding, same results.
+        let (w, h) = (7, 8);
+        let mut strided = vec![0u16; 8 * 4 * h];
+        rgba1010102be_to_rgba16_strided(&be, &mut strided, w, h, 32, 32).unwrap();
+        let mut back = vec![0u8; 64 * 4];
+        rgba16_to_rgba1010102be_strided(&strided, &mut back, w, h, 32, 32).unwrap();
+        for y in 0..h {
+            assert_eq!(&back[y * 32..][..w * 4], &be[y * 32..][..w * 4]);
+        }
+    }
+
     #[test]
     fn strided_invalid_dimensions_rejected() {
         let mut dst = [0u16; 16];
         // Width 0
         assert_eq!(
-            rgba1010102_to_rgba16_strided(&[0u8; 16], &mut dst, 0, 1, 4, 4),
-            Err(SizeError::InvalidStride)
+            rgba1010102_to_rgba16_strided(&[0u8; 16], &mut dst, 0, 1, 4, 4).map_err(|e| e.kind()),
+            Err(SizeErrorKind::InvalidStride)
         );
         // Stride < row_bytes
         assert_eq!(
-            rgba1010102_to_rgba16_strided(&[0u8; 16], &mut dst, 4, 1, 8, 16),
-            Err(SizeError::InvalidStride)
+            rgba1010102_to_rgba16_strided(&[0u8; 16], &mut dst, 4, 1, 8, 16).map_err(|e| e.kind()),
+            Err(SizeErrorKind::InvalidStride)
         );
         // Buffer too small for stride×height
         assert_eq!(
-            rgba1010102_to_rgba16_strided(&[0u8; 16], &mut dst, 1, 5, 4, 4),
-            Err(SizeError::InvalidStride)
+            rgba1010102_to_rgba16_strided(&[0u8; 16], &mut dst, 1, 5, 4, 4).map_err(|e| e.kind()),
+            Err(SizeErrorKind::InvalidStride)
         );
+        let e = rgba1010102_to_rgba16_strided(&[0u8; 16], &mut dst, 1, 5, 4, 4).unwrap_err();
+        assert_eq!(e.operand(), Some(Operand::Src));
+        assert_eq!((e.required(), e.actual()), (Some(20), Some(16)));
+        assert_eq!((e.row_bytes(), e.stride()), (Some(4), Some(4)));
+        // Strides count elements of each slice: `u16`s on the source here.
+        let e = rgba16_to_rgba1010102_strided(&[0u16; 14], &mut [0u8; 16], 2, 2, 8, 8).unwrap_err();
+        assert_eq!(e.operand(), Some(Operand::Src));
+        assert_eq!((e.required(), e.actual()), (Some(16), Some(14)));
+        let e = rgba16_to_rgba1010102_strided(&[0u16; 16], &mut [0u8; 16], 2, 2, 8, 6).unwrap_err();
+        assert_eq!(e.operand(), Some(Operand::Dst));
+        assert_eq!((e.row_bytes(), e.stride()), (Some(8), Some(6)));
     }
 
     #[test]
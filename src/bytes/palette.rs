@@ -0,0 +1,207 @@
+//! Palette (indexed color) expansion.
+//!
+//! Indexed PNG, GIF and BMP images store one palette index per pixel — a
+//! byte, or 1/2/4 bits packed MSB-first with each row starting on a byte
+//! boundary. These functions look each index up in a caller-supplied palette
+//! of `[R, G, B, A]` entries and write RGBA, BGRA, ARGB or RGB pixels.
+//!
+//! Indices past the end of the palette produce transparent black
+//! (`[0, 0, 0, 0]`, or `[0, 0, 0]` for RGB) instead of an error, matching what
+//! most decoders do with corrupt files. Entries past 256 are ignored.
+//!
+//! 8-bit indices into a palette of at most 16 entries — the common case for
+//! converted 4-bit images and small GIFs — use a byte-shuffle kernel on AVX2.
+//! Everything else goes through a 256-entry lookup table.
+//!
+//! For 8-bit indices the source length sets the pixel count (like
+//! [`gray_to_rgba`](super::gray_to_rgba)). For 1/2/4-bit indices the
+//! destination does, and the source must hold `ceil(pixels * bits / 8)` bytes.
+//! Strided forms take `width` in pixels and both strides in bytes.
+
+#![cfg_attr(target_arch = "x86", allow(unused_imports))]
+
+use archmage::prelude::*;
+
+use super::*;
+use crate::SizeError;
+
+/// Output byte order: which palette channel (`0..4` = R, G, B, A) lands in
+/// each output byte.
+type Order = [usize; 4];
+
+const RGBA: Order = [0, 1, 2, 3];
+const BGRA: Order = [2, 1, 0, 3];
+const ARGB: Order = [3, 0, 1, 2];
+
+/// Palette reordered to output byte order and padded to 256 entries with zeros.
+fn build_table(palette: &[[u8; 4]], order: Order) -> [[u8; 4]; 256] {
+    let mut table = [[0u8; 4]; 256];
+    for (t, p) in table.iter_mut().zip(palette) {
+        *t = [p[order[0]], p[order[1]], p[order[2]], p[order[3]]];
+    }
+    table
+}
+
+// ===========================================================================
+// Lookup-table kernels
+// ===========================================================================
+//
+// `BPP` is 3 or 4 output bytes (RGB writes the first three table bytes).
+// `BITS` is 1, 2 or 4; index `j` of a byte sits at shift `8 - BITS * (j + 1)`.
+
+#[autoversion(v3, neon, wasm128)]
+fn lut_row<const BPP: usize>(s: &[u8], d: &mut [u8], table: &[[u8; 4]; 256]) {
+    for (&i, d) in s.iter().zip(d.chunks_exact_mut(BPP)) {
+        d.copy_from_slice(&table[i as usize][..BPP]);
+    }
+}
+
+#[autoversion(v3, neon, wasm128)]
+fn lut_bits_row<const BITS: u32, const BPP: usize>(s: &[u8], d: &mut [u8], table: &[[u8; 4]; 256]) {
+    let ppb = (8 / BITS) as usize;
+    let mask = ((1u32 << BITS) - 1) as u8;
+    for (d, &s) in d.chunks_mut(ppb * BPP).zip(s) {
+        for (j, d) in d.chunks_exact_mut(BPP).enumerate() {
+            let i = (s >> (8 - BITS * (j as u32 + 1))) & mask;
+            d.copy_from_slice(&table[i as usize][..BPP]);
+        }
+    }
+}
+
+// ===========================================================================
+// Shared drivers
+// ===========================================================================
+
+fn expand<const BITS: u32, const BPP: usize>(
+    src: &[u8],
+    palette: &[[u8; 4]],
+    dst: &mut [u8],
+    order: Order,
+) -> Result<(), SizeError> {
+    if BITS == 8 {
+        check_copy(src.len(), 1, dst.len(), BPP)?;
+    } else {
+        check_inplace(dst.len(), BPP)?;
+        check_packed(Operand::Src, dst.len() / BPP, src.len(), BITS)?;
+    }
+    let table = build_table(palette, order);
+    if BITS != 8 {
+        lut_bits_row::<BITS, BPP>(src, dst, &table);
+    } else if BPP == 4 && palette.len() <= 16 {
+        incant!(indexed_to_4bpp_small_impl(src, dst, &table), [v3, scalar]);
+    } else {
+        lut_row::<BPP>(src, dst, &table);
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn expand_strided<const BITS: u32, const BPP: usize>(
+    src: &[u8],
+    palette: &[[u8; 4]],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    order: Order,
+) -> Result<(), SizeError> {
+    check_packed_strided(Operand::Src, src.len(), width, height, src_stride, BITS)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, BPP)?;
+    let table = build_table(palette, order);
+    if BITS == 8 && BPP == 4 && palette.len() <= 16 {
+        incant!(
+            indexed_to_4bpp_small_strided(src, dst, width, height, src_stride, dst_stride, &table),
+            [v3, scalar]
+        );
+        return Ok(());
+    }
+    let row_bytes = packed_row_bytes(width, BITS);
+    for y in 0..height {
+        let s = &src[y * src_stride..][..row_bytes];
+        let d = &mut dst[y * dst_stride..][..width * BPP];
+        if BITS == 8 {
+            lut_row::<BPP>(s, d, &table);
+        } else {
+            lut_bits_row::<BITS, BPP>(s, d, &table);
+        }
+    }
+    Ok(())
+}
+
+// ===========================================================================
+// Public API
+// ===========================================================================
+
+macro_rules! palette_api {
+    ($(
+        $name:ident, $name_strided:ident, $bits:literal, $bpp:literal, $order:expr,
+        $src_doc:literal, $dst_doc:literal;
+    )*) => {$(
+        #[doc = concat!($src_doc, " → ", $dst_doc, " via `palette` (`[R, G, B, A]` entries).")]
+        ///
+        /// Indices past the end of `palette` produce zero bytes.
+        pub fn $name(
+            src: &[u8],
+            palette: &[[u8; 4]],
+            dst: &mut [u8],
+        ) -> Result<(), SizeError> {
+            expand::<$bits, $bpp>(src, palette, dst, $order)
+        }
+
+        #[doc = concat!($src_doc, " → ", $dst_doc, " between strided buffers.")]
+        ///
+        /// `width` is pixels per row; `src_stride` / `dst_stride` are bytes
+        /// between row starts. Indices past the end of `palette` produce zero
+        /// bytes.
+        #[allow(clippy::too_many_arguments)]
+        pub fn $name_strided(
+            src: &[u8],
+            palette: &[[u8; 4]],
+            dst: &mut [u8],
+            width: usize,
+            height: usize,
+            src_stride: usize,
+            dst_stride: usize,
+        ) -> Result<(), SizeError> {
+            expand_strided::<$bits, $bpp>(
+                src, palette, dst, width, height, src_stride, dst_stride, $order,
+            )
+        }
+    )*};
+}
+
+palette_api! {
+    indexed8_to_rgba, indexed8_to_rgba_strided, 8, 4, RGBA,
+        "8-bit indices", "RGBA (4 bytes/px)";
+    indexed8_to_bgra, indexed8_to_bgra_strided, 8, 4, BGRA,
+        "8-bit indices", "BGRA (4 bytes/px)";
+    indexed8_to_argb, indexed8_to_argb_strided, 8, 4, ARGB,
+        "8-bit indices", "ARGB (4 bytes/px)";
+    indexed8_to_rgb, indexed8_to_rgb_strided, 8, 3, RGBA,
+        "8-bit indices", "RGB (3 bytes/px, palette alpha dropped)";
+    indexed4_to_rgba, indexed4_to_rgba_strided, 4, 4, RGBA,
+        "4-bit indices (2 px/byte, high nibble first)", "RGBA (4 bytes/px)";
+    indexed4_to_bgra, indexed4_to_bgra_strided, 4, 4, BGRA,
+        "4-bit indices (2 px/byte, high nibble first)", "BGRA (4 bytes/px)";
+    indexed4_to_argb, indexed4_to_argb_strided, 4, 4, ARGB,
+        "4-bit indices (2 px/byte, high nibble first)", "ARGB (4 bytes/px)";
+    indexed4_to_rgb, indexed4_to_rgb_strided, 4, 3, RGBA,
+        "4-bit indices (2 px/byte, high nibble first)", "RGB (3 bytes/px, palette alpha dropped)";
+    indexed2_to_rgba, indexed2_to_rgba_strided, 2, 4, RGBA,
+        "2-bit indices (4 px/byte, MSB first)", "RGBA (4 bytes/px)";
+    indexed2_to_bgra, indexed2_to_bgra_strided, 2, 4, BGRA,
+        "2-bit indices (4 px/byte, MSB first)", "BGRA (4 bytes/px)";
+    indexed2_to_argb, indexed2_to_argb_strided, 2, 4, ARGB,
+        "2-bit indices (4 px/byte, MSB first)", "ARGB (4 bytes/px)";
+    indexed2_to_rgb, indexed2_to_rgb_strided, 2, 3, RGBA,
+        "2-bit indices (4 px/byte, MSB first)", "RGB (3 bytes/px, palette alpha dropped)";
+    indexed1_to_rgba, indexed1_to_rgba_strided, 1, 4, RGBA,
+        "1-bit indices (8 px/byte, MSB first)", "RGBA (4 bytes/px)";
+    indexed1_to_bgra, indexed1_to_bgra_strided, 1, 4, BGRA,
+        "1-bit indices (8 px/byte, MSB first)", "BGRA (4 bytes/px)";
+    indexed1_to_argb, indexed1_to_argb_strided, 1, 4, ARGB,
+        "1-bit indices (8 px/byte, MSB first)", "ARGB (4 bytes/px)";
+    indexed1_to_rgb, indexed1_to_rgb_strided, 1, 3, RGBA,
+        "1-bit indices (8 px/byte, MSB first)", "RGB (3 bytes/px, palette alpha dropped)";
+}
@@ -0,0 +1,74 @@
+//! Thread-based [`Executor`] (feature `parallel`).
+//!
+//! [`Parallelism`] runs each row band on its own thread via
+//! [`std::thread::scope`]. Pass it to any `par_…` function or to
+//! [`par_bands`](super::par_bands) / [`par_bands_inplace`](super::par_bands_inplace).
+
+use std::thread;
+
+use super::{Band, Executor};
+
+/// How many threads a parallel conversion may use.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Parallelism {
+    /// Run on the calling thread.
+    #[default]
+    Sequential,
+    /// One band per available core ([`std::thread::available_parallelism`]).
+    Auto,
+    /// At most this many bands, one thread each. `0` and `1` run sequentially.
+    Threads(usize),
+}
+
+impl Executor for Parallelism {
+    fn max_bands(&self) -> usize {
+        match *self {
+            Parallelism::Sequential => 1,
+            Parallelism::Auto => thread::available_parallelism().map_or(1, |n| n.get()),
+            Parallelism::Threads(n) => n,
+        }
+    }
+
+    /// Spawns a scoped thread per band except the last, which runs on the
+    /// calling thread. A panicking band is resumed on the caller.
+    fn run_bands(&self, bands: &mut [Band<'_>]) {
+        let Some((last, rest)) = bands.split_last_mut() else {
+            return;
+        };
+        thread::scope(|scope| {
+            let handles: std::vec::Vec<_> = rest
+                .iter_mut()
+                .map(|band| scope.spawn(move || band.run()))
+                .collect();
+            last.run();
+            for h in handles {
+                h.join().unwrap_or_else(|e| std::panic::resume_unwind(e));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use std::vec;
+
+    #[test]
+    fn threads_match_sequential() {
+        let (w, h) = (700, 1001);
+        let (ss, ds) = (w * 4 + 12, w * 3 + 5);
+        let src: vec::Vec<u8> = (0..ss * h).map(|i| (i * 7 % 251) as u8).collect();
+        let mut seq = vec![0xAAu8; ds * h];
+        let mut par = vec![0xAAu8; ds * h];
+        rgba_to_rgb_strided(&src, &mut seq, w, h, ss, ds).unwrap();
+        par_rgba_to_rgb_strided(&src, &mut par, w, h, ss, ds, Parallelism::Threads(5)).unwrap();
+        assert!(seq == par);
+
+        let mut a = src.clone();
+        let mut b = src.clone();
+        rgba_to_bgra_inplace_strided(&mut a, w, h, ss).unwrap();
+        par_rgba_to_bgra_inplace_strided(&mut b, w, h, ss, Parallelism::Auto).unwrap();
+        assert!(a == b);
+    }
+}
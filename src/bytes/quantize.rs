@@ -0,0 +1,251 @@
+//! Palette mapping: RGBA / BGRA → 8-bit palette indices.
+//!
+//! The inverse of [`indexed8_to_rgba`](super::indexed8_to_rgba): each pixel
+//! is replaced by the index of the nearest entry in a caller-supplied palette
+//! of `[R, G, B, A]` entries, by squared Euclidean distance over all four
+//! channels. Ties go to the lowest index. Building the palette is up to the
+//! caller — this is only the mapping step used when writing GIF or PNG8.
+//!
+//! The search is brute force over the palette, 8 entries per step on AVX2.
+//! Floyd–Steinberg variants diffuse the mapping error; like the other
+//! error-diffusion functions they take a caller-provided `i16` scratch row.
+//!
+//! The palette must hold 1 to 256 entries; otherwise the functions return
+//! [`SizeErrorKind::PixelCountMismatch`](crate::SizeErrorKind::PixelCountMismatch).
+
+use super::dither::error_diffuse;
+use super::*;
+use crate::{SizeError, SizeErrorKind};
+
+/// Distance key for entries past the end of the palette: far enough that no
+/// real entry loses to it, small enough that `dist << 8` stays in `i32`.
+const PAD: i32 = 1024;
+
+/// Palette as `i32` channel planes in source byte order, padded with [`PAD`]
+/// entries to a multiple of 8.
+pub(super) struct PalettePlanes {
+    pub(super) c: [[i32; 256]; 4],
+    pub(super) n: usize,
+}
+
+impl PalettePlanes {
+    fn new(palette: &[[u8; 4]], order: [usize; 4]) -> Result<Self, SizeError> {
+        if palette.is_empty() || palette.len() > 256 {
+            return Err(SizeErrorKind::PixelCountMismatch.into());
+        }
+        let mut c = [[PAD; 256]; 4];
+        for (j, p) in palette.iter().enumerate() {
+            for (plane, &ch) in c.iter_mut().zip(&order) {
+                plane[j] = p[ch] as i32;
+            }
+        }
+        Ok(Self {
+            c,
+            n: palette.len().next_multiple_of(8),
+        })
+    }
+}
+
+/// Index of the palette entry nearest to `px` (source byte order).
+///
+/// Minimizes `dist << 8 | index`, so the lowest index wins ties — the AVX2
+/// kernel reduces the same key and returns identical results.
+#[inline(always)]
+pub(super) fn nearest_index(pal: &PalettePlanes, px: [i32; 4]) -> u8 {
+    let mut best = i32::MAX;
+    for j in 0..pal.n {
+        let mut dist = 0;
+        for (plane, &v) in pal.c.iter().zip(&px) {
+            let d = v - plane[j];
+            dist += d * d;
+        }
+        best = best.min((dist << 8) | j as i32);
+    }
+    (best & 0xFF) as u8
+}
+
+// Source orders: which palette channel sits at each source byte.
+pub(super) const RGBA: [usize; 4] = [0, 1, 2, 3];
+pub(super) const BGRA: [usize; 4] = [2, 1, 0, 3];
+
+fn map(
+    src: &[u8],
+    palette: &[[u8; 4]],
+    dst: &mut [u8],
+    order: [usize; 4],
+) -> Result<(), SizeError> {
+    check_copy(src.len(), 4, dst.len(), 1)?;
+    let pal = PalettePlanes::new(palette, order)?;
+    incant!(rgba_to_indexed_impl(src, dst, &pal), [v3, scalar]);
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn map_strided(
+    src: &[u8],
+    palette: &[[u8; 4]],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    order: [usize; 4],
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
+    let pal = PalettePlanes::new(palette, order)?;
+    incant!(
+        rgba_to_indexed_strided(src, dst, width, height, src_stride, dst_stride, &pal),
+        [v3, scalar]
+    );
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn map_fs_strided(
+    src: &[u8],
+    palette: &[[u8; 4]],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    scratch: &mut [i16],
+    order: [usize; 4],
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 4)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
+    map_fs(
+        src,
+        palette,
+        dst,
+        width,
+        height,
+        SignedStride::top_down(src_stride),
+        SignedStride::top_down(dst_stride),
+        scratch,
+        order,
+    )
+}
+
+/// Error diffusion over rows already validated against `src` and `dst`.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn map_fs(
+    src: &[u8],
+    palette: &[[u8; 4]],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_rows: SignedStride,
+    dst_rows: SignedStride,
+    scratch: &mut [i16],
+    order: [usize; 4],
+) -> Result<(), SizeError> {
+    check_scratch(scratch.len(), width * 4)?;
+    let pal = PalettePlanes::new(palette, order)?;
+    error_diffuse::<4>(width, height, scratch, |x, y, v| {
+        let s = &src[src_rows.row(y) + x * 4..][..4];
+        let mut px = [0i32; 4];
+        for c in 0..4 {
+            px[c] = (s[c] as i32 + v[c]).clamp(0, 255);
+        }
+        let i = nearest_index(&pal, px);
+        dst[dst_rows.row(y) + x] = i;
+        for c in 0..4 {
+            v[c] = px[c] - pal.c[c][i as usize];
+        }
+    });
+    Ok(())
+}
+
+// ===========================================================================
+// Public API
+// ===========================================================================
+
+/// RGBA (4 bytes/px) → 8-bit palette indices (1 byte/px), nearest entry.
+pub fn rgba_to_indexed8(src: &[u8], palette: &[[u8; 4]], dst: &mut [u8]) -> Result<(), SizeError> {
+    map(src, palette, dst, RGBA)
+}
+
+/// BGRA (4 bytes/px) → 8-bit palette indices (1 byte/px), nearest entry.
+///
+/// The palette is still `[R, G, B, A]`.
+pub fn bgra_to_indexed8(src: &[u8], palette: &[[u8; 4]], dst: &mut [u8]) -> Result<(), SizeError> {
+    map(src, palette, dst, BGRA)
+}
+
+/// RGBA → 8-bit palette indices between strided buffers, nearest entry.
+///
+/// `width` is pixels per row; strides are bytes between row starts.
+#[allow(clippy::too_many_arguments)]
+pub fn rgba_to_indexed8_strided(
+    src: &[u8],
+    palette: &[[u8; 4]],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    map_strided(
+        src, palette, dst, width, height, src_stride, dst_stride, RGBA,
+    )
+}
+
+/// BGRA → 8-bit palette indices between strided buffers, nearest entry.
+///
+/// `width` is pixels per row; strides are bytes between row starts.
+#[allow(clippy::too_many_arguments)]
+pub fn bgra_to_indexed8_strided(
+    src: &[u8],
+    palette: &[[u8; 4]],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    map_strided(
+        src, palette, dst, width, height, src_stride, dst_stride, BGRA,
+    )
+}
+
+/// RGBA → 8-bit palette indices with Floyd–Steinberg error diffusion.
+///
+/// `scratch` must hold at least `width * 4` elements; a shorter slice is
+/// rejected with `InvalidStride`. Its contents on entry are ignored.
+#[allow(clippy::too_many_arguments)]
+pub fn rgba_to_indexed8_floyd_steinberg_strided(
+    src: &[u8],
+    palette: &[[u8; 4]],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    scratch: &mut [i16],
+) -> Result<(), SizeError> {
+    map_fs_strided(
+        src, palette, dst, width, height, src_stride, dst_stride, scratch, RGBA,
+    )
+}
+
+/// BGRA → 8-bit palette indices with Floyd–Steinberg error diffusion.
+///
+/// `scratch` must hold at least `width * 4` elements; a shorter slice is
+/// rejected with `InvalidStride`. Its contents on entry are ignored.
+#[allow(clippy::too_many_arguments)]
+pub fn bgra_to_indexed8_floyd_steinberg_strided(
+    src: &[u8],
+    palette: &[[u8; 4]],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    scratch: &mut [i16],
+) -> Result<(), SizeError> {
+    map_fs_strided(
+        src, palette, dst, width, height, src_stride, dst_stride, scratch, BGRA,
+    )
+}
@@ -0,0 +1,132 @@
+//! In-place conversions that change bytes per pixel.
+//!
+//! The contiguous forms take a buffer sized for the larger format and return
+//! the sub-slice holding the result; the strided forms convert each row.
+//!
+//! Shrinking (RGBA → RGB) walks each row front to back; growing (RGB → RGBA)
+//! walks back to front, so no pixel is overwritten before it has been read.
+//! Rows are converted in chunks through a small stack buffer with the regular
+//! SIMD kernels, resolved once per call via [`Kernels`].
+//!
+//! After the call each row (or the whole contiguous buffer) holds the pixels
+//! in the new format starting at its first byte. When shrinking, the bytes
+//! between the new and old length keep stale data.
+
+use super::*;
+use crate::{SizeError, SizeErrorKind};
+
+/// Pixels per chunk.
+const CHUNK: usize = 64;
+
+type RowFn = fn(&Kernels, &[u8], &mut [u8]) -> Result<(), SizeError>;
+
+/// Convert `width` pixels at the start of `row` from `S` to `D` bytes each.
+///
+/// `row` must hold `width * max(S, D)` bytes.
+fn repack_row<const S: usize, const D: usize>(
+    k: &Kernels,
+    row: &mut [u8],
+    width: usize,
+    f: RowFn,
+) -> Result<(), SizeError> {
+    let mut out = [0u8; CHUNK * 4];
+    let mut chunk = |x: usize, n: usize| {
+        f(k, &row[x * S..][..n * S], &mut out[..n * D])?;
+        row[x * D..][..n * D].copy_from_slice(&out[..n * D]);
+        Ok::<(), SizeError>(())
+    };
+    if D < S {
+        for x in (0..width).step_by(CHUNK) {
+            chunk(x, CHUNK.min(width - x))?;
+        }
+    } else {
+        let mut end = width;
+        while end > 0 {
+            let n = CHUNK.min(end);
+            chunk(end - n, n)?;
+            end -= n;
+        }
+    }
+    Ok(())
+}
+
+/// `height` rows of [`repack_row`]; `buf` must be valid for the larger format.
+fn repack_strided<const S: usize, const D: usize>(
+    buf: &mut [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    f: RowFn,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Buf, buf.len(), width, height, stride, S.max(D))?;
+    let k = Kernels::detect();
+    for y in 0..height {
+        repack_row::<S, D>(&k, &mut buf[y * stride..][..width * S.max(D)], width, f)?;
+    }
+    Ok(())
+}
+
+// ===========================================================================
+// Public API
+// ===========================================================================
+
+/// RGBA → RGB in place (drops alpha); returns the RGB bytes.
+///
+/// Compacts front to back: the result is `&mut buf[..buf.len() / 4 * 3]`.
+pub fn rgba_to_rgb_inplace(buf: &mut [u8]) -> Result<&mut [u8], SizeError> {
+    check_inplace(buf.len(), 4)?;
+    let n = buf.len() / 4;
+    repack_row::<4, 3>(&Kernels::detect(), buf, n, Kernels::rgba_to_rgb)?;
+    Ok(&mut buf[..n * 3])
+}
+
+/// RGB → RGBA in place (alpha = 255); returns the RGBA bytes.
+///
+/// The first `n_pixels * 3` bytes of `buf` are read as RGB and expanded back
+/// to front, so `buf` must hold at least `n_pixels * 4` bytes. The result is
+/// `&mut buf[..n_pixels * 4]`.
+pub fn rgb_to_rgba_inplace(buf: &mut [u8], n_pixels: usize) -> Result<&mut [u8], SizeError> {
+    if n_pixels == 0 {
+        return Err(SizeError::new(SizeErrorKind::NotPixelAligned)
+            .of(Operand::Buf)
+            .lengths(None, buf.len()));
+    }
+    let len = n_pixels
+        .checked_mul(4)
+        .filter(|&len| len <= buf.len())
+        .ok_or(
+            SizeError::new(SizeErrorKind::PixelCountMismatch)
+                .of(Operand::Buf)
+                .lengths(n_pixels.checked_mul(4), buf.len()),
+        )?;
+    repack_row::<3, 4>(&Kernels::detect(), buf, n_pixels, Kernels::rgb_to_rgba)?;
+    Ok(&mut buf[..len])
+}
+
+/// RGBA → RGB in place, per row of a strided buffer (drops alpha).
+///
+/// Each row is compacted toward its start: afterwards its first `width * 3`
+/// bytes are RGB. `stride` is unchanged, so the result is a strided RGB image
+/// with the same stride.
+pub fn rgba_to_rgb_inplace_strided(
+    buf: &mut [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<(), SizeError> {
+    repack_strided::<4, 3>(buf, width, height, stride, Kernels::rgba_to_rgb)
+}
+
+/// RGB → RGBA in place, per row of a strided buffer (alpha = 255).
+///
+/// Each row's first `width * 3` bytes are read as RGB and expanded back to
+/// front into its first `width * 4` bytes, so `stride` must be at least
+/// `width * 4`.
+pub fn rgb_to_rgba_inplace_strided(
+    buf: &mut [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<(), SizeError> {
+    repack_strided::<3, 4>(buf, width, height, stride, Kernels::rgb_to_rgba)
+}
@@ -0,0 +1,294 @@
+//! Row-streaming conversion for incremental decoders.
+//!
+//! Decoders hand over one row (or a few) at a time. Calling e.g.
+//! [`rgb_to_bgra`](super::rgb_to_bgra) per row re-validates the buffers and
+//! re-runs CPU feature dispatch every call. A [`RowConverter`] does both once
+//! — it picks the kernel for a `(src, dst, width)` triple and the SIMD tier
+//! ([`Kernels`](super::Kernels)) at construction — so each
+//! [`push_row`](RowConverter::push_row) is a length check and a direct call.
+
+use super::*;
+use crate::{SizeError, SizeErrorKind};
+
+/// Byte order of an 8-bit-per-channel pixel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PixelLayout {
+    /// `[R, G, B, A]`
+    Rgba,
+    /// `[B, G, R, A]`
+    Bgra,
+    /// `[A, R, G, B]`
+    Argb,
+    /// `[A, B, G, R]`
+    Abgr,
+    /// `[R, G, B]`
+    Rgb,
+    /// `[B, G, R]`
+    Bgr,
+    /// `[Y]`
+    Gray,
+    /// `[Y, A]`
+    GrayAlpha,
+}
+
+impl PixelLayout {
+    /// Bytes per pixel.
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            PixelLayout::Rgba | PixelLayout::Bgra | PixelLayout::Argb | PixelLayout::Abgr => 4,
+            PixelLayout::Rgb | PixelLayout::Bgr => 3,
+            PixelLayout::Gray => 1,
+            PixelLayout::GrayAlpha => 2,
+        }
+    }
+}
+
+/// Row kernel for `src` → `dst`.
+type RowFn = fn(&Kernels, &[u8], &mut [u8]) -> Result<(), SizeError>;
+
+/// A [`Kernels`] method and the tiers it has a SIMD kernel for.
+macro_rules! kernel {
+    ($m:ident) => {
+        (Kernels::$m as RowFn, kernels::tiers::$m)
+    };
+}
+
+/// Kernel for `src` → `dst` and its tiers; `None` inside means plain copy.
+pub(super) const fn select(
+    src: PixelLayout,
+    dst: PixelLayout,
+) -> Option<Option<(RowFn, &'static [SimdTier])>> {
+    use PixelLayout::*;
+    Some(Some(match (src, dst) {
+        _ if src as u8 == dst as u8 => return Some(None),
+        (Rgba, Bgra) | (Bgra, Rgba) => kernel!(rgba_to_bgra),
+        (Argb, Rgba) | (Abgr, Bgra) => kernel!(argb_to_rgba),
+        (Rgba, Argb) | (Bgra, Abgr) => kernel!(rgba_to_argb),
+        (Argb, Bgra) | (Bgra, Argb) | (Abgr, Rgba) | (Rgba, Abgr) => kernel!(argb_to_bgra),
+        (Rgb, Bgr) | (Bgr, Rgb) => kernel!(rgb_to_bgr),
+        (Rgb, Rgba) | (Bgr, Bgra) => kernel!(rgb_to_rgba),
+        (Rgb, Bgra) | (Bgr, Rgba) => kernel!(rgb_to_bgra),
+        (Rgb, Argb) | (Bgr, Abgr) => kernel!(rgb_to_argb),
+        (Rgb, Abgr) | (Bgr, Argb) => kernel!(rgb_to_abgr),
+        (Rgba, Rgb) | (Bgra, Bgr) => kernel!(rgba_to_rgb),
+        (Bgra, Rgb) | (Rgba, Bgr) => kernel!(bgra_to_rgb),
+        (Argb, Rgb) | (Abgr, Bgr) => kernel!(argb_to_rgb),
+        (Argb, Bgr) | (Abgr, Rgb) => kernel!(argb_to_bgr),
+        (Gray, Rgba | Bgra) => kernel!(gray_to_rgba),
+        (Gray, Argb | Abgr) => kernel!(gray_to_argb),
+        (GrayAlpha, Rgba | Bgra) => kernel!(gray_alpha_to_rgba),
+        (GrayAlpha, Argb | Abgr) => kernel!(gray_alpha_to_argb),
+        _ => return None,
+    }))
+}
+
+/// Converts rows of one [`PixelLayout`] to another at a fixed width.
+///
+/// Construction validates the width and resolves both the kernel and the SIMD
+/// tier; [`push_row`](Self::push_row) only checks slice lengths.
+///
+/// ```rust
+/// use garb::bytes::{PixelLayout, RowConverter};
+///
+/// let conv = RowConverter::new(PixelLayout::Rgb, PixelLayout::Bgra, 2).unwrap();
+/// let mut out = [0u8; 8];
+/// conv.push_row(&[1, 2, 3, 4, 5, 6], &mut out).unwrap();
+/// assert_eq!(out, [3, 2, 1, 255, 6, 5, 4, 255]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RowConverter {
+    kernel: Option<RowFn>,
+    kernels: Kernels,
+    src_bytes: usize,
+    dst_bytes: usize,
+}
+
+impl RowConverter {
+    /// Converter for rows of `width` pixels from `src` to `dst`.
+    ///
+    /// Returns `None` if no kernel converts `src` to `dst` directly (anything
+    /// to `Gray` / `GrayAlpha`, or `Argb` ↔ `Abgr`), or if `width` is zero or
+    /// the row size overflows.
+    pub fn new(src: PixelLayout, dst: PixelLayout, width: usize) -> Option<Self> {
+        let kernel = select(src, dst)?.map(|(f, _)| f);
+        if width == 0 {
+            return None;
+        }
+        Some(Self {
+            kernel,
+            kernels: Kernels::detect(),
+            src_bytes: width.checked_mul(src.bytes_per_pixel())?,
+            dst_bytes: width.checked_mul(dst.bytes_per_pixel())?,
+        })
+    }
+
+    /// Bytes read from each source row.
+    pub fn src_row_bytes(&self) -> usize {
+        self.src_bytes
+    }
+
+    /// Bytes written to each destination row.
+    pub fn dst_row_bytes(&self) -> usize {
+        self.dst_bytes
+    }
+
+    /// Convert one row. Bytes past [`src_row_bytes`](Self::src_row_bytes) /
+    /// [`dst_row_bytes`](Self::dst_row_bytes) are ignored; shorter slices are
+    /// rejected with `PixelCountMismatch`.
+    #[inline]
+    pub fn push_row(&self, src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+        let err = SizeError::new(SizeErrorKind::PixelCountMismatch);
+        let Some(s) = src.get(..self.src_bytes) else {
+            return Err(err
+                .of(Operand::Src)
+                .lengths(Some(self.src_bytes), src.len()));
+        };
+        let dst_len = dst.len();
+        let Some(d) = dst.get_mut(..self.dst_bytes) else {
+            return Err(err.of(Operand::Dst).lengths(Some(self.dst_bytes), dst_len));
+        };
+        self.run(s, d)
+    }
+
+    /// Convert `height` rows between strided buffers (strides in bytes).
+    pub fn push_rows(
+        &self,
+        src: &[u8],
+        dst: &mut [u8],
+        height: usize,
+        src_stride: usize,
+        dst_stride: usize,
+    ) -> Result<(), SizeError> {
+        check_strided(
+            Operand::Src,
+            src.len(),
+            self.src_bytes,
+            height,
+            src_stride,
+            1,
+        )?;
+        check_strided(
+            Operand::Dst,
+            dst.len(),
+            self.dst_bytes,
+            height,
+            dst_stride,
+            1,
+        )?;
+        for y in 0..height {
+            let s = &src[y * src_stride..][..self.src_bytes];
+            let d = &mut dst[y * dst_stride..][..self.dst_bytes];
+            self.run(s, d)?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn run(&self, s: &[u8], d: &mut [u8]) -> Result<(), SizeError> {
+        match self.kernel {
+            Some(f) => f(&self.kernels, s, d),
+            None => {
+                d.copy_from_slice(s);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use PixelLayout::*;
+
+    const ALL: [PixelLayout; 8] = [Rgba, Bgra, Argb, Abgr, Rgb, Bgr, Gray, GrayAlpha];
+
+    /// Channel order of a layout as indices into `[R, G, B, A]` (gray = 0).
+    fn channels(l: PixelLayout) -> &'static [usize] {
+        match l {
+            Rgba => &[0, 1, 2, 3],
+            Bgra => &[2, 1, 0, 3],
+            Argb => &[3, 0, 1, 2],
+            Abgr => &[3, 2, 1, 0],
+            Rgb => &[0, 1, 2],
+            Bgr => &[2, 1, 0],
+            Gray => &[0],
+            GrayAlpha => &[0, 3],
+        }
+    }
+
+    /// Scalar reference: decode to RGBA, re-encode.
+    fn reference(src: PixelLayout, dst: PixelLayout, s: &[u8], width: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        for px in s.chunks_exact(src.bytes_per_pixel()).take(width) {
+            let mut rgba = [0, 0, 0, 255];
+            for (&c, &v) in channels(src).iter().zip(px) {
+                rgba[c] = v;
+            }
+            if matches!(src, Gray | GrayAlpha) {
+                rgba[1] = rgba[0];
+                rgba[2] = rgba[0];
+            }
+            out.extend(channels(dst).iter().map(|&c| rgba[c]));
+        }
+        out
+    }
+
+    #[test]
+    fn every_supported_pair_matches_reference() {
+        let width = 67;
+        let src: Vec<u8> = (0..width * 4).map(|i| (i * 31 + 7) as u8).collect();
+        for s in ALL {
+            for d in ALL {
+                let Some(conv) = RowConverter::new(s, d, width) else {
+                    continue;
+                };
+                let mut out = vec![0u8; conv.dst_row_bytes() + 5];
+                conv.push_row(&src, &mut out).unwrap();
+                assert_eq!(
+                    out[..conv.dst_row_bytes()],
+                    reference(s, d, &src, width),
+                    "{s:?} → {d:?}"
+                );
+                assert!(out[conv.dst_row_bytes()..].iter().all(|&b| b == 0));
+            }
+        }
+    }
+
+    #[test]
+    fn unsupported_and_short_rows() {
+        assert!(RowConverter::new(Rgb, Gray, 4).is_none());
+        assert!(RowConverter::new(Argb, Abgr, 4).is_none());
+        assert!(RowConverter::new(Rgba, Bgra, 0).is_none());
+        assert!(RowConverter::new(Rgba, Bgra, usize::MAX).is_none());
+
+        let conv = RowConverter::new(Rgb, Rgba, 4).unwrap();
+        let mut dst = [0u8; 16];
+        assert_eq!(
+            conv.push_row(&[0; 11], &mut dst).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
+        );
+        assert_eq!(
+            conv.push_row(&[0; 12], &mut dst[..15])
+                .map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
+        );
+    }
+
+    #[test]
+    fn push_rows_strided() {
+        let (w, h, ss, ds) = (5, 3, 17, 23);
+        let src: Vec<u8> = (0..ss * h).map(|i| i as u8).collect();
+        let conv = RowConverter::new(Bgr, Rgba, w).unwrap();
+        let mut a = vec![0u8; ds * h];
+        let mut b = vec![0u8; ds * h];
+        conv.push_rows(&src, &mut a, h, ss, ds).unwrap();
+        rgb_to_bgra_strided(&src, &mut b, w, h, ss, ds).unwrap();
+        assert_eq!(a, b);
+    }
+}
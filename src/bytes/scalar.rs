@@ -611,6 +611,134 @@ pub(super) fn gray_alpha_to_4bpp_alpha_first_strided_scalar(
     }
 }
 
+// ===========================================================================
+// Byte-order swaps (u16 / u32 samples)
+// ===========================================================================
+//
+// `N` is the sample width in bytes (2 or 4); every N-byte group is reversed.
+
+pub(super) fn swap_bytes_row_scalar<const N: usize>(_token: ScalarToken, row: &mut [u8]) {
+    for v in row.chunks_exact_mut(N) {
+        v.reverse();
+    }
+}
+
+pub(super) fn copy_swap_bytes_row_scalar<const N: usize>(
+    _token: ScalarToken,
+    src: &[u8],
+    dst: &mut [u8],
+) {
+    for (s, d) in src.chunks_exact(N).zip(dst.chunks_exact_mut(N)) {
+        for (j, b) in d.iter_mut().enumerate() {
+            *b = s[N - 1 - j];
+        }
+    }
+}
+
+pub(super) fn swap_bytes16_impl_scalar(t: ScalarToken, b: &mut [u8]) {
+    swap_bytes_row_scalar::<2>(t, b);
+}
+
+pub(super) fn copy_swap_bytes16_impl_scalar(t: ScalarToken, s: &[u8], d: &mut [u8]) {
+    copy_swap_bytes_row_scalar::<2>(t, s, d);
+}
+
+pub(super) fn swap_bytes16_strided_scalar(
+    t: ScalarToken,
+    buf: &mut [u8],
+    w: usize,
+    h: usize,
+    stride: usize,
+) {
+    for y in 0..h {
+        swap_bytes_row_scalar::<2>(t, &mut buf[y * stride..][..w * 2]);
+    }
+}
+
+pub(super) fn copy_swap_bytes16_strided_scalar(
+    t: ScalarToken,
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+) {
+    for y in 0..h {
+        copy_swap_bytes_row_scalar::<2>(t, &src[y * ss..][..w * 2], &mut dst[y * ds..][..w * 2]);
+    }
+}
+
+pub(super) fn swap_bytes32_impl_scalar(t: ScalarToken, b: &mut [u8]) {
+    swap_bytes_row_scalar::<4>(t, b);
+}
+
+pub(super) fn copy_swap_bytes32_impl_scalar(t: ScalarToken, s: &[u8], d: &mut [u8]) {
+    copy_swap_bytes_row_scalar::<4>(t, s, d);
+}
+
+pub(super) fn swap_bytes32_strided_scalar(
+    t: ScalarToken,
+    buf: &mut [u8],
+    w: usize,
+    h: usize,
+    stride: usize,
+) {
+    for y in 0..h {
+        swap_bytes_row_scalar::<4>(t, &mut buf[y * stride..][..w * 4]);
+    }
+}
+
+pub(super) fn copy_swap_bytes32_strided_scalar(
+    t: ScalarToken,
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+) {
+    for y in 0..h {
+        copy_swap_bytes_row_scalar::<4>(t, &src[y * ss..][..w * 4], &mut dst[y * ds..][..w * 4]);
+    }
+}
+
+// ===========================================================================
+// Palette expansion (≤ 16 entries) — LUT fallback
+// ===========================================================================
+
+pub(super) fn indexed_to_4bpp_small_impl_scalar(
+    _token: ScalarToken,
+    s: &[u8],
+    d: &mut [u8],
+    table: &[[u8; 4]; 256],
+) {
+    for (&i, d) in s.iter().zip(d.chunks_exact_mut(4)) {
+        d.copy_from_slice(&table[i as usize]);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn indexed_to_4bpp_small_strided_scalar(
+    t: ScalarToken,
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+    table: &[[u8; 4]; 256],
+) {
+    for y in 0..h {
+        indexed_to_4bpp_small_impl_scalar(
+            t,
+            &src[y * ss..][..w],
+            &mut dst[y * ds..][..w * 4],
+            table,
+        );
+    }
+}
+
 // ===========================================================================
 // Experimental: depth, gray layout, luma, premul (feature = "experimental")
 // ===========================================================================
@@ -620,26 +748,44 @@ pub(super) fn gray_alpha_to_4bpp_alpha_first_strided_scalar(
 mod experimental {
     use archmage::prelude::*;
 
+    use crate::bytes::quantize::{PalettePlanes, nearest_index};
+
     // -----------------------------------------------------------------------
     // Depth conversion row implementations
     // -----------------------------------------------------------------------
+    //
+    // u16 rows take `BE`: false = native-endian u16, true = big-endian.
+
+    #[inline(always)]
+    fn read_u16<const BE: bool>(b: &[u8]) -> u16 {
+        if BE {
+            u16::from_be_bytes([b[0], b[1]])
+        } else {
+            u16::from_ne_bytes([b[0], b[1]])
+        }
+    }
+
+    #[inline(always)]
+    fn u16_bytes<const BE: bool>(v: u16) -> [u8; 2] {
+        if BE { v.to_be_bytes() } else { v.to_ne_bytes() }
+    }
 
-    pub(in crate::bytes) fn convert_u8_to_u16_row_scalar(
+    pub(in crate::bytes) fn convert_u8_to_u16_row_scalar<const BE: bool>(
         _t: ScalarToken,
         src: &[u8],
         dst: &mut [u8],
     ) {
         for (s, d) in src.iter().zip(dst.chunks_exact_mut(2)) {
-            d.copy_from_slice(&((*s as u16) * 257).to_ne_bytes());
+            d.copy_from_slice(&u16_bytes::<BE>(*s as u16 * 257));
         }
     }
-    pub(in crate::bytes) fn convert_u16_to_u8_row_scalar(
+    pub(in crate::bytes) fn convert_u16_to_u8_row_scalar<const BE: bool>(
         _t: ScalarToken,
         src: &[u8],
         dst: &mut [u8],
     ) {
         for (s, d) in src.chunks_exact(2).zip(dst.iter_mut()) {
-            let v = u16::from_ne_bytes([s[0], s[1]]);
+            let v = read_u16::<BE>(s);
             *d = ((v as u32 * 255 + 32768) >> 16) as u8;
         }
     }
@@ -662,33 +808,33 @@ mod experimental {
             *d = (v.clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
         }
     }
-    pub(in crate::bytes) fn convert_u16_to_f32_row_scalar(
+    pub(in crate::bytes) fn convert_u16_to_f32_row_scalar<const BE: bool>(
         _t: ScalarToken,
         src: &[u8],
         dst: &mut [u8],
     ) {
         for (s, d) in src.chunks_exact(2).zip(dst.chunks_exact_mut(4)) {
-            let v = u16::from_ne_bytes([s[0], s[1]]);
+            let v = read_u16::<BE>(s);
             d.copy_from_slice(&(v as f32 / 65535.0).to_ne_bytes());
         }
     }
-    pub(in crate::bytes) fn convert_f32_to_u16_row_scalar(
+    pub(in crate::bytes) fn convert_f32_to_u16_row_scalar<const BE: bool>(
         _t: ScalarToken,
         src: &[u8],
         dst: &mut [u8],
     ) {
         for (s, d) in src.chunks_exact(4).zip(dst.chunks_exact_mut(2)) {
             let v = f32::from_ne_bytes([s[0], s[1], s[2], s[3]]);
-            d.copy_from_slice(&((v.clamp(0.0, 1.0) * 65535.0 + 0.5) as u16).to_ne_bytes());
+            d.copy_from_slice(&u16_bytes::<BE>((v.clamp(0.0, 1.0) * 65535.0 + 0.5) as u16));
         }
     }
 
     // Depth contiguous wrappers
     pub(in crate::bytes) fn convert_u8_to_u16_impl_scalar(t: ScalarToken, s: &[u8], d: &mut [u8]) {
-        convert_u8_to_u16_row_scalar(t, s, d);
+        convert_u8_to_u16_row_scalar::<false>(t, s, d);
     }
     pub(in crate::bytes) fn convert_u16_to_u8_impl_scalar(t: ScalarToken, s: &[u8], d: &mut [u8]) {
-        convert_u16_to_u8_row_scalar(t, s, d);
+        convert_u16_to_u8_row_scalar::<false>(t, s, d);
     }
     pub(in crate::bytes) fn convert_u8_to_f32_impl_scalar(t: ScalarToken, s: &[u8], d: &mut [u8]) {
         convert_u8_to_f32_row_scalar(t, s, d);
@@ -697,10 +843,10 @@ mod experimental {
         convert_f32_to_u8_row_scalar(t, s, d);
     }
     pub(in crate::bytes) fn convert_u16_to_f32_impl_scalar(t: ScalarToken, s: &[u8], d: &mut [u8]) {
-        convert_u16_to_f32_row_scalar(t, s, d);
+        convert_u16_to_f32_row_scalar::<false>(t, s, d);
     }
     pub(in crate::bytes) fn convert_f32_to_u16_impl_scalar(t: ScalarToken, s: &[u8], d: &mut [u8]) {
-        convert_f32_to_u16_row_scalar(t, s, d);
+        convert_f32_to_u16_row_scalar::<false>(t, s, d);
     }
 
     // Depth strided wrappers
@@ -714,7 +860,11 @@ mod experimental {
         ds: usize,
     ) {
         for y in 0..h {
-            convert_u8_to_u16_row_scalar(t, &src[y * ss..][..w], &mut dst[y * ds..][..w * 2]);
+            convert_u8_to_u16_row_scalar::<false>(
+                t,
+                &src[y * ss..][..w],
+                &mut dst[y * ds..][..w * 2],
+            );
         }
     }
     pub(in crate::bytes) fn convert_u16_to_u8_strided_scalar(
@@ -727,7 +877,11 @@ mod experimental {
         ds: usize,
     ) {
         for y in 0..h {
-            convert_u16_to_u8_row_scalar(t, &src[y * ss..][..w * 2], &mut dst[y * ds..][..w]);
+            convert_u16_to_u8_row_scalar::<false>(
+                t,
+                &src[y * ss..][..w * 2],
+                &mut dst[y * ds..][..w],
+            );
         }
     }
     pub(in crate::bytes) fn convert_u8_to_f32_strided_scalar(
@@ -766,7 +920,11 @@ mod experimental {
         ds: usize,
     ) {
         for y in 0..h {
-            convert_u16_to_f32_row_scalar(t, &src[y * ss..][..w * 2], &mut dst[y * ds..][..w * 4]);
+            convert_u16_to_f32_row_scalar::<false>(
+                t,
+                &src[y * ss..][..w * 2],
+                &mut dst[y * ds..][..w * 4],
+            );
         }
     }
     pub(in crate::bytes) fn convert_f32_to_u16_strided_scalar(
@@ -779,7 +937,109 @@ mod experimental {
         ds: usize,
     ) {
         for y in 0..h {
-            convert_f32_to_u16_row_scalar(t, &src[y * ss..][..w * 4], &mut dst[y * ds..][..w * 2]);
+            convert_f32_to_u16_row_scalar::<false>(
+                t,
+                &src[y * ss..][..w * 4],
+                &mut dst[y * ds..][..w * 2],
+            );
+        }
+    }
+
+    // Big-endian u16 depth wrappers (same row kernels, BE = true)
+    pub(in crate::bytes) fn convert_u8_to_u16be_impl_scalar(
+        t: ScalarToken,
+        s: &[u8],
+        d: &mut [u8],
+    ) {
+        convert_u8_to_u16_row_scalar::<true>(t, s, d);
+    }
+    pub(in crate::bytes) fn convert_u16be_to_u8_impl_scalar(
+        t: ScalarToken,
+        s: &[u8],
+        d: &mut [u8],
+    ) {
+        convert_u16_to_u8_row_scalar::<true>(t, s, d);
+    }
+    pub(in crate::bytes) fn convert_u16be_to_f32_impl_scalar(
+        t: ScalarToken,
+        s: &[u8],
+        d: &mut [u8],
+    ) {
+        convert_u16_to_f32_row_scalar::<true>(t, s, d);
+    }
+    pub(in crate::bytes) fn convert_f32_to_u16be_impl_scalar(
+        t: ScalarToken,
+        s: &[u8],
+        d: &mut [u8],
+    ) {
+        convert_f32_to_u16_row_scalar::<true>(t, s, d);
+    }
+    pub(in crate::bytes) fn convert_u8_to_u16be_strided_scalar(
+        t: ScalarToken,
+        src: &[u8],
+        dst: &mut [u8],
+        w: usize,
+        h: usize,
+        ss: usize,
+        ds: usize,
+    ) {
+        for y in 0..h {
+            convert_u8_to_u16_row_scalar::<true>(
+                t,
+                &src[y * ss..][..w],
+                &mut dst[y * ds..][..w * 2],
+            );
+        }
+    }
+    pub(in crate::bytes) fn convert_u16be_to_u8_strided_scalar(
+        t: ScalarToken,
+        src: &[u8],
+        dst: &mut [u8],
+        w: usize,
+        h: usize,
+        ss: usize,
+        ds: usize,
+    ) {
+        for y in 0..h {
+            convert_u16_to_u8_row_scalar::<true>(
+                t,
+                &src[y * ss..][..w * 2],
+                &mut dst[y * ds..][..w],
+            );
+        }
+    }
+    pub(in crate::bytes) fn convert_u16be_to_f32_strided_scalar(
+        t: ScalarToken,
+        src: &[u8],
+        dst: &mut [u8],
+        w: usize,
+        h: usize,
+        ss: usize,
+        ds: usize,
+    ) {
+        for y in 0..h {
+            convert_u16_to_f32_row_scalar::<true>(
+                t,
+                &src[y * ss..][..w * 2],
+                &mut dst[y * ds..][..w * 4],
+            );
+        }
+    }
+    pub(in crate::bytes) fn convert_f32_to_u16be_strided_scalar(
+        t: ScalarToken,
+        src: &[u8],
+        dst: &mut [u8],
+        w: usize,
+        h: usize,
+        ss: usize,
+        ds: usize,
+    ) {
+        for y in 0..h {
+            convert_f32_to_u16_row_scalar::<true>(
+                t,
+                &src[y * ss..][..w * 4],
+                &mut dst[y * ds..][..w * 2],
+            );
         }
     }
 
@@ -1215,6 +1475,37 @@ mod experimental {
             }
         }
     }
+
+    // -----------------------------------------------------------------------
+    // Palette mapping — nearest entry
+    // -----------------------------------------------------------------------
+
+    pub(in crate::bytes) fn rgba_to_indexed_impl_scalar(
+        _token: ScalarToken,
+        s: &[u8],
+        d: &mut [u8],
+        pal: &PalettePlanes,
+    ) {
+        for (s, d) in s.chunks_exact(4).zip(d.iter_mut()) {
+            *d = nearest_index(pal, [s[0] as i32, s[1] as i32, s[2] as i32, s[3] as i32]);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(in crate::bytes) fn rgba_to_indexed_strided_scalar(
+        t: ScalarToken,
+        src: &[u8],
+        dst: &mut [u8],
+        w: usize,
+        h: usize,
+        ss: usize,
+        ds: usize,
+        pal: &PalettePlanes,
+    ) {
+        for y in 0..h {
+            rgba_to_indexed_impl_scalar(t, &src[y * ss..][..w * 4], &mut dst[y * ds..][..w], pal);
+        }
+    }
 }
 
 #[cfg(feature = "experimental")]
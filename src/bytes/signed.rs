@@ -0,0 +1,661 @@
+//! Strided conversions with signed strides and a base offset.
+//!
+//! Windows GDI DIBs, Direct3D mapped resources and FFmpeg frames may use a
+//! negative row pitch: row `y` starts at byte `offset + y * stride` of the
+//! buffer, with `stride < 0` meaning rows run bottom-up in memory. Every
+//! sequential `…_strided` function has a `…_strided_signed` twin that takes a
+//! [`SignedStride`] per buffer, validates it, and converts the same rows. No
+//! `unsafe` and no extra pass: a negative-stride image is the same rows in
+//! reverse order.
+//!
+//! How the rows are visited depends on the conversion:
+//!
+//! - Swizzles with a [`…_strided_flip_y`](super::rgba_to_bgra_strided_flip_y)
+//!   variant run in one call, using the flip when source and destination run
+//!   in opposite directions.
+//! - Other row-by-row conversions run in one call when both buffers are
+//!   top-down, and otherwise once per row, top to bottom. Ordered dither
+//!   shifts its matrix origin per row, so the output matches the unsigned call.
+//! - Floyd–Steinberg diffuses through the rows in image order whatever their
+//!   layout in memory.
+//! - [`transform_strided_signed`] and [`transform_4bpp_strided_signed`] fold
+//!   a vertical flip into the [`Orientation`].
+//!
+//! The `par_…` functions have no signed form.
+
+use super::*;
+use crate::{SizeError, SizeErrorKind};
+
+/// Row layout with a signed stride: row `y` starts at byte
+/// `offset + y * stride`.
+///
+/// With a negative `stride`, `offset` is the start of row 0 (the top row),
+/// which sits at the *end* of the image's bytes. Both count elements of the
+/// buffer's slice type: bytes for `&[u8]`, `u16`s for `&[u16]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SignedStride {
+    /// Byte offset of row 0.
+    pub offset: usize,
+    /// Distance in bytes from the start of row `y` to the start of row `y + 1`.
+    pub stride: isize,
+}
+
+impl SignedStride {
+    /// Row 0 at `offset`, rows `stride` bytes apart.
+    pub const fn new(offset: usize, stride: isize) -> Self {
+        Self { offset, stride }
+    }
+
+    /// Ordinary top-down rows starting at byte 0.
+    pub const fn top_down(stride: usize) -> Self {
+        Self {
+            offset: 0,
+            stride: stride as isize,
+        }
+    }
+
+    /// Bottom-up rows packed from byte 0: row 0 (the top row) is the last of
+    /// `height` rows in memory.
+    pub const fn bottom_up(stride: usize, height: usize) -> Self {
+        Self {
+            offset: height.saturating_sub(1).saturating_mul(stride),
+            stride: (stride as isize).wrapping_neg(),
+        }
+    }
+
+    /// Start of row `y`; only meaningful once [`resolve`] accepted the rows.
+    #[inline(always)]
+    pub(super) fn row(self, y: usize) -> usize {
+        self.offset
+            .wrapping_add_signed((y as isize).wrapping_mul(self.stride))
+    }
+}
+
+/// Rows of `row_len` elements as a top-down sub-slice:
+/// `(start, stride, reversed)`.
+fn resolve(
+    op: Operand,
+    len: usize,
+    row_len: usize,
+    height: usize,
+    s: SignedStride,
+) -> Result<(usize, usize, bool), SizeError> {
+    let stride = s.stride.unsigned_abs();
+    let err = SizeError::new(SizeErrorKind::InvalidStride).of(op);
+    let start = if s.stride < 0 {
+        let span = height
+            .checked_sub(1)
+            .and_then(|rows| rows.checked_mul(stride))
+            .ok_or(err)?;
+        s.offset.checked_sub(span).ok_or(err)?
+    } else {
+        s.offset
+    };
+    let rest = len.checked_sub(start).ok_or(err)?;
+    check_strided(op, rest, row_len, height, stride, 1)?;
+    Ok((start, stride, s.stride < 0))
+}
+
+macro_rules! signed_copy {
+    ($($name:ident => $base:ident, $flip:ident, $sbpp:literal, $dbpp:literal;)*) => {
+        $(
+            #[doc = concat!("[`", stringify!($base), "`](super::", stringify!($base), ") with signed strides; see [`SignedStride`].")]
+            pub fn $name(
+                src: &[u8],
+                dst: &mut [u8],
+                width: usize,
+                height: usize,
+                src_stride: SignedStride,
+                dst_stride: SignedStride,
+            ) -> Result<(), SizeError> {
+                let (s0, ss, s_rev) = resolve(Operand::Src, src.len(), width.saturating_mul($sbpp), height, src_stride)?;
+                let (d0, ds, d_rev) = resolve(Operand::Dst, dst.len(), width.saturating_mul($dbpp), height, dst_stride)?;
+                let (src, dst) = (&src[s0..], &mut dst[d0..]);
+                if s_rev == d_rev {
+                    $base(src, dst, width, height, ss, ds)
+                } else {
+                    $flip(src, dst, width, height, ss, ds)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! signed_inplace {
+    ($($name:ident => $base:ident, $bpp:literal;)*) => {
+        $(
+            #[doc = concat!("[`", stringify!($base), "`](super::", stringify!($base), ") with a signed stride; see [`SignedStride`].")]
+            pub fn $name(
+                buf: &mut [u8],
+                width: usize,
+                height: usize,
+                stride: SignedStride,
+            ) -> Result<(), SizeError> {
+                let (start, stride, _) = resolve(Operand::Buf, buf.len(), width.saturating_mul($bpp), height, stride)?;
+                $base(&mut buf[start..], width, height, stride)
+            }
+        )*
+    };
+}
+
+/// Elements in a row of `width` pixels of `bits` each, for slices of `T`.
+fn row_len<T>(width: usize, bits: usize) -> usize {
+    width
+        .saturating_mul(bits)
+        .div_ceil(8 * core::mem::size_of::<T>())
+}
+
+/// Validates both buffers, then runs `f(src, dst, height, src_stride,
+/// dst_stride, first_row)`: once over the whole image when both run top-down,
+/// otherwise once per row (`height == 1`), top to bottom.
+#[allow(clippy::too_many_arguments)]
+fn signed_rows<S, D>(
+    src: &[S],
+    dst: &mut [D],
+    width: usize,
+    height: usize,
+    src_stride: SignedStride,
+    dst_stride: SignedStride,
+    (src_bits, dst_bits): (usize, usize),
+    mut f: impl FnMut(&[S], &mut [D], usize, usize, usize, usize) -> Result<(), SizeError>,
+) -> Result<(), SizeError> {
+    let src_row = row_len::<S>(width, src_bits);
+    let dst_row = row_len::<D>(width, dst_bits);
+    let (s0, ss, s_rev) = resolve(Operand::Src, src.len(), src_row, height, src_stride)?;
+    let (d0, ds, d_rev) = resolve(Operand::Dst, dst.len(), dst_row, height, dst_stride)?;
+    if !s_rev && !d_rev {
+        return f(&src[s0..], &mut dst[d0..], height, ss, ds, 0);
+    }
+    for y in 0..height {
+        f(
+            &src[src_stride.row(y)..],
+            &mut dst[dst_stride.row(y)..],
+            1,
+            ss,
+            ds,
+            y,
+        )?;
+    }
+    Ok(())
+}
+
+/// Signed forms of conversions without a flip variant. `$sbits` / `$dbits`
+/// are bits per pixel (per element where `width` counts elements);
+/// `$pre` arguments sit between `src` and `dst`, `$post` ones after the strides.
+macro_rules! signed_rows {
+    ($(
+        $name:ident => $base:ident($($pre:ident: $pre_ty:ty),*; $($post:ident: $post_ty:ty),*),
+        $sbits:literal, $dbits:literal;
+    )*) => {
+        signed_rows! {$(
+            $name => $base($($pre: $pre_ty),*; $($post: $post_ty),*) [u8 => u8], $sbits, $dbits;
+        )*}
+    };
+    ($(
+        $name:ident => $base:ident($($pre:ident: $pre_ty:ty),*; $($post:ident: $post_ty:ty),*)
+        [$st:ty => $dt:ty], $sbits:literal, $dbits:literal;
+    )*) => {
+        $(
+            #[doc = concat!("[`", stringify!($base), "`](super::", stringify!($base), ") with signed strides; see [`SignedStride`].")]
+            #[allow(clippy::too_many_arguments)]
+            pub fn $name(
+                src: &[$st],
+                $($pre: $pre_ty,)*
+                dst: &mut [$dt],
+                width: usize,
+                height: usize,
+                src_stride: SignedStride,
+                dst_stride: SignedStride,
+                $($post: $post_ty,)*
+            ) -> Result<(), SizeError> {
+                signed_rows(
+                    src, dst, width, height, src_stride, dst_stride, ($sbits, $dbits),
+                    |s, d, h, ss, ds, _| $base(s, $($pre,)* d, width, h, ss, ds, $($post,)*),
+                )
+            }
+        )*
+    };
+}
+
+/// [`signed_rows!`] for ordered dither: each row band gets the matrix origin
+/// of its first row, so the pattern does not depend on the memory layout.
+#[cfg(feature = "experimental")]
+macro_rules! signed_dithered {
+    ($(
+        $name:ident => $base:ident($($post:ident: $post_ty:ty),*), $sbits:literal, $dbits:literal;
+    )*) => {
+        $(
+            #[doc = concat!("[`", stringify!($base), "`](super::", stringify!($base), ") with signed strides; see [`SignedStride`].")]
+            #[allow(clippy::too_many_arguments)]
+            pub fn $name(
+                src: &[u8],
+                dst: &mut [u8],
+                width: usize,
+                height: usize,
+                src_stride: SignedStride,
+                dst_stride: SignedStride,
+                $($post: $post_ty,)*
+                dither: OrderedDither,
+            ) -> Result<(), SizeError> {
+                signed_rows(
+                    src, dst, width, height, src_stride, dst_stride, ($sbits, $dbits),
+                    |s, d, h, ss, ds, y| {
+                        let dither = OrderedDither {
+                            y: dither.y.wrapping_add(y),
+                            ..dither
+                        };
+                        $base(s, d, width, h, ss, ds, $($post,)* dither)
+                    },
+                )
+            }
+        )*
+    };
+}
+
+signed_copy! {
+    rgba_to_bgra_strided_signed => rgba_to_bgra_strided, rgba_to_bgra_strided_flip_y, 4, 4;
+    bgra_to_rgba_strided_signed => bgra_to_rgba_strided, bgra_to_rgba_strided_flip_y, 4, 4;
+    rgb_to_bgra_strided_signed => rgb_to_bgra_strided, rgb_to_bgra_strided_flip_y, 3, 4;
+    bgr_to_rgba_strided_signed => bgr_to_rgba_strided, bgr_to_rgba_strided_flip_y, 3, 4;
+    rgb_to_rgba_strided_signed => rgb_to_rgba_strided, rgb_to_rgba_strided_flip_y, 3, 4;
+    bgr_to_bgra_strided_signed => bgr_to_bgra_strided, bgr_to_bgra_strided_flip_y, 3, 4;
+    gray_to_rgba_strided_signed => gray_to_rgba_strided, gray_to_rgba_strided_flip_y, 1, 4;
+    gray_to_bgra_strided_signed => gray_to_bgra_strided, gray_to_bgra_strided_flip_y, 1, 4;
+    gray_alpha_to_rgba_strided_signed => gray_alpha_to_rgba_strided, gray_alpha_to_rgba_strided_flip_y, 2, 4;
+    gray_alpha_to_bgra_strided_signed => gray_alpha_to_bgra_strided, gray_alpha_to_bgra_strided_flip_y, 2, 4;
+    rgb_to_bgr_strided_signed => rgb_to_bgr_strided, rgb_to_bgr_strided_flip_y, 3, 3;
+    bgr_to_rgb_strided_signed => bgr_to_rgb_strided, bgr_to_rgb_strided_flip_y, 3, 3;
+    rgba_to_rgb_strided_signed => rgba_to_rgb_strided, rgba_to_rgb_strided_flip_y, 4, 3;
+    bgra_to_bgr_strided_signed => bgra_to_bgr_strided, bgra_to_bgr_strided_flip_y, 4, 3;
+    bgra_to_rgb_strided_signed => bgra_to_rgb_strided, bgra_to_rgb_strided_flip_y, 4, 3;
+    rgba_to_bgr_strided_signed => rgba_to_bgr_strided, rgba_to_bgr_strided_flip_y, 4, 3;
+    argb_to_rgba_strided_signed => argb_to_rgba_strided, argb_to_rgba_strided_flip_y, 4, 4;
+    abgr_to_bgra_strided_signed => abgr_to_bgra_strided, abgr_to_bgra_strided_flip_y, 4, 4;
+    rgba_to_argb_strided_signed => rgba_to_argb_strided, rgba_to_argb_strided_flip_y, 4, 4;
+    bgra_to_abgr_strided_signed => bgra_to_abgr_strided, bgra_to_abgr_strided_flip_y, 4, 4;
+    argb_to_bgra_strided_signed => argb_to_bgra_strided, argb_to_bgra_strided_flip_y, 4, 4;
+    bgra_to_argb_strided_signed => bgra_to_argb_strided, bgra_to_argb_strided_flip_y, 4, 4;
+    abgr_to_rgba_strided_signed => abgr_to_rgba_strided, abgr_to_rgba_strided_flip_y, 4, 4;
+    rgba_to_abgr_strided_signed => rgba_to_abgr_strided, rgba_to_abgr_strided_flip_y, 4, 4;
+    rgb_to_argb_strided_signed => rgb_to_argb_strided, rgb_to_argb_strided_flip_y, 3, 4;
+    bgr_to_abgr_strided_signed => bgr_to_abgr_strided, bgr_to_abgr_strided_flip_y, 3, 4;
+    rgb_to_abgr_strided_signed => rgb_to_abgr_strided, rgb_to_abgr_strided_flip_y, 3, 4;
+    bgr_to_argb_strided_signed => bgr_to_argb_strided, bgr_to_argb_strided_flip_y, 3, 4;
+    argb_to_rgb_strided_signed => argb_to_rgb_strided, argb_to_rgb_strided_flip_y, 4, 3;
+    abgr_to_bgr_strided_signed => abgr_to_bgr_strided, abgr_to_bgr_strided_flip_y, 4, 3;
+    argb_to_bgr_strided_signed => argb_to_bgr_strided, argb_to_bgr_strided_flip_y, 4, 3;
+    abgr_to_rgb_strided_signed => abgr_to_rgb_strided, abgr_to_rgb_strided_flip_y, 4, 3;
+    gray_to_argb_strided_signed => gray_to_argb_strided, gray_to_argb_strided_flip_y, 1, 4;
+    gray_to_abgr_strided_signed => gray_to_abgr_strided, gray_to_abgr_strided_flip_y, 1, 4;
+    gray_alpha_to_argb_strided_signed => gray_alpha_to_argb_strided, gray_alpha_to_argb_strided_flip_y, 2, 4;
+    gray_alpha_to_abgr_strided_signed => gray_alpha_to_abgr_strided, gray_alpha_to_abgr_strided_flip_y, 2, 4;
+    swap_bytes_u16_strided_signed => swap_bytes_u16_strided, swap_bytes_u16_strided_flip_y, 2, 2;
+    swap_bytes_u32_strided_signed => swap_bytes_u32_strided, swap_bytes_u32_strided_flip_y, 4, 4;
+}
+
+signed_inplace! {
+    rgba_to_bgra_inplace_strided_signed => rgba_to_bgra_inplace_strided, 4;
+    bgra_to_rgba_inplace_strided_signed => bgra_to_rgba_inplace_strided, 4;
+    fill_alpha_rgba_strided_signed => fill_alpha_rgba_strided, 4;
+    fill_alpha_bgra_strided_signed => fill_alpha_bgra_strided, 4;
+    rgb_to_bgr_inplace_strided_signed => rgb_to_bgr_inplace_strided, 3;
+    bgr_to_rgb_inplace_strided_signed => bgr_to_rgb_inplace_strided, 3;
+    argb_to_rgba_inplace_strided_signed => argb_to_rgba_inplace_strided, 4;
+    abgr_to_bgra_inplace_strided_signed => abgr_to_bgra_inplace_strided, 4;
+    rgba_to_argb_inplace_strided_signed => rgba_to_argb_inplace_strided, 4;
+    bgra_to_abgr_inplace_strided_signed => bgra_to_abgr_inplace_strided, 4;
+    argb_to_bgra_inplace_strided_signed => argb_to_bgra_inplace_strided, 4;
+    bgra_to_argb_inplace_strided_signed => bgra_to_argb_inplace_strided, 4;
+    abgr_to_rgba_inplace_strided_signed => abgr_to_rgba_inplace_strided, 4;
+    rgba_to_abgr_inplace_strided_signed => rgba_to_abgr_inplace_strided, 4;
+    fill_alpha_argb_strided_signed => fill_alpha_argb_strided, 4;
+    fill_alpha_xrgb_strided_signed => fill_alpha_xrgb_strided, 4;
+    fill_alpha_abgr_strided_signed => fill_alpha_abgr_strided, 4;
+    fill_alpha_xbgr_strided_signed => fill_alpha_xbgr_strided, 4;
+    swap_bytes_u16_inplace_strided_signed => swap_bytes_u16_inplace_strided, 2;
+    swap_bytes_u32_inplace_strided_signed => swap_bytes_u32_inplace_strided, 4;
+    rgba_to_rgb_inplace_strided_signed => rgba_to_rgb_inplace_strided, 4;
+    rgb_to_rgba_inplace_strided_signed => rgb_to_rgba_inplace_strided, 4;
+}
+
+signed_rows! {
+    indexed8_to_rgba_strided_signed => indexed8_to_rgba_strided(palette: &[[u8; 4]];), 8, 32;
+    indexed8_to_bgra_strided_signed => indexed8_to_bgra_strided(palette: &[[u8; 4]];), 8, 32;
+    indexed8_to_argb_strided_signed => indexed8_to_argb_strided(palette: &[[u8; 4]];), 8, 32;
+    indexed8_to_rgb_strided_signed => indexed8_to_rgb_strided(palette: &[[u8; 4]];), 8, 24;
+    indexed4_to_rgba_strided_signed => indexed4_to_rgba_strided(palette: &[[u8; 4]];), 4, 32;
+    indexed4_to_bgra_strided_signed => indexed4_to_bgra_strided(palette: &[[u8; 4]];), 4, 32;
+    indexed4_to_argb_strided_signed => indexed4_to_argb_strided(palette: &[[u8; 4]];), 4, 32;
+    indexed4_to_rgb_strided_signed => indexed4_to_rgb_strided(palette: &[[u8; 4]];), 4, 24;
+    indexed2_to_rgba_strided_signed => indexed2_to_rgba_strided(palette: &[[u8; 4]];), 2, 32;
+    indexed2_to_bgra_strided_signed => indexed2_to_bgra_strided(palette: &[[u8; 4]];), 2, 32;
+    indexed2_to_argb_strided_signed => indexed2_to_argb_strided(palette: &[[u8; 4]];), 2, 32;
+    indexed2_to_rgb_strided_signed => indexed2_to_rgb_strided(palette: &[[u8; 4]];), 2, 24;
+    indexed1_to_rgba_strided_signed => indexed1_to_rgba_strided(palette: &[[u8; 4]];), 1, 32;
+    indexed1_to_bgra_strided_signed => indexed1_to_bgra_strided(palette: &[[u8; 4]];), 1, 32;
+    indexed1_to_argb_strided_signed => indexed1_to_argb_strided(palette: &[[u8; 4]];), 1, 32;
+    indexed1_to_rgb_strided_signed => indexed1_to_rgb_strided(palette: &[[u8; 4]];), 1, 24;
+}
+
+/// Validates both buffers of a transform and returns the top-row offset and
+/// absolute stride of each, plus `orientation` with the vertical flips of
+/// bottom-up buffers folded in.
+#[allow(clippy::too_many_arguments)]
+fn resolve_transform(
+    src_len: usize,
+    dst_len: usize,
+    width: usize,
+    height: usize,
+    src_stride: SignedStride,
+    dst_stride: SignedStride,
+    bytes_per_pixel: usize,
+    orientation: Orientation,
+) -> Result<(usize, usize, usize, usize, Orientation), SizeError> {
+    let (dw, dh) = orientation.output_size(width, height);
+    let (s0, ss, s_rev) = resolve(
+        Operand::Src,
+        src_len,
+        width.saturating_mul(bytes_per_pixel),
+        height,
+        src_stride,
+    )?;
+    let (d0, ds, d_rev) = resolve(
+        Operand::Dst,
+        dst_len,
+        dw.saturating_mul(bytes_per_pixel),
+        dh,
+        dst_stride,
+    )?;
+    // Bottom-up memory holds the image flipped: undo it on the way in and
+    // redo it on the way out.
+    let mut o = orientation;
+    if s_rev {
+        o = Orientation::FlipVertical.then(o);
+    }
+    if d_rev {
+        o = o.then(Orientation::FlipVertical);
+    }
+    Ok((s0, ss, d0, ds, o))
+}
+
+/// [`transform_strided`](super::transform_strided) with signed strides; see
+/// [`SignedStride`].
+#[allow(clippy::too_many_arguments)]
+pub fn transform_strided_signed(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: SignedStride,
+    dst_stride: SignedStride,
+    bytes_per_pixel: usize,
+    orientation: Orientation,
+) -> Result<(), SizeError> {
+    if !matches!(bytes_per_pixel, 1 | 2 | 3 | 4 | 8 | 16) {
+        return Err(SizeErrorKind::NotPixelAligned.into());
+    }
+    let (s0, ss, d0, ds, o) = resolve_transform(
+        src.len(),
+        dst.len(),
+        width,
+        height,
+        src_stride,
+        dst_stride,
+        bytes_per_pixel,
+        orientation,
+    )?;
+    transform_strided(
+        &src[s0..],
+        &mut dst[d0..],
+        width,
+        height,
+        ss,
+        ds,
+        bytes_per_pixel,
+        o,
+    )
+}
+
+/// [`transform_4bpp_strided`](super::transform_4bpp_strided) with signed
+/// strides; see [`SignedStride`].
+#[allow(clippy::too_many_arguments)]
+pub fn transform_4bpp_strided_signed(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: SignedStride,
+    dst_stride: SignedStride,
+    orientation: Orientation,
+    swizzle: Swizzle,
+) -> Result<(), SizeError> {
+    let (s0, ss, d0, ds, o) = resolve_transform(
+        src.len(),
+        dst.len(),
+        width,
+        height,
+        src_stride,
+        dst_stride,
+        4,
+        orientation,
+    )?;
+    transform_4bpp_strided(
+        &src[s0..],
+        &mut dst[d0..],
+        width,
+        height,
+        ss,
+        ds,
+        o,
+        swizzle,
+    )
+}
+
+#[cfg(feature = "experimental")]
+mod experimental {
+    use super::super::dither::{to_rgb565_fs, to_rgba4444_fs, u16_to_u8_fs};
+    use super::super::quantize::{BGRA, RGBA, map_fs};
+    use super::*;
+
+    signed_rows! {
+        rgb_to_gray_bt709_strided_signed => rgb_to_gray_bt709_strided(;), 24, 8;
+        bgr_to_gray_bt709_strided_signed => bgr_to_gray_bt709_strided(;), 24, 8;
+        rgba_to_gray_bt709_strided_signed => rgba_to_gray_bt709_strided(;), 32, 8;
+        bgra_to_gray_bt709_strided_signed => bgra_to_gray_bt709_strided(;), 32, 8;
+        rgb_to_gray_bt601_strided_signed => rgb_to_gray_bt601_strided(;), 24, 8;
+        bgr_to_gray_bt601_strided_signed => bgr_to_gray_bt601_strided(;), 24, 8;
+        rgba_to_gray_bt601_strided_signed => rgba_to_gray_bt601_strided(;), 32, 8;
+        bgra_to_gray_bt601_strided_signed => bgra_to_gray_bt601_strided(;), 32, 8;
+        rgb_to_gray_bt2020_strided_signed => rgb_to_gray_bt2020_strided(;), 24, 8;
+        bgr_to_gray_bt2020_strided_signed => bgr_to_gray_bt2020_strided(;), 24, 8;
+        rgba_to_gray_bt2020_strided_signed => rgba_to_gray_bt2020_strided(;), 32, 8;
+        bgra_to_gray_bt2020_strided_signed => bgra_to_gray_bt2020_strided(;), 32, 8;
+        rgb_to_gray_identity_strided_signed => rgb_to_gray_identity_strided(;), 24, 8;
+        bgr_to_gray_identity_strided_signed => bgr_to_gray_identity_strided(;), 24, 8;
+        rgba_to_gray_identity_strided_signed => rgba_to_gray_identity_strided(;), 32, 8;
+        bgra_to_gray_identity_strided_signed => bgra_to_gray_identity_strided(;), 32, 8;
+        gray_to_rgb_strided_signed => gray_to_rgb_strided(;), 8, 24;
+        gray_to_bgr_strided_signed => gray_to_bgr_strided(;), 8, 24;
+        gray_alpha_to_rgb_strided_signed => gray_alpha_to_rgb_strided(;), 16, 24;
+        gray_alpha_to_bgr_strided_signed => gray_alpha_to_bgr_strided(;), 16, 24;
+        gray_to_gray_alpha_strided_signed => gray_to_gray_alpha_strided(;), 8, 16;
+        gray_alpha_to_gray_strided_signed => gray_alpha_to_gray_strided(;), 16, 8;
+        convert_u8_to_u16_strided_signed => convert_u8_to_u16_strided(;), 8, 16;
+        convert_u16_to_u8_strided_signed => convert_u16_to_u8_strided(;), 16, 8;
+        convert_u8_to_f32_strided_signed => convert_u8_to_f32_strided(;), 8, 32;
+        convert_f32_to_u8_strided_signed => convert_f32_to_u8_strided(;), 32, 8;
+        convert_u16_to_f32_strided_signed => convert_u16_to_f32_strided(;), 16, 32;
+        convert_f32_to_u16_strided_signed => convert_f32_to_u16_strided(;), 32, 16;
+        convert_u8_to_u16be_strided_signed => convert_u8_to_u16be_strided(;), 8, 16;
+        convert_u16be_to_u8_strided_signed => convert_u16be_to_u8_strided(;), 16, 8;
+        convert_u16be_to_f32_strided_signed => convert_u16be_to_f32_strided(;), 16, 32;
+        convert_f32_to_u16be_strided_signed => convert_f32_to_u16be_strided(;), 32, 16;
+        premultiply_alpha_f32_copy_strided_signed => premultiply_alpha_f32_copy_strided(;), 128, 128;
+        unpremultiply_alpha_f32_copy_strided_signed => unpremultiply_alpha_f32_copy_strided(;), 128, 128;
+        premultiply_alpha_rgba_u8_copy_strided_signed => premultiply_alpha_rgba_u8_copy_strided(;), 32, 32;
+        premultiply_alpha_bgra_u8_copy_strided_signed => premultiply_alpha_bgra_u8_copy_strided(;), 32, 32;
+        rgb565_to_rgba_strided_signed => rgb565_to_rgba_strided(;), 16, 32;
+        rgb565_to_bgra_strided_signed => rgb565_to_bgra_strided(;), 16, 32;
+        rgba4444_to_rgba_strided_signed => rgba4444_to_rgba_strided(;), 16, 32;
+        rgba4444_to_bgra_strided_signed => rgba4444_to_bgra_strided(;), 16, 32;
+        rgb565be_to_rgba_strided_signed => rgb565be_to_rgba_strided(;), 16, 32;
+        rgb565be_to_bgra_strided_signed => rgb565be_to_bgra_strided(;), 16, 32;
+        rgba4444be_to_rgba_strided_signed => rgba4444be_to_rgba_strided(;), 16, 32;
+        rgba4444be_to_bgra_strided_signed => rgba4444be_to_bgra_strided(;), 16, 32;
+        rgba_to_rgb565_strided_signed => rgba_to_rgb565_strided(;), 32, 16;
+        bgra_to_rgb565_strided_signed => bgra_to_rgb565_strided(;), 32, 16;
+        rgba_to_rgba4444_strided_signed => rgba_to_rgba4444_strided(;), 32, 16;
+        bgra_to_rgba4444_strided_signed => bgra_to_rgba4444_strided(;), 32, 16;
+        rgba_to_rgb565be_strided_signed => rgba_to_rgb565be_strided(;), 32, 16;
+        bgra_to_rgb565be_strided_signed => bgra_to_rgb565be_strided(;), 32, 16;
+        rgba_to_rgba4444be_strided_signed => rgba_to_rgba4444be_strided(;), 32, 16;
+        bgra_to_rgba4444be_strided_signed => bgra_to_rgba4444be_strided(;), 32, 16;
+        unpack_gray1_to_gray8_strided_signed => unpack_gray1_to_gray8_strided(;), 1, 8;
+        unpack_gray2_to_gray8_strided_signed => unpack_gray2_to_gray8_strided(;), 2, 8;
+        unpack_gray4_to_gray8_strided_signed => unpack_gray4_to_gray8_strided(;), 4, 8;
+        pack_gray8_to_gray1_strided_signed => pack_gray8_to_gray1_strided(;), 8, 1;
+        pack_gray8_to_gray2_strided_signed => pack_gray8_to_gray2_strided(;), 8, 2;
+        pack_gray8_to_gray4_strided_signed => pack_gray8_to_gray4_strided(;), 8, 4;
+        pack_gray8_to_gray1_threshold_strided_signed => pack_gray8_to_gray1_threshold_strided(; threshold: u8), 8, 1;
+        rgba_to_indexed8_strided_signed => rgba_to_indexed8_strided(palette: &[[u8; 4]];), 32, 8;
+        bgra_to_indexed8_strided_signed => bgra_to_indexed8_strided(palette: &[[u8; 4]];), 32, 8;
+    }
+
+    signed_rows! {
+        rgba1010102_to_rgba16_strided_signed => rgba1010102_to_rgba16_strided(;) [u8 => u16], 32, 64;
+        rgba1010102be_to_rgba16_strided_signed => rgba1010102be_to_rgba16_strided(;) [u8 => u16], 32, 64;
+        rgba16_to_rgba1010102_strided_signed => rgba16_to_rgba1010102_strided(;) [u16 => u8], 64, 32;
+        rgba16_to_rgba1010102be_strided_signed => rgba16_to_rgba1010102be_strided(;) [u16 => u8], 64, 32;
+    }
+
+    signed_dithered! {
+        rgba_to_rgb565_dithered_strided_signed => rgba_to_rgb565_dithered_strided(), 32, 16;
+        bgra_to_rgb565_dithered_strided_signed => bgra_to_rgb565_dithered_strided(), 32, 16;
+        rgba_to_rgba4444_dithered_strided_signed => rgba_to_rgba4444_dithered_strided(), 32, 16;
+        bgra_to_rgba4444_dithered_strided_signed => bgra_to_rgba4444_dithered_strided(), 32, 16;
+        convert_u16_to_u8_dithered_strided_signed => convert_u16_to_u8_dithered_strided(channels: usize), 16, 8;
+        pack_gray8_to_gray1_dithered_strided_signed => pack_gray8_to_gray1_dithered_strided(), 8, 1;
+        pack_gray8_to_gray2_dithered_strided_signed => pack_gray8_to_gray2_dithered_strided(), 8, 2;
+        pack_gray8_to_gray4_dithered_strided_signed => pack_gray8_to_gray4_dithered_strided(), 8, 4;
+    }
+
+    signed_inplace! {
+        premultiply_alpha_f32_strided_signed => premultiply_alpha_f32_strided, 16;
+        unpremultiply_alpha_f32_strided_signed => unpremultiply_alpha_f32_strided, 16;
+        premultiply_alpha_rgba_u8_strided_signed => premultiply_alpha_rgba_u8_strided, 4;
+        premultiply_alpha_bgra_u8_strided_signed => premultiply_alpha_bgra_u8_strided, 4;
+    }
+
+    /// Validates both buffers of a Floyd–Steinberg call and `scratch` (at
+    /// least `scratch_len` elements).
+    #[allow(clippy::too_many_arguments)]
+    fn check_fs(
+        src: &[u8],
+        dst: &[u8],
+        (src_row, dst_row): (usize, usize),
+        height: usize,
+        src_stride: SignedStride,
+        dst_stride: SignedStride,
+        scratch: &[i16],
+        scratch_len: usize,
+    ) -> Result<(), SizeError> {
+        resolve(Operand::Src, src.len(), src_row, height, src_stride)?;
+        resolve(Operand::Dst, dst.len(), dst_row, height, dst_stride)?;
+        check_scratch(scratch.len(), scratch_len)?;
+        Ok(())
+    }
+
+    macro_rules! signed_fs {
+        ($($name:ident => $base:ident, $engine:ident, $channels:literal, $order:expr;)*) => {
+            $(
+                #[doc = concat!("[`", stringify!($base), "`](super::", stringify!($base), ") with signed strides; see [`SignedStride`].")]
+                #[allow(clippy::too_many_arguments)]
+                pub fn $name(
+                    src: &[u8],
+                    dst: &mut [u8],
+                    width: usize,
+                    height: usize,
+                    src_stride: SignedStride,
+                    dst_stride: SignedStride,
+                    scratch: &mut [i16],
+                ) -> Result<(), SizeError> {
+                    let rows = (width.saturating_mul(4), width.saturating_mul(2));
+                    let need = width.saturating_mul($channels);
+                    check_fs(src, dst, rows, height, src_stride, dst_stride, scratch, need)?;
+                    $engine(src, dst, width, height, src_stride, dst_stride, scratch, $order);
+                    Ok(())
+                }
+            )*
+        };
+    }
+
+    signed_fs! {
+        rgba_to_rgb565_floyd_steinberg_strided_signed => rgba_to_rgb565_floyd_steinberg_strided, to_rgb565_fs, 3, [0, 1, 2];
+        bgra_to_rgb565_floyd_steinberg_strided_signed => bgra_to_rgb565_floyd_steinberg_strided, to_rgb565_fs, 3, [2, 1, 0];
+        rgba_to_rgba4444_floyd_steinberg_strided_signed => rgba_to_rgba4444_floyd_steinberg_strided, to_rgba4444_fs, 4, [0, 1, 2, 3];
+        bgra_to_rgba4444_floyd_steinberg_strided_signed => bgra_to_rgba4444_floyd_steinberg_strided, to_rgba4444_fs, 4, [2, 1, 0, 3];
+    }
+
+    /// [`convert_u16_to_u8_floyd_steinberg_strided`](super::convert_u16_to_u8_floyd_steinberg_strided)
+    /// with signed strides; see [`SignedStride`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn convert_u16_to_u8_floyd_steinberg_strided_signed(
+        src: &[u8],
+        dst: &mut [u8],
+        width: usize,
+        height: usize,
+        src_stride: SignedStride,
+        dst_stride: SignedStride,
+        channels: usize,
+        scratch: &mut [i16],
+    ) -> Result<(), SizeError> {
+        if !(1..=4).contains(&channels) || !width.is_multiple_of(channels) {
+            return Err(SizeErrorKind::NotPixelAligned.into());
+        }
+        let rows = (width.saturating_mul(2), width);
+        check_fs(
+            src, dst, rows, height, src_stride, dst_stride, scratch, width,
+        )?;
+        let (w, h, ss, ds) = (width / channels, height, src_stride, dst_stride);
+        match channels {
+            1 => u16_to_u8_fs::<1>(src, dst, w, h, ss, ds, scratch),
+            2 => u16_to_u8_fs::<2>(src, dst, w, h, ss, ds, scratch),
+            3 => u16_to_u8_fs::<3>(src, dst, w, h, ss, ds, scratch),
+            _ => u16_to_u8_fs::<4>(src, dst, w, h, ss, ds, scratch),
+        }
+        Ok(())
+    }
+
+    macro_rules! signed_indexed_fs {
+        ($($name:ident => $base:ident, $order:expr;)*) => {
+            $(
+                #[doc = concat!("[`", stringify!($base), "`](super::", stringify!($base), ") with signed strides; see [`SignedStride`].")]
+                #[allow(clippy::too_many_arguments)]
+                pub fn $name(
+                    src: &[u8],
+                    palette: &[[u8; 4]],
+                    dst: &mut [u8],
+                    width: usize,
+                    height: usize,
+                    src_stride: SignedStride,
+                    dst_stride: SignedStride,
+                    scratch: &mut [i16],
+                ) -> Result<(), SizeError> {
+                    let rows = (width.saturating_mul(4), width);
+                    resolve(Operand::Src, src.len(), rows.0, height, src_stride)?;
+                    resolve(Operand::Dst, dst.len(), rows.1, height, dst_stride)?;
+                    map_fs(
+                        src, palette, dst, width, height, src_stride, dst_stride, scratch, $order,
+                    )
+                }
+            )*
+        };
+    }
+
+    signed_indexed_fs! {
+        rgba_to_indexed8_floyd_steinberg_strided_signed => rgba_to_indexed8_floyd_steinberg_strided, RGBA;
+        bgra_to_indexed8_floyd_steinberg_strided_signed => bgra_to_indexed8_floyd_steinberg_strided, BGRA;
+    }
+}
+#[cfg(feature = "experimental")]
+pub use experimental::*;
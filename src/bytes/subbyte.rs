@@ -0,0 +1,554 @@
+//! Sub-byte gray formats (1, 2 and 4 bits per pixel, packed MSB-first).
+//!
+//! PNG, BMP and TIFF store low-depth grayscale with several pixels per byte,
+//! the leftmost pixel in the most significant bits. Each row starts on a byte
+//! boundary, so the last byte of a row may carry unused padding bits.
+//!
+//! Unpacking scales each level to the full 8-bit range by bit replication
+//! (1-bit `1` → 255, 2-bit `k` → `k * 0x55`, 4-bit `k` → `k * 0x11`), which
+//! is exact for these widths. Packing is the inverse: round to the nearest
+//! level, compare against a fixed threshold (1-bit only), or ordered-dither
+//! with an [`OrderedDither`]. Padding bits in the last byte of a row are
+//! written as zero.
+//!
+//! The 8-bit side sets the pixel count: contiguous unpacking fills all of
+//! `dst`, contiguous packing consumes all of `src`, and the packed buffer must
+//! hold `ceil(pixels * bits / 8)` bytes. Strided forms take `width` in pixels
+//! and packed strides in bytes (at least `ceil(width * bits / 8)`).
+
+#![cfg_attr(target_arch = "x86", allow(unused_imports))]
+
+use archmage::prelude::*;
+
+use super::dither::{LEVELS1, LEVELS2, LEVELS4, OrderedDither, ordered_u8};
+use super::{check_packed, check_packed_strided, check_strided, packed_row_bytes};
+use crate::{Operand, SizeError};
+
+// ===========================================================================
+// Row kernels
+// ===========================================================================
+//
+// `BITS` is 1, 2 or 4. Pixel `j` of a byte sits at shift `8 - BITS * (j + 1)`.
+// Unpacking multiplies each level by `scale` (255 / 85 / 17).
+
+#[autoversion(v3, neon, wasm128)]
+fn unpack_row<const BITS: u32>(s: &[u8], d: &mut [u8], scale: u8) {
+    let ppb = (8 / BITS) as usize;
+    let mask = ((1u32 << BITS) - 1) as u8;
+    for (d, &s) in d.chunks_mut(ppb).zip(s) {
+        for (j, d) in d.iter_mut().enumerate() {
+            *d = ((s >> (8 - BITS * (j as u32 + 1))) & mask) * scale;
+        }
+    }
+}
+
+/// Pack `s` into `d`, quantizing pixel `i` with `q(i, v)` to a `BITS`-bit level.
+#[inline(always)]
+fn pack_with<const BITS: u32>(s: &[u8], d: &mut [u8], q: impl Fn(usize, u8) -> u8) {
+    let ppb = (8 / BITS) as usize;
+    for (i, (s, d)) in s.chunks(ppb).zip(d.iter_mut()).enumerate() {
+        let mut b = 0u8;
+        for (j, &v) in s.iter().enumerate() {
+            b |= q(i * ppb + j, v) << (8 - BITS * (j as u32 + 1));
+        }
+        *d = b;
+    }
+}
+
+#[autoversion(v3, neon, wasm128)]
+fn pack_nearest_row<const BITS: u32>(s: &[u8], d: &mut [u8]) {
+    let max = (1u32 << BITS) - 1;
+    pack_with::<BITS>(s, d, |_, v| ((v as u32 * max + 128) >> 8) as u8);
+}
+
+#[autoversion(v3, neon, wasm128)]
+fn pack_threshold_row(s: &[u8], d: &mut [u8], threshold: u8) {
+    pack_with::<1>(s, d, |_, v| (v >= threshold) as u8);
+}
+
+#[autoversion(v3, neon, wasm128)]
+fn pack_dither_row<const BITS: u32>(s: &[u8], d: &mut [u8], dither: OrderedDither, y: usize) {
+    let levels = match BITS {
+        1 => &LEVELS1,
+        2 => &LEVELS2,
+        _ => &LEVELS4,
+    };
+    let thr = dither.row(y);
+    let mask = thr.len() - 1;
+    pack_with::<BITS>(s, d, |i, v| {
+        ordered_u8(levels, v, thr[(dither.x + i) & mask] as u32) as u8
+    });
+}
+
+// ===========================================================================
+// Shared drivers
+// ===========================================================================
+
+fn unpack<const BITS: u32>(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_packed(Operand::Src, dst.len(), src.len(), BITS)?;
+    unpack_row::<BITS>(src, dst, 255 / ((1 << BITS) - 1));
+    Ok(())
+}
+
+fn unpack_strided<const BITS: u32>(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_packed_strided(Operand::Src, src.len(), width, height, src_stride, BITS)?;
+    check_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
+    let row_bytes = packed_row_bytes(width, BITS);
+    for y in 0..height {
+        unpack_row::<BITS>(
+            &src[y * src_stride..][..row_bytes],
+            &mut dst[y * dst_stride..][..width],
+            255 / ((1 << BITS) - 1),
+        );
+    }
+    Ok(())
+}
+
+fn pack<const BITS: u32>(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    check_packed(Operand::Dst, src.len(), dst.len(), BITS)?;
+    pack_nearest_row::<BITS>(src, dst);
+    Ok(())
+}
+
+fn pack_strided<const BITS: u32>(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 1)?;
+    check_packed_strided(Operand::Dst, dst.len(), width, height, dst_stride, BITS)?;
+    let row_bytes = packed_row_bytes(width, BITS);
+    for y in 0..height {
+        pack_nearest_row::<BITS>(
+            &src[y * src_stride..][..width],
+            &mut dst[y * dst_stride..][..row_bytes],
+        );
+    }
+    Ok(())
+}
+
+fn pack_dithered<const BITS: u32>(
+    src: &[u8],
+    dst: &mut [u8],
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    check_packed(Operand::Dst, src.len(), dst.len(), BITS)?;
+    pack_dither_row::<BITS>(src, dst, dither, 0);
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn pack_dithered_strided<const BITS: u32>(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 1)?;
+    check_packed_strided(Operand::Dst, dst.len(), width, height, dst_stride, BITS)?;
+    let row_bytes = packed_row_bytes(width, BITS);
+    for y in 0..height {
+        pack_dither_row::<BITS>(
+            &src[y * src_stride..][..width],
+            &mut dst[y * dst_stride..][..row_bytes],
+            dither,
+            y,
+        );
+    }
+    Ok(())
+}
+
+// ===========================================================================
+// Public API — unpacking (N-bit → Gray8)
+// ===========================================================================
+
+/// 1-bit gray (8 px/byte, MSB first) → Gray8. `0` → 0, `1` → 255.
+///
+/// Converts `dst.len()` pixels; `src` must hold at least `ceil(dst.len() / 8)` bytes.
+pub fn unpack_gray1_to_gray8(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    unpack::<1>(src, dst)
+}
+
+/// 2-bit gray (4 px/byte, MSB first) → Gray8. Level `k` → `k * 0x55`.
+///
+/// Converts `dst.len()` pixels; `src` must hold at least `ceil(dst.len() / 4)` bytes.
+pub fn unpack_gray2_to_gray8(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    unpack::<2>(src, dst)
+}
+
+/// 4-bit gray (2 px/byte, high nibble first) → Gray8. Level `k` → `k * 0x11`.
+///
+/// Converts `dst.len()` pixels; `src` must hold at least `ceil(dst.len() / 2)` bytes.
+pub fn unpack_gray4_to_gray8(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    unpack::<4>(src, dst)
+}
+
+/// 1-bit gray → Gray8 between strided buffers.
+///
+/// `width` is pixels per row. `src_stride` is in bytes and must be at least
+/// `ceil(width / 8)`; padding bits at the end of each source row are ignored.
+pub fn unpack_gray1_to_gray8_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    unpack_strided::<1>(src, dst, width, height, src_stride, dst_stride)
+}
+
+/// 2-bit gray → Gray8 between strided buffers.
+///
+/// `width` is pixels per row. `src_stride` is in bytes and must be at least
+/// `ceil(width / 4)`; padding bits at the end of each source row are ignored.
+pub fn unpack_gray2_to_gray8_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    unpack_strided::<2>(src, dst, width, height, src_stride, dst_stride)
+}
+
+/// 4-bit gray → Gray8 between strided buffers.
+///
+/// `width` is pixels per row. `src_stride` is in bytes and must be at least
+/// `ceil(width / 2)`; padding bits at the end of each source row are ignored.
+pub fn unpack_gray4_to_gray8_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    unpack_strided::<4>(src, dst, width, height, src_stride, dst_stride)
+}
+
+// ===========================================================================
+// Public API — packing (Gray8 → N-bit)
+// ===========================================================================
+
+/// Gray8 → 1-bit gray (8 px/byte, MSB first), rounding to nearest (`v >= 128` → 1).
+///
+/// Converts `src.len()` pixels; `dst` must hold at least `ceil(src.len() / 8)` bytes.
+pub fn pack_gray8_to_gray1(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    pack::<1>(src, dst)
+}
+
+/// Gray8 → 2-bit gray (4 px/byte, MSB first), rounding to the nearest level.
+///
+/// Converts `src.len()` pixels; `dst` must hold at least `ceil(src.len() / 4)` bytes.
+pub fn pack_gray8_to_gray2(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    pack::<2>(src, dst)
+}
+
+/// Gray8 → 4-bit gray (2 px/byte, high nibble first), rounding to the nearest level.
+///
+/// Converts `src.len()` pixels; `dst` must hold at least `ceil(src.len() / 2)` bytes.
+pub fn pack_gray8_to_gray4(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    pack::<4>(src, dst)
+}
+
+/// Gray8 → 1-bit gray with a caller-chosen threshold: `v >= threshold` → 1.
+///
+/// Converts `src.len()` pixels; `dst` must hold at least `ceil(src.len() / 8)` bytes.
+pub fn pack_gray8_to_gray1_threshold(
+    src: &[u8],
+    dst: &mut [u8],
+    threshold: u8,
+) -> Result<(), SizeError> {
+    check_packed(Operand::Dst, src.len(), dst.len(), 1)?;
+    pack_threshold_row(src, dst, threshold);
+    Ok(())
+}
+
+/// Gray8 → 1-bit gray with ordered dithering.
+///
+/// Flat areas keep their average brightness; pure black and white are never
+/// dithered. See [`OrderedDither`] for matrix choice and tile origins.
+pub fn pack_gray8_to_gray1_dithered(
+    src: &[u8],
+    dst: &mut [u8],
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    pack_dithered::<1>(src, dst, dither)
+}
+
+/// Gray8 → 2-bit gray with ordered dithering. Exact levels are never dithered.
+pub fn pack_gray8_to_gray2_dithered(
+    src: &[u8],
+    dst: &mut [u8],
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    pack_dithered::<2>(src, dst, dither)
+}
+
+/// Gray8 → 4-bit gray with ordered dithering. Exact levels are never dithered.
+pub fn pack_gray8_to_gray4_dithered(
+    src: &[u8],
+    dst: &mut [u8],
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    pack_dithered::<4>(src, dst, dither)
+}
+
+/// Gray8 → 1-bit gray between strided buffers, rounding to nearest.
+///
+/// `width` is pixels per row. `dst_stride` is in bytes and must be at least
+/// `ceil(width / 8)`; padding bits in the last byte of each row are zeroed.
+pub fn pack_gray8_to_gray1_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    pack_strided::<1>(src, dst, width, height, src_stride, dst_stride)
+}
+
+/// Gray8 → 2-bit gray between strided buffers, rounding to nearest.
+///
+/// `width` is pixels per row. `dst_stride` is in bytes and must be at least
+/// `ceil(width / 4)`; padding bits in the last byte of each row are zeroed.
+pub fn pack_gray8_to_gray2_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    pack_strided::<2>(src, dst, width, height, src_stride, dst_stride)
+}
+
+/// Gray8 → 4-bit gray between strided buffers, rounding to nearest.
+///
+/// `width` is pixels per row. `dst_stride` is in bytes and must be at least
+/// `ceil(width / 2)`; padding bits in the last byte of each row are zeroed.
+pub fn pack_gray8_to_gray4_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), SizeError> {
+    pack_strided::<4>(src, dst, width, height, src_stride, dst_stride)
+}
+
+/// Gray8 → 1-bit gray between strided buffers with a fixed threshold.
+#[allow(clippy::too_many_arguments)]
+pub fn pack_gray8_to_gray1_threshold_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    threshold: u8,
+) -> Result<(), SizeError> {
+    check_strided(Operand::Src, src.len(), width, height, src_stride, 1)?;
+    check_packed_strided(Operand::Dst, dst.len(), width, height, dst_stride, 1)?;
+    let row_bytes = packed_row_bytes(width, 1);
+    for y in 0..height {
+        pack_threshold_row(
+            &src[y * src_stride..][..width],
+            &mut dst[y * dst_stride..][..row_bytes],
+            threshold,
+        );
+    }
+    Ok(())
+}
+
+/// Gray8 → 1-bit gray between strided buffers with ordered dithering.
+#[allow(clippy::too_many_arguments)]
+pub fn pack_gray8_to_gray1_dithered_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    pack_dithered_strided::<1>(src, dst, width, height, src_stride, dst_stride, dither)
+}
+
+/// Gray8 → 2-bit gray between strided buffers with ordered dithering.
+#[allow(clippy::too_many_arguments)]
+pub fn pack_gray8_to_gray2_dithered_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    pack_dithered_strided::<2>(src, dst, width, height, src_stride, dst_stride, dither)
+}
+
+/// Gray8 → 4-bit gray between strided buffers with ordered dithering.
+#[allow(clippy::too_many_arguments)]
+pub fn pack_gray8_to_gray4_dithered_strided(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+    dither: OrderedDither,
+) -> Result<(), SizeError> {
+    pack_dithered_strided::<4>(src, dst, width, height, src_stride, dst_stride, dither)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+    use crate::SizeErrorKind;
+    use crate::bytes::DitherMatrix;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn unpack_scales_by_bit_replication() {
+        let mut d = [0u8; 8];
+        unpack_gray1_to_gray8(&[0b1010_0001], &mut d).unwrap();
+        assert_eq!(d, [255, 0, 255, 0, 0, 0, 0, 255]);
+
+        let mut d = [0u8; 4];
+        unpack_gray2_to_gray8(&[0b00_01_10_11], &mut d).unwrap();
+        assert_eq!(d, [0, 0x55, 0xAA, 0xFF]);
+
+        let mut d = [0u8; 3];
+        unpack_gray4_to_gray8(&[0x0F, 0x7A], &mut d).unwrap();
+        assert_eq!(d, [0x00, 0xFF, 0x77]);
+    }
+
+    #[test]
+    fn roundtrip_all_levels() {
+        for (bits, n) in [(1u32, 2usize), (2, 4), (4, 16)] {
+            let levels: Vec<u8> = (0..n).map(|k| (k * 255 / (n - 1)) as u8).collect();
+            let mut packed = vec![0u8; packed_row_bytes(n, bits)];
+            let mut back = vec![0u8; n];
+            match bits {
+                1 => {
+                    pack_gray8_to_gray1(&levels, &mut packed).unwrap();
+                    unpack_gray1_to_gray8(&packed, &mut back).unwrap();
+                }
+                2 => {
+                    pack_gray8_to_gray2(&levels, &mut packed).unwrap();
+                    unpack_gray2_to_gray8(&packed, &mut back).unwrap();
+                }
+                _ => {
+                    pack_gray8_to_gray4(&levels, &mut packed).unwrap();
+                    unpack_gray4_to_gray8(&packed, &mut back).unwrap();
+                }
+            }
+            assert_eq!(back, levels, "bits={bits}");
+            // Exact levels are never dithered either.
+            let mut dithered = vec![0u8; packed.len()];
+            let dither = OrderedDither::new(DitherMatrix::Bayer4);
+            match bits {
+                1 => pack_gray8_to_gray1_dithered(&levels, &mut dithered, dither),
+                2 => pack_gray8_to_gray2_dithered(&levels, &mut dithered, dither),
+                _ => pack_gray8_to_gray4_dithered(&levels, &mut dithered, dither),
+            }
+            .unwrap();
+            assert_eq!(dithered, packed, "bits={bits}");
+        }
+    }
+
+    #[test]
+    fn strided_row_padding() {
+        // 11 px of 1-bit → 2 bytes per row, stored with a 4-byte stride.
+        let (w, h, ss) = (11, 3, 4);
+        let gray: Vec<u8> = (0..w * h)
+            .map(|i| if i % 3 == 0 { 200 } else { 10 })
+            .collect();
+        let mut packed = vec![0xEEu8; ss * h];
+        pack_gray8_to_gray1_strided(&gray, &mut packed, w, h, w, ss).unwrap();
+        for y in 0..h {
+            // Padding bits of the last used byte are zero; padding bytes untouched.
+            assert_eq!(packed[y * ss + 1] & 0b0001_1111, 0);
+            assert_eq!(&packed[y * ss + 2..][..2], &[0xEE, 0xEE]);
+        }
+        let mut back = vec![0u8; w * h];
+        unpack_gray1_to_gray8_strided(&packed, &mut back, w, h, ss, w).unwrap();
+        for (b, g) in back.iter().zip(&gray) {
+            assert_eq!(*b, if *g >= 128 { 255 } else { 0 });
+        }
+
+        let mut t = vec![0u8; ss * h];
+        pack_gray8_to_gray1_threshold_strided(&gray, &mut t, w, h, w, ss, 5).unwrap();
+        assert!(t.chunks(ss).all(|r| r[..2] == [0xFF, 0xE0]));
+
+        assert_eq!(
+            unpack_gray4_to_gray8_strided(&packed, &mut back, w, h, 5, w).map_err(|e| e.kind()),
+            Err(SizeErrorKind::InvalidStride)
+        );
+    }
+
+    #[test]
+    fn dithered_flat_area_keeps_mean() {
+        let src = [100u8; 64 * 8];
+        let mut packed = [0u8; 64];
+        pack_gray8_to_gray1_dithered_strided(
+            &src,
+            &mut packed,
+            64,
+            8,
+            64,
+            8,
+            OrderedDither::new(DitherMatrix::Bayer8),
+        )
+        .unwrap();
+        let ones: u32 = packed.iter().map(|b| b.count_ones()).sum();
+        // 100 / 255 of 512 pixels ≈ 200.8
+        assert!((195..=206).contains(&ones), "ones={ones}");
+    }
+
+    #[test]
+    fn size_errors() {
+        let mut d = [0u8; 9];
+        assert_eq!(
+            unpack_gray1_to_gray8(&[0], &mut d).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
+        );
+        assert_eq!(
+            pack_gray8_to_gray4(&[], &mut d).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
+        );
+        assert_eq!(
+            pack_gray8_to_gray2(&[0; 9], &mut d[..2]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
+        );
+
+        // The short side is the packed one: the source when unpacking.
+        let e = unpack_gray2_to_gray8(&[0; 2], &mut d).unwrap_err();
+        assert_eq!(e.operand(), Some(Operand::Src));
+        assert_eq!((e.required(), e.actual()), (Some(3), Some(2)));
+        let e = pack_gray8_to_gray2(&[0; 9], &mut d[..2]).unwrap_err();
+        assert_eq!(e.operand(), Some(Operand::Dst));
+        assert_eq!((e.required(), e.actual()), (Some(3), Some(2)));
+    }
+}
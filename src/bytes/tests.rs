@@ -1,7 +1,7 @@
 extern crate alloc;
 extern crate std;
 use super::*;
-use alloc::{vec, vec::Vec};
+use alloc::{string::ToString, vec, vec::Vec};
 use archmage::testing::{CompileTimePolicy, for_each_token_permutation};
 
 fn policy() -> CompileTimePolicy {
@@ -630,6 +630,89 @@ fn permutation_strided_3bpp_and_strip() {
     std::eprintln!("strided_3bpp_and_strip: {report}");
 }
 
+#[test]
+fn permutation_strided_repack_inplace() {
+    let report = for_each_token_permutation(policy(), |perm| {
+        // Widths around the internal chunk size exercise partial chunks.
+        for w in [1, 5, 63, 64, 65, 200] {
+            let h = 3;
+            let stride = w * 4 + 5;
+            let src4: Vec<u8> = (0..stride * h).map(|i| (i % 251) as u8).collect();
+
+            let mut buf = src4.clone();
+            rgba_to_rgb_inplace_strided(&mut buf, w, h, stride).unwrap();
+            for y in 0..h {
+                let row = &src4[y * stride..][..w * 4];
+                assert_eq!(
+                    buf[y * stride..][..w * 3],
+                    ref_rgba_to_rgb(row),
+                    "rgba_to_rgb_inplace_strided w={w} y={y} tier={perm}"
+                );
+            }
+
+            let mut buf = src4.clone();
+            rgb_to_rgba_inplace_strided(&mut buf, w, h, stride).unwrap();
+            for y in 0..h {
+                let row = &src4[y * stride..][..w * 3];
+                assert_eq!(
+                    buf[y * stride..][..w * 4],
+                    ref_rgb_to_rgba(row),
+                    "rgb_to_rgba_inplace_strided w={w} y={y} tier={perm}"
+                );
+                // Row padding is untouched.
+                assert_eq!(
+                    buf[y * stride + w * 4..][..5],
+                    src4[y * stride + w * 4..][..5]
+                );
+            }
+        }
+
+        // Contiguous forms return the converted prefix.
+        for n in [1, 7, 64, 129] {
+            let src4 = make_4bpp(n);
+            let mut buf = src4.clone();
+            let rgb = rgba_to_rgb_inplace(&mut buf).unwrap();
+            assert_eq!(rgb.len(), n * 3);
+            assert_eq!(
+                *rgb,
+                ref_rgba_to_rgb(&src4),
+                "rgba_to_rgb_inplace n={n} tier={perm}"
+            );
+
+            let src3 = make_3bpp(n);
+            let mut buf = src3.clone();
+            buf.resize(n * 4 + 3, 0xEE);
+            let rgba = rgb_to_rgba_inplace(&mut buf, n).unwrap();
+            assert_eq!(
+                *rgba,
+                ref_rgb_to_rgba(&src3),
+                "rgb_to_rgba_inplace n={n} tier={perm}"
+            );
+            assert_eq!(buf[n * 4..], [0xEE; 3]);
+        }
+        assert_eq!(
+            rgb_to_rgba_inplace(&mut [0u8; 11], 3)
+                .map(|b| b.len())
+                .map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
+        );
+        assert_eq!(
+            rgba_to_rgb_inplace(&mut [0u8; 6])
+                .map(|b| b.len())
+                .map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
+        );
+
+        // Growing needs room for the RGBA row.
+        let mut buf = vec![0u8; 4 * 10 * 2];
+        assert_eq!(
+            rgb_to_rgba_inplace_strided(&mut buf, 10, 2, 39).map_err(|e| e.kind()),
+            Err(SizeErrorKind::InvalidStride)
+        );
+    });
+    std::eprintln!("strided_repack_inplace: {report}");
+}
+
 // -----------------------------------------------------------------------
 // ARGB/XRGB operations
 // -----------------------------------------------------------------------
@@ -1096,6 +1179,217 @@ fn permutation_strided_argb_cross_bpp() {
     std::eprintln!("strided_argb_cross_bpp: {report}");
 }
 
+// -----------------------------------------------------------------------
+// Byte-order swaps (u16 / u32)
+// -----------------------------------------------------------------------
+
+fn ref_swap_bytes(data: &[u8], n: usize) -> Vec<u8> {
+    let mut out = data.to_vec();
+    for v in out.chunks_exact_mut(n) {
+        v.reverse();
+    }
+    out
+}
+
+#[test]
+fn permutation_swap_bytes() {
+    let report = for_each_token_permutation(policy(), |perm| {
+        for &n in TEST_PIXEL_COUNTS {
+            // n × 4 bytes: n*2 u16 elements or n u32 elements
+            let src = make_4bpp(n);
+
+            let expected16 = ref_swap_bytes(&src, 2);
+            let mut data = src.clone();
+            swap_bytes_u16_inplace(&mut data).unwrap();
+            assert_eq!(data, expected16, "swap_bytes_u16_inplace n={n} tier={perm}");
+            let mut dst = vec![0u8; n * 4];
+            swap_bytes_u16(&src, &mut dst).unwrap();
+            assert_eq!(dst, expected16, "swap_bytes_u16 n={n} tier={perm}");
+
+            let expected32 = ref_swap_bytes(&src, 4);
+            let mut data = src.clone();
+            swap_bytes_u32_inplace(&mut data).unwrap();
+            assert_eq!(data, expected32, "swap_bytes_u32_inplace n={n} tier={perm}");
+            let mut dst = vec![0u8; n * 4];
+            swap_bytes_u32(&src, &mut dst).unwrap();
+            assert_eq!(dst, expected32, "swap_bytes_u32 n={n} tier={perm}");
+        }
+    });
+    std::eprintln!("swap_bytes: {report}");
+}
+
+#[test]
+fn permutation_strided_swap_bytes() {
+    let report = for_each_token_permutation(policy(), |perm| {
+        // 3 × RGBA64 pixels per row = 12 u16 elements = 6 u32 elements (24 bytes)
+        let h = 4;
+        let stride = 24 + 10;
+        let src: Vec<u8> = (0..stride * h).map(|i| (i % 251) as u8).collect();
+
+        for (elem, w) in [(2usize, 12usize), (4, 6)] {
+            let mut inplace = src.clone();
+            let mut copied = vec![0xCCu8; stride * h];
+            if elem == 2 {
+                swap_bytes_u16_inplace_strided(&mut inplace, w, h, stride).unwrap();
+                swap_bytes_u16_strided(&src, &mut copied, w, h, stride, stride).unwrap();
+            } else {
+                swap_bytes_u32_inplace_strided(&mut inplace, w, h, stride).unwrap();
+                swap_bytes_u32_strided(&src, &mut copied, w, h, stride, stride).unwrap();
+            }
+            for y in 0..h {
+                let row = &src[y * stride..][..stride];
+                let expected = ref_swap_bytes(&row[..24], elem);
+                assert_eq!(&inplace[y * stride..][..24], &expected[..], "tier={perm}");
+                assert_eq!(&copied[y * stride..][..24], &expected[..], "tier={perm}");
+                // Padding untouched
+                assert_eq!(&inplace[y * stride + 24..][..10], &row[24..], "tier={perm}");
+                assert!(copied[y * stride + 24..][..10].iter().all(|&b| b == 0xCC));
+            }
+        }
+
+        assert_eq!(
+            swap_bytes_u32_inplace(&mut [0u8; 6]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
+        );
+    });
+    std::eprintln!("strided_swap_bytes: {report}");
+}
+
+// -----------------------------------------------------------------------
+// Palette expansion
+// -----------------------------------------------------------------------
+
+fn make_palette(n: usize) -> Vec<[u8; 4]> {
+    (0..n)
+        .map(|i| {
+            let i = i as u8;
+            [i.wrapping_mul(7), i ^ 0x5A, 255 - i, i.wrapping_mul(3) | 1]
+        })
+        .collect()
+}
+
+fn ref_indexed(idx: &[u8], palette: &[[u8; 4]], order: [usize; 4], bpp: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    for &i in idx {
+        let p = palette.get(i as usize).copied().unwrap_or([0; 4]);
+        for &c in &order[..bpp] {
+            out.push(p[c]);
+        }
+    }
+    out
+}
+
+#[test]
+fn permutation_indexed8() {
+    type Expand = fn(&[u8], &[[u8; 4]], &mut [u8]) -> Result<(), SizeError>;
+    let cases: [(Expand, [usize; 4], usize); 4] = [
+        (indexed8_to_rgba, [0, 1, 2, 3], 4),
+        (indexed8_to_bgra, [2, 1, 0, 3], 4),
+        (indexed8_to_argb, [3, 0, 1, 2], 4),
+        (indexed8_to_rgb, [0, 1, 2, 3], 3),
+    ];
+    let report = for_each_token_permutation(policy(), |perm| {
+        // 16 and below take the shuffle path; indices run past every palette.
+        for pal_len in [1, 5, 16, 17, 256] {
+            let palette = make_palette(pal_len);
+            for &n in TEST_PIXEL_COUNTS {
+                let idx: Vec<u8> = (0..n).map(|i| (i * 13 % 40) as u8).collect();
+                for (f, order, bpp) in cases {
+                    let mut dst = vec![0xCCu8; n * bpp];
+                    f(&idx, &palette, &mut dst).unwrap();
+                    assert_eq!(
+                        dst,
+                        ref_indexed(&idx, &palette, order, bpp),
+                        "pal={pal_len} n={n} bpp={bpp} tier={perm}"
+                    );
+                }
+            }
+        }
+    });
+    std::eprintln!("indexed8: {report}");
+}
+
+#[test]
+fn permutation_indexed_strided_and_subbyte() {
+    let report = for_each_token_permutation(policy(), |perm| {
+        let (w, h) = (37, 3);
+        let palette = make_palette(12);
+        let dst_stride = w * 4 + 12;
+
+        // 8-bit, strided, small palette (shuffle path).
+        let src_stride = w + 5;
+        let idx: Vec<u8> = (0..src_stride * h).map(|i| (i % 14) as u8).collect();
+        let mut dst = vec![0xCCu8; dst_stride * h];
+        indexed8_to_bgra_strided(&idx, &palette, &mut dst, w, h, src_stride, dst_stride).unwrap();
+        for y in 0..h {
+            let row = &idx[y * src_stride..][..w];
+            let expected = ref_indexed(row, &palette, [2, 1, 0, 3], 4);
+            assert_eq!(
+                &dst[y * dst_stride..][..w * 4],
+                &expected[..],
+                "tier={perm}"
+            );
+            assert!(
+                dst[y * dst_stride + w * 4..][..12]
+                    .iter()
+                    .all(|&b| b == 0xCC)
+            );
+        }
+
+        // 1/2/4-bit, strided with row padding.
+        for bits in [1usize, 2, 4] {
+            let row_bytes = (w * bits).div_ceil(8);
+            let src_stride = row_bytes + 3;
+            let packed: Vec<u8> = (0..src_stride * h).map(|i| (i * 29 % 256) as u8).collect();
+            let mut dst = vec![0u8; dst_stride * h];
+            match bits {
+                1 => indexed1_to_rgba_strided(
+                    &packed, &palette, &mut dst, w, h, src_stride, dst_stride,
+                ),
+                2 => indexed2_to_rgba_strided(
+                    &packed, &palette, &mut dst, w, h, src_stride, dst_stride,
+                ),
+                _ => indexed4_to_rgba_strided(
+                    &packed, &palette, &mut dst, w, h, src_stride, dst_stride,
+                ),
+            }
+            .unwrap();
+            for y in 0..h {
+                let row: Vec<u8> = (0..w)
+                    .map(|x| {
+                        let b = packed[y * src_stride + x * bits / 8];
+                        let shift = 8 - bits * (x % (8 / bits) + 1);
+                        (b >> shift) & ((1 << bits) - 1) as u8
+                    })
+                    .collect();
+                let expected = ref_indexed(&row, &palette, [0, 1, 2, 3], 4);
+                assert_eq!(
+                    &dst[y * dst_stride..][..w * 4],
+                    &expected[..],
+                    "bits={bits} y={y} tier={perm}"
+                );
+            }
+        }
+
+        // Contiguous sub-byte: the destination sets the pixel count.
+        let mut rgb = [0u8; 5 * 3];
+        indexed2_to_rgb(&[0b00_01_10_11, 0b1100_0000], &palette, &mut rgb).unwrap();
+        assert_eq!(
+            rgb,
+            &ref_indexed(&[0, 1, 2, 3, 3], &palette, [0, 1, 2, 3], 3)[..]
+        );
+        assert_eq!(
+            indexed2_to_rgb(&[0], &palette, &mut rgb).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
+        );
+        assert_eq!(
+            indexed4_to_rgba(&[0], &palette, &mut [0u8; 6]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
+        );
+    });
+    std::eprintln!("indexed_strided_and_subbyte: {report}");
+}
+
 // -----------------------------------------------------------------------
 // Gray layout conversions (no luma weights)
 // -----------------------------------------------------------------------
@@ -1533,31 +1827,96 @@ mod experimental_tests {
         std::eprintln!("depth_u16_f32: {report}");
     }
 
+    #[test]
+    fn permutation_depth_big_endian() {
+        let report = for_each_token_permutation(policy(), |perm| {
+            for &n in TEST_ELEMENT_COUNTS {
+                let src16: Vec<u16> = (0..n).map(|i| (i * 251 + 3) as u16).collect();
+                let ne: Vec<u8> = src16.iter().flat_map(|v| v.to_ne_bytes()).collect();
+                let be: Vec<u8> = src16.iter().flat_map(|v| v.to_be_bytes()).collect();
+
+                let (mut a, mut b) = (vec![0u8; n], vec![0u8; n]);
+                convert_u16_to_u8(&ne, &mut a).unwrap();
+                convert_u16be_to_u8(&be, &mut b).unwrap();
+                assert_eq!(a, b, "u16be→u8 n={n} tier={perm}");
+
+                let (mut wide_ne, mut wide_be) = (vec![0u8; n * 2], vec![0u8; n * 2]);
+                convert_u8_to_u16(&a, &mut wide_ne).unwrap();
+                convert_u8_to_u16be(&a, &mut wide_be).unwrap();
+                let expect: Vec<u8> = wide_ne.chunks_exact(2).flat_map(|p| [p[1], p[0]]).collect();
+                assert_eq!(wide_be, expect, "u8→u16be n={n} tier={perm}");
+
+                let (mut fa, mut fb) = (vec![0u8; n * 4], vec![0u8; n * 4]);
+                convert_u16_to_f32(&ne, &mut fa).unwrap();
+                convert_u16be_to_f32(&be, &mut fb).unwrap();
+                assert_eq!(fa, fb, "u16be→f32 n={n} tier={perm}");
+
+                let mut back = vec![0u8; n * 2];
+                convert_f32_to_u16be(&fb, &mut back).unwrap();
+                assert_eq!(back, be, "f32→u16be roundtrip n={n} tier={perm}");
+            }
+        });
+        std::eprintln!("depth_big_endian: {report}");
+    }
+
+    #[test]
+    fn depth_big_endian_strided() {
+        let (w, h, ss, ds) = (9, 4, 24, 12);
+        let mut src = vec![0u8; ss * h];
+        for y in 0..h {
+            for x in 0..w {
+                let v = (y * 1000 + x * 97) as u16;
+                src[y * ss + x * 2..][..2].copy_from_slice(&v.to_be_bytes());
+            }
+        }
+        let mut dst = vec![0xEEu8; ds * h];
+        convert_u16be_to_u8_strided(&src, &mut dst, w, h, ss, ds).unwrap();
+        let mut f = vec![0u8; 40 * h];
+        convert_u16be_to_f32_strided(&src, &mut f, w, h, ss, 40).unwrap();
+        let mut back = vec![0u8; ss * h];
+        convert_f32_to_u16be_strided(&f, &mut back, w, h, 40, ss).unwrap();
+        let mut wide = vec![0u8; ss * h];
+        convert_u8_to_u16be_strided(&dst, &mut wide, w, h, ds, ss).unwrap();
+        for y in 0..h {
+            assert_eq!(&back[y * ss..][..w * 2], &src[y * ss..][..w * 2]);
+            assert_eq!(&dst[y * ds + w..(y + 1) * ds], &[0xEE; 3]);
+            for x in 0..w {
+                let v = u16::from_be_bytes([src[y * ss + x * 2], src[y * ss + x * 2 + 1]]);
+                let q = ((v as u32 * 255 + 32768) >> 16) as u8;
+                assert_eq!(dst[y * ds + x], q);
+                assert_eq!(
+                    &wide[y * ss + x * 2..][..2],
+                    &(q as u16 * 257).to_be_bytes()
+                );
+            }
+        }
+    }
+
     #[test]
     fn depth_size_errors() {
         // Empty source
         assert_eq!(
-            convert_u8_to_u16(&[], &mut [0; 2]),
-            Err(SizeError::NotPixelAligned)
+            convert_u8_to_u16(&[], &mut [0; 2]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Source not aligned (u16 needs even bytes)
         assert_eq!(
-            convert_u16_to_u8(&[0; 3], &mut [0; 2]),
-            Err(SizeError::NotPixelAligned)
+            convert_u16_to_u8(&[0; 3], &mut [0; 2]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Source not aligned (f32 needs 4-byte multiple)
         assert_eq!(
-            convert_f32_to_u8(&[0; 5], &mut [0; 2]),
-            Err(SizeError::NotPixelAligned)
+            convert_f32_to_u8(&[0; 5], &mut [0; 2]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Dest too small
         assert_eq!(
-            convert_u8_to_u16(&[0; 4], &mut [0; 6]),
-            Err(SizeError::PixelCountMismatch)
+            convert_u8_to_u16(&[0; 4], &mut [0; 6]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
         assert_eq!(
-            convert_u8_to_f32(&[0; 4], &mut [0; 12]),
-            Err(SizeError::PixelCountMismatch)
+            convert_u8_to_f32(&[0; 4], &mut [0; 12]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
     }
 
@@ -1590,38 +1949,38 @@ mod experimental_tests {
 fn test_size_errors() {
     // Not pixel-aligned
     assert_eq!(
-        rgba_to_bgra_inplace(&mut [0; 5]),
-        Err(SizeError::NotPixelAligned)
+        rgba_to_bgra_inplace(&mut [0; 5]).map_err(|e| e.kind()),
+        Err(SizeErrorKind::NotPixelAligned)
     );
     assert_eq!(
-        rgba_to_bgra_inplace(&mut [0; 0]),
-        Err(SizeError::NotPixelAligned)
+        rgba_to_bgra_inplace(&mut [0; 0]).map_err(|e| e.kind()),
+        Err(SizeErrorKind::NotPixelAligned)
     );
     assert_eq!(
-        rgb_to_bgr_inplace(&mut [0; 5]),
-        Err(SizeError::NotPixelAligned)
+        rgb_to_bgr_inplace(&mut [0; 5]).map_err(|e| e.kind()),
+        Err(SizeErrorKind::NotPixelAligned)
     );
     assert_eq!(
-        gray_alpha_to_rgba(&[0; 3], &mut [0; 8]),
-        Err(SizeError::NotPixelAligned)
+        gray_alpha_to_rgba(&[0; 3], &mut [0; 8]).map_err(|e| e.kind()),
+        Err(SizeErrorKind::NotPixelAligned)
     );
     assert_eq!(
-        fill_alpha_rgba(&mut [0; 5]),
-        Err(SizeError::NotPixelAligned)
+        fill_alpha_rgba(&mut [0; 5]).map_err(|e| e.kind()),
+        Err(SizeErrorKind::NotPixelAligned)
     );
 
     // Pixel count mismatch (src aligned, dst too small)
     assert_eq!(
-        rgb_to_bgra(&[0; 6], &mut [0; 4]),
-        Err(SizeError::PixelCountMismatch)
+        rgb_to_bgra(&[0; 6], &mut [0; 4]).map_err(|e| e.kind()),
+        Err(SizeErrorKind::PixelCountMismatch)
     );
     assert_eq!(
-        gray_to_rgba(&[0; 3], &mut [0; 8]),
-        Err(SizeError::PixelCountMismatch)
+        gray_to_rgba(&[0; 3], &mut [0; 8]).map_err(|e| e.kind()),
+        Err(SizeErrorKind::PixelCountMismatch)
     );
     assert_eq!(
-        rgba_to_rgb(&[0; 8], &mut [0; 3]),
-        Err(SizeError::PixelCountMismatch)
+        rgba_to_rgb(&[0; 8], &mut [0; 3]).map_err(|e| e.kind()),
+        Err(SizeErrorKind::PixelCountMismatch)
     );
 }
 
@@ -1629,26 +1988,60 @@ fn test_size_errors() {
 fn test_strided_size_errors() {
     // stride < width * bpp
     assert_eq!(
-        rgba_to_bgra_inplace_strided(&mut [0; 32], 2, 2, 4),
-        Err(SizeError::InvalidStride)
+        rgba_to_bgra_inplace_strided(&mut [0; 32], 2, 2, 4).map_err(|e| e.kind()),
+        Err(SizeErrorKind::InvalidStride)
     );
     // buffer too small
     assert_eq!(
-        rgba_to_bgra_inplace_strided(&mut [0; 10], 2, 2, 8),
-        Err(SizeError::InvalidStride)
+        rgba_to_bgra_inplace_strided(&mut [0; 10], 2, 2, 8).map_err(|e| e.kind()),
+        Err(SizeErrorKind::InvalidStride)
     );
     // zero width
     assert_eq!(
-        rgba_to_bgra_inplace_strided(&mut [0; 8], 0, 1, 8),
-        Err(SizeError::InvalidStride)
+        rgba_to_bgra_inplace_strided(&mut [0; 8], 0, 1, 8).map_err(|e| e.kind()),
+        Err(SizeErrorKind::InvalidStride)
     );
     // zero height
     assert_eq!(
-        rgba_to_bgra_inplace_strided(&mut [0; 8], 2, 0, 8),
-        Err(SizeError::InvalidStride)
+        rgba_to_bgra_inplace_strided(&mut [0; 8], 2, 0, 8).map_err(|e| e.kind()),
+        Err(SizeErrorKind::InvalidStride)
     );
 }
 
+#[test]
+fn test_size_error_context() {
+    let e = rgb_to_bgra(&[0; 6], &mut [0; 4]).unwrap_err();
+    assert_eq!(e.operand(), Some(crate::Operand::Dst));
+    assert_eq!((e.required(), e.actual()), (Some(8), Some(4)));
+    assert_eq!(
+        e.to_string(),
+        "dst: destination has fewer pixels than source (needs 8, has 4)"
+    );
+
+    let e = rgba_to_bgra_inplace(&mut [0; 5]).unwrap_err();
+    assert_eq!(e.operand(), Some(crate::Operand::Buf));
+    assert_eq!((e.required(), e.actual()), (None, Some(5)));
+
+    let e = rgba_to_bgra_strided(&[0; 64], &mut [0; 64], 2, 2, 8, 4).unwrap_err();
+    assert_eq!(e.operand(), Some(crate::Operand::Dst));
+    assert_eq!((e.row_bytes(), e.stride()), (Some(8), Some(4)));
+    assert_eq!(e.required(), None);
+
+    let e = rgba_to_bgra_strided(&[0; 12], &mut [0; 64], 2, 2, 8, 8).unwrap_err();
+    assert_eq!(e.operand(), Some(crate::Operand::Src));
+    assert_eq!((e.required(), e.actual()), (Some(16), Some(12)));
+    assert_eq!(
+        e.to_string(),
+        "src: stride, dimensions, or buffer size are inconsistent \
+         (needs 16, has 12) (row 8 bytes, stride 8)"
+    );
+
+    // Errors built from a bare kind carry no context.
+    let e = crate::SizeError::from(SizeErrorKind::InvalidStride);
+    assert_eq!((e.operand(), e.required()), (None, None));
+    assert_eq!(e.to_string(), SizeErrorKind::InvalidStride.to_string());
+}
+
 // -----------------------------------------------------------------------
 // Alias correctness
 // -----------------------------------------------------------------------
@@ -1881,17 +2274,17 @@ mod experimental_premul_tests {
     fn premul_f32_size_errors() {
         // Not 16-byte aligned
         assert_eq!(
-            premultiply_alpha_f32(&mut [0; 15]),
-            Err(SizeError::NotPixelAligned)
+            premultiply_alpha_f32(&mut [0; 15]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         assert_eq!(
-            premultiply_alpha_f32(&mut [0; 0]),
-            Err(SizeError::NotPixelAligned)
+            premultiply_alpha_f32(&mut [0; 0]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Copy: dst too small
         assert_eq!(
-            premultiply_alpha_f32_copy(&[0; 32], &mut [0; 16]),
-            Err(SizeError::PixelCountMismatch)
+            premultiply_alpha_f32_copy(&[0; 32], &mut [0; 16]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
     }
 
@@ -2219,16 +2612,16 @@ mod premul_u8_tests {
     #[test]
     fn premul_u8_size_errors() {
         assert_eq!(
-            premultiply_alpha_rgba_u8(&mut [0; 3]),
-            Err(SizeError::NotPixelAligned)
+            premultiply_alpha_rgba_u8(&mut [0; 3]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         assert_eq!(
-            premultiply_alpha_rgba_u8(&mut []),
-            Err(SizeError::NotPixelAligned)
+            premultiply_alpha_rgba_u8(&mut []).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         assert_eq!(
-            premultiply_alpha_rgba_u8_copy(&[0; 8], &mut [0; 4]),
-            Err(SizeError::PixelCountMismatch)
+            premultiply_alpha_rgba_u8_copy(&[0; 8], &mut [0; 4]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
     }
 
@@ -2465,27 +2858,27 @@ mod packed_format_tests {
     fn packed_size_errors() {
         // Empty source
         assert_eq!(
-            rgb565_to_rgba(&[], &mut [0; 4]),
-            Err(SizeError::NotPixelAligned)
+            rgb565_to_rgba(&[], &mut [0; 4]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Odd byte count (not pixel-aligned)
         assert_eq!(
-            rgb565_to_rgba(&[0; 3], &mut [0; 8]),
-            Err(SizeError::NotPixelAligned)
+            rgb565_to_rgba(&[0; 3], &mut [0; 8]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Dst too small
         assert_eq!(
-            rgb565_to_rgba(&[0; 4], &mut [0; 4]),
-            Err(SizeError::PixelCountMismatch)
+            rgb565_to_rgba(&[0; 4], &mut [0; 4]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
         // Same for RGBA4444
         assert_eq!(
-            rgba4444_to_rgba(&[0; 1], &mut [0; 4]),
-            Err(SizeError::NotPixelAligned)
+            rgba4444_to_rgba(&[0; 1], &mut [0; 4]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         assert_eq!(
-            rgba4444_to_rgba(&[0; 4], &mut [0; 4]),
-            Err(SizeError::PixelCountMismatch)
+            rgba4444_to_rgba(&[0; 4], &mut [0; 4]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
     }
 
@@ -2885,27 +3278,27 @@ mod packed_format_tests {
     fn compress_size_errors() {
         // Empty
         assert_eq!(
-            rgba_to_rgb565(&[], &mut [0; 2]),
-            Err(SizeError::NotPixelAligned)
+            rgba_to_rgb565(&[], &mut [0; 2]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Not 4-byte aligned
         assert_eq!(
-            rgba_to_rgb565(&[0; 3], &mut [0; 2]),
-            Err(SizeError::NotPixelAligned)
+            rgba_to_rgb565(&[0; 3], &mut [0; 2]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Dst too small
         assert_eq!(
-            rgba_to_rgb565(&[0; 8], &mut [0; 2]),
-            Err(SizeError::PixelCountMismatch)
+            rgba_to_rgb565(&[0; 8], &mut [0; 2]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
         // Same for RGBA4444
         assert_eq!(
-            rgba_to_rgba4444(&[0; 5], &mut [0; 2]),
-            Err(SizeError::NotPixelAligned)
+            rgba_to_rgba4444(&[0; 5], &mut [0; 2]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         assert_eq!(
-            rgba_to_rgba4444(&[0; 8], &mut [0; 2]),
-            Err(SizeError::PixelCountMismatch)
+            rgba_to_rgba4444(&[0; 8], &mut [0; 2]).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
     }
 
@@ -2924,6 +3317,236 @@ mod packed_format_tests {
         assert_eq!(dst_from_bgra, dst_from_rgba);
     }
 
+    #[test]
+    fn big_endian_packed_matches_swapped_little_endian() {
+        fn swap_pairs(v: &[u8]) -> Vec<u8> {
+            v.chunks_exact(2).flat_map(|p| [p[1], p[0]]).collect()
+        }
+        type Expand = fn(&[u8], &mut [u8]) -> Result<(), SizeError>;
+        let expand: [(Expand, Expand); 4] = [
+            (rgb565_to_rgba, rgb565be_to_rgba),
+            (rgb565_to_bgra, rgb565be_to_bgra),
+            (rgba4444_to_rgba, rgba4444be_to_rgba),
+            (rgba4444_to_bgra, rgba4444be_to_bgra),
+        ];
+        let compress: [(Expand, Expand); 4] = [
+            (rgba_to_rgb565, rgba_to_rgb565be),
+            (bgra_to_rgb565, bgra_to_rgb565be),
+            (rgba_to_rgba4444, rgba_to_rgba4444be),
+            (bgra_to_rgba4444, bgra_to_rgba4444be),
+        ];
+        let report = for_each_token_permutation(policy(), |perm| {
+            for n in [1, 7, 16, 33] {
+                let le = make_2bpp(n);
+                let be = swap_pairs(&le);
+                for (i, (f_le, f_be)) in expand.iter().enumerate() {
+                    let (mut a, mut b) = (vec![0u8; n * 4], vec![0u8; n * 4]);
+                    f_le(&le, &mut a).unwrap();
+                    f_be(&be, &mut b).unwrap();
+                    assert_eq!(a, b, "expand #{i} n={n} tier={perm}");
+                }
+                let px = make_4bpp(n);
+                for (i, (f_le, f_be)) in compress.iter().enumerate() {
+                    let (mut a, mut b) = (vec![0u8; n * 2], vec![0u8; n * 2]);
+                    f_le(&px, &mut a).unwrap();
+                    f_be(&px, &mut b).unwrap();
+                    assert_eq!(swap_pairs(&a), b, "compress #{i} n={n} tier={perm}");
+                }
+            }
+        });
+        std::eprintln!("packed_be: {report}");
+    }
+
+    #[test]
+    fn big_endian_packed_strided() {
+        let (w, h) = (5, 3);
+        // Pure red RGB565 is 0xF800: BE bytes [0xF8, 0x00].
+        let mut src = vec![0u8; 16 * h];
+        for y in 0..h {
+            for x in 0..w {
+                src[y * 16 + x * 2..][..2].copy_from_slice(&[0xF8, 0x00]);
+            }
+        }
+        let mut dst = vec![0u8; 24 * h];
+        rgb565be_to_rgba_strided(&src, &mut dst, w, h, 16, 24).unwrap();
+        for y in 0..h {
+            for x in 0..w {
+                assert_eq!(&dst[y * 24 + x * 4..][..4], &[255, 0, 0, 255]);
+            }
+        }
+        let mut back = vec![0u8; 16 * h];
+        rgba_to_rgb565be_strided(&dst, &mut back, w, h, 24, 16).unwrap();
+        assert_eq!(back, src);
+        let mut bgra = vec![0u8; 24 * h];
+        rgba4444be_to_bgra_strided(&src, &mut bgra, w, h, 16, 24).unwrap();
+        let mut back4444 = vec![0u8; 16 * h];
+        bgra_to_rgba4444be_strided(&bgra, &mut back4444, w, h, 24, 16).unwrap();
+        assert_eq!(back4444, src);
+    }
+
+    #[test]
+    fn permutation_subbyte_gray() {
+        let report = for_each_token_permutation(policy(), |perm| {
+            for &n in TEST_PIXEL_COUNTS {
+                let gray: Vec<u8> = (0..n).map(|i| (i * 37 % 256) as u8).collect();
+                for bits in [1usize, 2, 4] {
+                    let max = (1u32 << bits) - 1;
+                    let mut packed = vec![0u8; (n * bits).div_ceil(8)];
+                    let mut back = vec![0u8; n];
+                    match bits {
+                        1 => {
+                            pack_gray8_to_gray1(&gray, &mut packed).unwrap();
+                            unpack_gray1_to_gray8(&packed, &mut back).unwrap();
+                        }
+                        2 => {
+                            pack_gray8_to_gray2(&gray, &mut packed).unwrap();
+                            unpack_gray2_to_gray8(&packed, &mut back).unwrap();
+                        }
+                        _ => {
+                            pack_gray8_to_gray4(&gray, &mut packed).unwrap();
+                            unpack_gray4_to_gray8(&packed, &mut back).unwrap();
+                        }
+                    }
+                    for (i, (&g, &b)) in gray.iter().zip(&back).enumerate() {
+                        let k = (g as u32 * max + 128) >> 8;
+                        assert_eq!(
+                            b as u32,
+                            k * (255 / max),
+                            "bits={bits} i={i} n={n} tier={perm}"
+                        );
+                    }
+                }
+            }
+        });
+        std::eprintln!("subbyte_gray: {report}");
+    }
+
+    fn ref_nearest(px: [u8; 4], palette: &[[u8; 4]]) -> u8 {
+        let mut best = (u32::MAX, 0u8);
+        for (j, p) in palette.iter().enumerate() {
+            let d: u32 = (0..4)
+                .map(|c| (px[c] as i32 - p[c] as i32).pow(2) as u32)
+                .sum();
+            if d < best.0 {
+                best = (d, j as u8);
+            }
+        }
+        best.1
+    }
+
+    #[test]
+    fn permutation_rgba_to_indexed8() {
+        let report = for_each_token_permutation(policy(), |perm| {
+            for pal_len in [1usize, 7, 8, 16, 100, 256] {
+                // Duplicate entries check the lowest-index tie break.
+                let palette: Vec<[u8; 4]> = (0..pal_len)
+                    .map(|j| {
+                        let j = (j % 90) as u8;
+                        [
+                            j.wrapping_mul(37),
+                            j.wrapping_mul(91),
+                            j.wrapping_mul(13),
+                            255 - j,
+                        ]
+                    })
+                    .collect();
+                for &n in TEST_PIXEL_COUNTS {
+                    let src = make_4bpp(n);
+                    let mut dst = vec![0u8; n];
+                    rgba_to_indexed8(&src, &palette, &mut dst).unwrap();
+                    for (i, (px, &d)) in src.chunks_exact(4).zip(&dst).enumerate() {
+                        let px = [px[0], px[1], px[2], px[3]];
+                        assert_eq!(
+                            d,
+                            ref_nearest(px, &palette),
+                            "pal={pal_len} i={i} n={n} tier={perm}"
+                        );
+                    }
+                    // BGRA source with the same palette picks the same entries.
+                    let mut bgra = src.clone();
+                    rgba_to_bgra_inplace(&mut bgra).unwrap();
+                    let mut dst2 = vec![0u8; n];
+                    bgra_to_indexed8(&bgra, &palette, &mut dst2).unwrap();
+                    assert_eq!(dst2, dst, "bgra pal={pal_len} n={n} tier={perm}");
+                }
+            }
+        });
+        std::eprintln!("rgba_to_indexed8: {report}");
+    }
+
+    #[test]
+    fn indexed8_roundtrip_and_errors() {
+        let palette: Vec<[u8; 4]> = (0..40u8).map(|j| [j * 6, 255 - j * 6, j, 200]).collect();
+        let (w, h) = (40, 3);
+        let idx: Vec<u8> = (0..w * h).map(|i| (i * 7 % 40) as u8).collect();
+        let mut rgba = vec![0u8; w * h * 4];
+        indexed8_to_rgba(&idx, &palette, &mut rgba).unwrap();
+
+        // Exact palette colors map back exactly, with or without diffusion.
+        let mut back = vec![0xEEu8; (w + 3) * h];
+        rgba_to_indexed8_strided(&rgba, &palette, &mut back, w, h, w * 4, w + 3).unwrap();
+        let mut fs = vec![0u8; w * h];
+        let mut scratch = vec![0i16; w * 4];
+        rgba_to_indexed8_floyd_steinberg_strided(
+            &rgba,
+            &palette,
+            &mut fs,
+            w,
+            h,
+            w * 4,
+            w,
+            &mut scratch,
+        )
+        .unwrap();
+        for y in 0..h {
+            assert_eq!(&back[y * (w + 3)..][..w], &idx[y * w..][..w]);
+            assert_eq!(&back[y * (w + 3) + w..][..3], &[0xEE; 3]);
+        }
+        assert_eq!(fs, idx);
+
+        // Diffusion keeps the mean of a flat area between two entries.
+        let bw = [[0u8, 0, 0, 255], [255, 255, 255, 255]];
+        let gray = [100u8, 100, 100, 255].repeat(64 * 8);
+        let mut out = vec![0u8; 64 * 8];
+        let mut scratch = vec![0i16; 64 * 4];
+        bgra_to_indexed8_floyd_steinberg_strided(
+            &gray,
+            &bw,
+            &mut out,
+            64,
+            8,
+            256,
+            64,
+            &mut scratch,
+        )
+        .unwrap();
+        let ones: usize = out.iter().map(|&i| i as usize).sum();
+        assert!((190..=212).contains(&ones), "ones={ones}");
+
+        assert_eq!(
+            rgba_to_indexed8(&rgba, &[], &mut back).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
+        );
+        assert_eq!(
+            rgba_to_indexed8(&rgba, &[[0; 4]; 257], &mut back).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
+        );
+        assert_eq!(
+            rgba_to_indexed8_floyd_steinberg_strided(
+                &rgba,
+                &palette,
+                &mut fs,
+                w,
+                h,
+                w * 4,
+                w,
+                &mut scratch[..10],
+            )
+            .map_err(|e| e.kind()),
+            Err(SizeErrorKind::InvalidStride)
+        );
+    }
+
     // --- Unaligned packed format tests ---
     // Expand: 2bpp → 4bpp
     unaligned_copy_tests! {
@@ -2937,3 +3560,426 @@ mod packed_format_tests {
         unaligned_bgra_to_rgba4444: bgra_to_rgba4444, 4, 2;
     }
 }
+
+// ===========================================================================
+// Orientation transforms
+// ===========================================================================
+
+const ORIENTATIONS: [Orientation; 8] = [
+    Orientation::Identity,
+    Orientation::FlipHorizontal,
+    Orientation::Rotate180,
+    Orientation::FlipVertical,
+    Orientation::Transpose,
+    Orientation::Rotate90,
+    Orientation::Transverse,
+    Orientation::Rotate270,
+];
+
+/// Source `(x, y)` for output `(x, y)`, straight from the EXIF definitions.
+fn ref_orient_source(o: Orientation, x: usize, y: usize, w: usize, h: usize) -> (usize, usize) {
+    match o {
+        Orientation::Identity => (x, y),
+        Orientation::FlipHorizontal => (w - 1 - x, y),
+        Orientation::Rotate180 => (w - 1 - x, h - 1 - y),
+        Orientation::FlipVertical => (x, h - 1 - y),
+        Orientation::Transpose => (y, x),
+        Orientation::Rotate90 => (y, h - 1 - x),
+        Orientation::Transverse => (w - 1 - y, h - 1 - x),
+        Orientation::Rotate270 => (w - 1 - y, x),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn ref_transform(
+    src: &[u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+    bpp: usize,
+    o: Orientation,
+    order: Option<[u8; 4]>,
+) -> Vec<u8> {
+    let (dw, dh) = o.output_size(w, h);
+    let mut out = vec![0u8; (dh - 1) * ds + dw * bpp];
+    for y in 0..dh {
+        for x in 0..dw {
+            let (sx, sy) = ref_orient_source(o, x, y, w, h);
+            let s = &src[sy * ss + sx * bpp..][..bpp];
+            let d = &mut out[y * ds + x * bpp..][..bpp];
+            match order {
+                Some(order) => d.copy_from_slice(&order.map(|i| s[i as usize])),
+                None => d.copy_from_slice(s),
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn orientation_exif_round_trip_and_inverse() {
+    for o in ORIENTATIONS {
+        assert_eq!(Orientation::from_exif(o.exif()), Some(o));
+        let src: Vec<u8> = (0..5 * 3).map(|i| i as u8).collect();
+        let (dw, dh) = o.output_size(5, 3);
+        let mut mid = vec![0u8; 15];
+        let mut back = vec![0u8; 15];
+        transform_strided(&src, &mut mid, 5, 3, 5, dw, 1, o).unwrap();
+        transform_strided(&mid, &mut back, dw, dh, dw, 5, 1, o.inverse()).unwrap();
+        assert_eq!(back, src, "{o:?}");
+    }
+    assert_eq!(Orientation::from_exif(0), None);
+    assert_eq!(Orientation::from_exif(9), None);
+    assert_eq!(Swizzle::new([0, 1, 2, 4]), None);
+}
+
+#[test]
+fn transform_every_orientation_and_bpp() {
+    // Sizes straddle the 8×8 SIMD block and the tile edge.
+    for (w, h) in [(1, 1), (3, 2), (8, 8), (13, 9), (37, 70)] {
+        for bpp in [1, 2, 3, 4, 8, 16] {
+            let ss = w * bpp + 5;
+            let src: Vec<u8> = (0..h * ss).map(|i| (i * 7 + 3) as u8).collect();
+            for o in ORIENTATIONS {
+                let (dw, _) = o.output_size(w, h);
+                let ds = dw * bpp + 3;
+                let expected = ref_transform(&src, w, h, ss, ds, bpp, o, None);
+                let mut dst = vec![0u8; expected.len()];
+                transform_strided(&src, &mut dst, w, h, ss, ds, bpp, o).unwrap();
+                for (a, b) in dst.chunks(ds).zip(expected.chunks(ds)) {
+                    assert_eq!(a[..dw * bpp], b[..dw * bpp], "{w}x{h} bpp={bpp} {o:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn permutation_transform_4bpp_swizzle() {
+    let swizzles = [
+        Swizzle::IDENTITY,
+        Swizzle::SWAP_BR,
+        Swizzle::ROTATE_LEFT,
+        Swizzle::REVERSE,
+        Swizzle::new([3, 3, 0, 1]).unwrap(),
+    ];
+    let report = for_each_token_permutation(policy(), |perm| {
+        for (w, h) in [(5, 3), (16, 8), (29, 67)] {
+            let ss = w * 4 + 12;
+            let src: Vec<u8> = (0..h * ss).map(|i| (i * 13 + 1) as u8).collect();
+            for o in ORIENTATIONS {
+                let (dw, _) = o.output_size(w, h);
+                let ds = dw * 4;
+                for sw in swizzles {
+                    let expected = ref_transform(&src, w, h, ss, ds, 4, o, Some(sw.order()));
+                    let mut dst = vec![0u8; expected.len()];
+                    transform_4bpp_strided(&src, &mut dst, w, h, ss, ds, o, sw).unwrap();
+                    assert_eq!(dst, expected, "{w}x{h} {o:?} {sw:?} tier={perm}");
+                }
+            }
+        }
+    });
+    std::eprintln!("transform_4bpp: {report}");
+}
+
+#[test]
+fn transform_rejects_bad_sizes() {
+    let src = [0u8; 24];
+    let mut dst = [0u8; 24];
+    assert_eq!(
+        transform_strided(&src, &mut dst, 2, 2, 12, 12, 5, Orientation::Identity)
+            .map_err(|e| e.kind()),
+        Err(SizeErrorKind::NotPixelAligned)
+    );
+    // Rotated output is 3 wide: a 2-pixel stride is too short.
+    assert_eq!(
+        transform_4bpp_strided(
+            &src[..24],
+            &mut dst,
+            2,
+            3,
+            8,
+            8,
+            Orientation::Rotate90,
+            Swizzle::IDENTITY
+        )
+        .map_err(|e| e.kind()),
+        Err(SizeErrorKind::InvalidStride)
+    );
+}
+
+// ===========================================================================
+// Vertical flip
+// ===========================================================================
+
+type StridedCopyFn = fn(&[u8], &mut [u8], usize, usize, usize, usize) -> Result<(), SizeError>;
+
+#[test]
+fn permutation_strided_flip_y() {
+    let cases: [(&str, StridedCopyFn, StridedCopyFn, usize, usize); 8] = [
+        (
+            "bgra_to_rgba",
+            bgra_to_rgba_strided_flip_y,
+            bgra_to_rgba_strided,
+            4,
+            4,
+        ),
+        (
+            "bgr_to_rgba",
+            bgr_to_rgba_strided_flip_y,
+            bgr_to_rgba_strided,
+            3,
+            4,
+        ),
+        (
+            "gray_to_bgra",
+            gray_to_bgra_strided_flip_y,
+            gray_to_bgra_strided,
+            1,
+            4,
+        ),
+        (
+            "rgba_to_rgb",
+            rgba_to_rgb_strided_flip_y,
+            rgba_to_rgb_strided,
+            4,
+            3,
+        ),
+        (
+            "abgr_to_rgb",
+            abgr_to_rgb_strided_flip_y,
+            abgr_to_rgb_strided,
+            4,
+            3,
+        ),
+        (
+            "rgb_to_abgr",
+            rgb_to_abgr_strided_flip_y,
+            rgb_to_abgr_strided,
+            3,
+            4,
+        ),
+        (
+            "gray_alpha_to_argb",
+            gray_alpha_to_argb_strided_flip_y,
+            gray_alpha_to_argb_strided,
+            2,
+            4,
+        ),
+        (
+            "swap_bytes_u16",
+            swap_bytes_u16_strided_flip_y,
+            swap_bytes_u16_strided,
+            2,
+            2,
+        ),
+    ];
+    let report = for_each_token_permutation(policy(), |perm| {
+        for (w, h) in [(1, 1), (7, 3), (67, 5)] {
+            for &(name, flipped, plain, sbpp, dbpp) in &cases {
+                let (ss, ds) = (w * sbpp + 3, w * dbpp + 5);
+                let src: Vec<u8> = (0..ss * h).map(|i| (i * 11 + 2) as u8).collect();
+                // Reference: reverse the source rows, then convert normally.
+                let mut rev = vec![0u8; ss * h];
+                for y in 0..h {
+                    rev[y * ss..][..ss].copy_from_slice(&src[(h - 1 - y) * ss..][..ss]);
+                }
+                let mut expected = vec![0u8; ds * h];
+                plain(&rev, &mut expected, w, h, ss, ds).unwrap();
+                let mut dst = vec![0u8; ds * h];
+                flipped(&src, &mut dst, w, h, ss, ds).unwrap();
+                assert_eq!(dst, expected, "{name} {w}x{h} tier={perm}");
+            }
+        }
+    });
+    std::eprintln!("strided_flip_y: {report}");
+    assert_eq!(
+        rgba_to_bgra_strided_flip_y(&[0; 15], &mut [0; 16], 2, 2, 8, 8).map_err(|e| e.kind()),
+        Err(SizeErrorKind::InvalidStride)
+    );
+}
+
+// ===========================================================================
+// Signed strides
+// ===========================================================================
+
+#[test]
+fn strided_signed_matches_flip_and_plain() {
+    let (w, h) = (9, 4);
+    let (ss, ds) = (w * 3 + 2, w * 4);
+    let src: Vec<u8> = (0..ss * h).map(|i| (i * 5 + 1) as u8).collect();
+    let (top, bottom) = (SignedStride::top_down(ds), SignedStride::bottom_up(ds, h));
+
+    // Bottom-up source into a top-down destination flips.
+    let mut expected = vec![0u8; ds * h];
+    rgb_to_bgra_strided_flip_y(&src, &mut expected, w, h, ss, ds).unwrap();
+    let mut dst = vec![0u8; ds * h];
+    rgb_to_bgra_strided_signed(&src, &mut dst, w, h, SignedStride::bottom_up(ss, h), top).unwrap();
+    assert_eq!(dst, expected);
+
+    // Both bottom-up: same rows, no flip.
+    rgb_to_bgra_strided(&src, &mut expected, w, h, ss, ds).unwrap();
+    rgb_to_bgra_strided_signed(&src, &mut dst, w, h, SignedStride::bottom_up(ss, h), bottom)
+        .unwrap();
+    assert_eq!(dst, expected);
+
+    // A negative stride with a base offset into a larger buffer (e.g. a
+    // mapped resource with a header), in place.
+    let mut buf = vec![0u8; 16 + ds * h];
+    buf[16..].copy_from_slice(&expected);
+    let at = SignedStride::new(16 + (h - 1) * ds, -(ds as isize));
+    bgra_to_rgba_inplace_strided_signed(&mut buf, w, h, at).unwrap();
+    bgra_to_rgba_inplace_strided(&mut expected, w, h, ds).unwrap();
+    assert_eq!(buf[16..], expected);
+    assert!(buf[..16].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn strided_signed_rejects_out_of_bounds() {
+    let mut buf = [0u8; 32];
+    // Row 0 at offset 8 with stride -8 would need rows at 0 and -8.
+    let at = SignedStride::new(8, -8);
+    assert_eq!(
+        fill_alpha_rgba_strided_signed(&mut buf, 2, 3, at).map_err(|e| e.kind()),
+        Err(SizeErrorKind::InvalidStride)
+    );
+    assert_eq!(
+        fill_alpha_rgba_strided_signed(&mut buf, 2, 2, SignedStride::new(40, 8))
+            .map_err(|e| e.kind()),
+        Err(SizeErrorKind::InvalidStride)
+    );
+    assert_eq!(
+        fill_alpha_rgba_strided_signed(&mut buf, 3, 2, SignedStride::new(8, -8))
+            .map_err(|e| e.kind()),
+        Err(SizeErrorKind::InvalidStride)
+    );
+    fill_alpha_rgba_strided_signed(&mut buf, 2, 2, at).unwrap();
+    assert_eq!(
+        buf[..16],
+        [0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255]
+    );
+}
+
+/// Reverse the rows of a top-down image with `stride`-byte rows.
+fn flip_rows(img: &[u8], stride: usize, height: usize) -> Vec<u8> {
+    let mut out = vec![0u8; stride * height];
+    for y in 0..height {
+        out[y * stride..][..stride].copy_from_slice(&img[(height - 1 - y) * stride..][..stride]);
+    }
+    out
+}
+
+#[test]
+fn strided_signed_per_row_fallback() {
+    let (w, h) = (7, 5);
+    let palette: Vec<[u8; 4]> = (0..=255u8).map(|i| [i, !i, i ^ 0x5a, 255]).collect();
+    let (ss, ds) = (w + 3, w * 3 + 1);
+    let src: Vec<u8> = (0..ss * h).map(|i| (i * 7 + 3) as u8).collect();
+    let mut plain = vec![0u8; ds * h];
+    indexed8_to_rgb_strided(&src, &palette, &mut plain, w, h, ss, ds).unwrap();
+
+    // Bottom-up destination: the same image, stored with its rows reversed.
+    let mut dst = vec![0u8; ds * h];
+    let (top, bottom) = (SignedStride::top_down(ss), SignedStride::bottom_up(ds, h));
+    indexed8_to_rgb_strided_signed(&src, &palette, &mut dst, w, h, top, bottom).unwrap();
+    assert_eq!(dst, flip_rows(&plain, ds, h));
+
+    // Both bottom-up: memory order is unchanged.
+    let src_up = SignedStride::bottom_up(ss, h);
+    indexed8_to_rgb_strided_signed(&src, &palette, &mut dst, w, h, src_up, bottom).unwrap();
+    assert_eq!(dst, plain);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn strided_signed_dither_in_image_order() {
+    let (w, h) = (6, 5);
+    let stride = w * 4;
+    let src: Vec<u8> = (0..stride * h).map(|i| (i * 13 + 5) as u8).collect();
+    let up = flip_rows(&src, stride, h);
+    let (top, bottom) = (
+        SignedStride::top_down(stride),
+        SignedStride::bottom_up(stride, h),
+    );
+    let dtop = SignedStride::top_down(w * 2);
+
+    // Ordered dither keeps the matrix anchored to image rows.
+    let dither = OrderedDither::new(DitherMatrix::Bayer4);
+    let mut expected = vec![0u8; w * 2 * h];
+    rgba_to_rgb565_dithered_strided(&src, &mut expected, w, h, stride, w * 2, dither).unwrap();
+    let mut dst = vec![0u8; w * 2 * h];
+    rgba_to_rgb565_dithered_strided_signed(&up, &mut dst, w, h, bottom, dtop, dither).unwrap();
+    assert_eq!(dst, expected);
+
+    // Error diffusion runs top to bottom in image order, not memory order.
+    let mut scratch = vec![0i16; w * 3 * 2];
+    rgba_to_rgb565_floyd_steinberg_strided(&src, &mut expected, w, h, stride, w * 2, &mut scratch)
+        .unwrap();
+    rgba_to_rgb565_floyd_steinberg_strided_signed(&up, &mut dst, w, h, bottom, dtop, &mut scratch)
+        .unwrap();
+    assert_eq!(dst, expected);
+
+    let palette = [[0, 0, 0, 255], [255, 255, 255, 255], [255, 0, 0, 255]];
+    let mut expected = vec![0u8; w * h];
+    rgba_to_indexed8_floyd_steinberg_strided(
+        &src,
+        &palette,
+        &mut expected,
+        w,
+        h,
+        stride,
+        w,
+        &mut scratch,
+    )
+    .unwrap();
+    let mut dst = vec![0u8; w * h];
+    let dup = SignedStride::bottom_up(w, h);
+    rgba_to_indexed8_floyd_steinberg_strided_signed(
+        &src,
+        &palette,
+        &mut dst,
+        w,
+        h,
+        top,
+        dup,
+        &mut scratch,
+    )
+    .unwrap();
+    assert_eq!(dst, flip_rows(&expected, w, h));
+}
+
+#[test]
+fn strided_signed_transform_folds_flip() {
+    let (w, h) = (5, 3);
+    let src: Vec<u8> = (0..w * h * 4).map(|i| (i * 3 + 1) as u8).collect();
+    for o in ORIENTATIONS {
+        let (dw, dh) = o.output_size(w, h);
+        let mut expected = vec![0u8; dw * dh * 4];
+        transform_strided(&src, &mut expected, w, h, w * 4, dw * 4, 4, o).unwrap();
+
+        // A bottom-up source holds the image flipped in memory.
+        let flipped = flip_rows(&src, w * 4, h);
+        let mut dst = vec![0u8; dw * dh * 4];
+        let (from, to) = (
+            SignedStride::bottom_up(w * 4, h),
+            SignedStride::top_down(dw * 4),
+        );
+        transform_strided_signed(&flipped, &mut dst, w, h, from, to, 4, o).unwrap();
+        assert_eq!(dst, expected, "{o:?} bottom-up src");
+
+        let to = SignedStride::bottom_up(dw * 4, dh);
+        transform_4bpp_strided_signed(
+            &src,
+            &mut dst,
+            w,
+            h,
+            SignedStride::top_down(w * 4),
+            to,
+            o,
+            Swizzle::IDENTITY,
+        )
+        .unwrap();
+        assert_eq!(dst, flip_rows(&expected, dw * 4, dh), "{o:?} bottom-up dst");
+    }
+}
@@ -959,3 +959,133 @@ pub(super) fn gray_alpha_to_4bpp_alpha_first_strided_wasm128(
         );
     }
 }
+
+// ===========================================================================
+// Byte-order swaps (u16 / u32 samples)
+// ===========================================================================
+//
+// `N` is the sample width in bytes (2 or 4); every N-byte group is reversed.
+
+#[rite]
+pub(super) fn swap_bytes_row_wasm128<const N: usize>(_token: Wasm128Token, row: &mut [u8]) {
+    let mask = if N == 2 {
+        i8x16(1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14)
+    } else {
+        i8x16(3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12)
+    };
+    let n = row.len();
+    let mut i = 0;
+    while i + 16 <= n {
+        let arr: &[u8; 16] = row[i..i + 16].try_into().unwrap();
+        let v = v128_load(arr);
+        let out: &mut [u8; 16] = (&mut row[i..i + 16]).try_into().unwrap();
+        v128_store(out, i8x16_swizzle(v, mask));
+        i += 16;
+    }
+    for v in row[i..].chunks_exact_mut(N) {
+        v.reverse();
+    }
+}
+
+#[rite]
+pub(super) fn copy_swap_bytes_row_wasm128<const N: usize>(
+    _token: Wasm128Token,
+    src: &[u8],
+    dst: &mut [u8],
+) {
+    let mask = if N == 2 {
+        i8x16(1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14)
+    } else {
+        i8x16(3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12)
+    };
+    let n = src.len().min(dst.len());
+    let mut i = 0;
+    while i + 16 <= n {
+        let s: &[u8; 16] = src[i..i + 16].try_into().unwrap();
+        let v = v128_load(s);
+        let d: &mut [u8; 16] = (&mut dst[i..i + 16]).try_into().unwrap();
+        v128_store(d, i8x16_swizzle(v, mask));
+        i += 16;
+    }
+    for (s, d) in src[i..].chunks_exact(N).zip(dst[i..].chunks_exact_mut(N)) {
+        for (j, b) in d.iter_mut().enumerate() {
+            *b = s[N - 1 - j];
+        }
+    }
+}
+
+#[arcane]
+pub(super) fn swap_bytes16_impl_wasm128(t: Wasm128Token, b: &mut [u8]) {
+    swap_bytes_row_wasm128::<2>(t, b);
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes16_impl_wasm128(t: Wasm128Token, s: &[u8], d: &mut [u8]) {
+    copy_swap_bytes_row_wasm128::<2>(t, s, d);
+}
+
+#[arcane]
+pub(super) fn swap_bytes16_strided_wasm128(
+    t: Wasm128Token,
+    buf: &mut [u8],
+    w: usize,
+    h: usize,
+    stride: usize,
+) {
+    for y in 0..h {
+        swap_bytes_row_wasm128::<2>(t, &mut buf[y * stride..][..w * 2]);
+    }
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes16_strided_wasm128(
+    t: Wasm128Token,
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+) {
+    for y in 0..h {
+        copy_swap_bytes_row_wasm128::<2>(t, &src[y * ss..][..w * 2], &mut dst[y * ds..][..w * 2]);
+    }
+}
+
+#[arcane]
+pub(super) fn swap_bytes32_impl_wasm128(t: Wasm128Token, b: &mut [u8]) {
+    swap_bytes_row_wasm128::<4>(t, b);
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes32_impl_wasm128(t: Wasm128Token, s: &[u8], d: &mut [u8]) {
+    copy_swap_bytes_row_wasm128::<4>(t, s, d);
+}
+
+#[arcane]
+pub(super) fn swap_bytes32_strided_wasm128(
+    t: Wasm128Token,
+    buf: &mut [u8],
+    w: usize,
+    h: usize,
+    stride: usize,
+) {
+    for y in 0..h {
+        swap_bytes_row_wasm128::<4>(t, &mut buf[y * stride..][..w * 4]);
+    }
+}
+
+#[arcane]
+pub(super) fn copy_swap_bytes32_strided_wasm128(
+    t: Wasm128Token,
+    src: &[u8],
+    dst: &mut [u8],
+    w: usize,
+    h: usize,
+    ss: usize,
+    ds: usize,
+) {
+    for y in 0..h {
+        copy_swap_bytes_row_wasm128::<4>(t, &src[y * ss..][..w * 4], &mut dst[y * ds..][..w * 4]);
+    }
+}
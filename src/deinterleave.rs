@@ -18,7 +18,7 @@
 //! - **Scalar** — explicit `array::from_fn` writes, exposed for benchmarking
 //!   the unaccelerated path.
 
-use crate::SizeError;
+use crate::{Operand, SizeError, SizeErrorKind};
 #[cfg(target_arch = "x86_64")]
 use archmage::X64V3Token;
 use archmage::prelude::*;
@@ -34,8 +34,8 @@ use archmage::prelude::*;
 /// `0..=255` are converted directly to `f32` — no normalization.
 ///
 /// # Errors
-/// - [`SizeError::NotPixelAligned`] if `src.len() % 3 != 0` or `src` is empty.
-/// - [`SizeError::PixelCountMismatch`] if any plane is shorter than the pixel
+/// - [`SizeErrorKind::NotPixelAligned`] if `src.len() % 3 != 0` or `src` is empty.
+/// - [`SizeErrorKind::PixelCountMismatch`] if any plane is shorter than the pixel
 ///   count.
 pub fn rgb24_to_planes_f32(
     src: &[u8],
@@ -43,13 +43,7 @@ pub fn rgb24_to_planes_f32(
     g: &mut [f32],
     b: &mut [f32],
 ) -> Result<(), SizeError> {
-    if src.is_empty() || !src.len().is_multiple_of(3) {
-        return Err(SizeError::NotPixelAligned);
-    }
-    let pixels = src.len() / 3;
-    if r.len() < pixels || g.len() < pixels || b.len() < pixels {
-        return Err(SizeError::PixelCountMismatch);
-    }
+    let pixels = check_deinterleave(src.len(), 3, &[r.len(), g.len(), b.len()])?;
     let r = &mut r[..pixels];
     let g = &mut g[..pixels];
     let b = &mut b[..pixels];
@@ -64,8 +58,8 @@ pub fn rgb24_to_planes_f32(
 /// `0..=65535` are converted directly to `f32` — no normalization.
 ///
 /// # Errors
-/// - [`SizeError::NotPixelAligned`] if `src.len() % 3 != 0` or `src` is empty.
-/// - [`SizeError::PixelCountMismatch`] if any plane is shorter than the pixel
+/// - [`SizeErrorKind::NotPixelAligned`] if `src.len() % 3 != 0` or `src` is empty.
+/// - [`SizeErrorKind::PixelCountMismatch`] if any plane is shorter than the pixel
 ///   count.
 pub fn rgb48_to_planes_f32(
     src: &[u16],
@@ -73,13 +67,7 @@ pub fn rgb48_to_planes_f32(
     g: &mut [f32],
     b: &mut [f32],
 ) -> Result<(), SizeError> {
-    if src.is_empty() || !src.len().is_multiple_of(3) {
-        return Err(SizeError::NotPixelAligned);
-    }
-    let pixels = src.len() / 3;
-    if r.len() < pixels || g.len() < pixels || b.len() < pixels {
-        return Err(SizeError::PixelCountMismatch);
-    }
+    let pixels = check_deinterleave(src.len(), 3, &[r.len(), g.len(), b.len()])?;
     let r = &mut r[..pixels];
     let g = &mut g[..pixels];
     let b = &mut b[..pixels];
@@ -87,6 +75,48 @@ pub fn rgb48_to_planes_f32(
     Ok(())
 }
 
+/// Interleaved `src` of `channels` per pixel → planes of at least its pixel
+/// count each. Returns the pixel count.
+#[inline]
+fn check_deinterleave(src: usize, channels: usize, planes: &[usize]) -> Result<usize, SizeError> {
+    if src == 0 || !src.is_multiple_of(channels) {
+        return Err(SizeError::new(SizeErrorKind::NotPixelAligned)
+            .of(Operand::Src)
+            .lengths(None, src));
+    }
+    let pixels = src / channels;
+    match planes.iter().find(|&&len| len < pixels) {
+        Some(&short) => Err(SizeError::new(SizeErrorKind::PixelCountMismatch)
+            .of(Operand::Dst)
+            .lengths(Some(pixels), short)),
+        None => Ok(pixels),
+    }
+}
+
+/// Equal-length planes → interleaved `dst` of one channel per plane. Returns
+/// the pixel count.
+#[inline]
+fn check_interleave(planes: &[usize], dst: usize) -> Result<usize, SizeError> {
+    let pixels = planes[0];
+    if pixels == 0 {
+        return Err(SizeError::new(SizeErrorKind::NotPixelAligned)
+            .of(Operand::Src)
+            .lengths(None, 0));
+    }
+    if let Some(&other) = planes.iter().find(|&&len| len != pixels) {
+        return Err(SizeError::new(SizeErrorKind::NotPixelAligned)
+            .of(Operand::Src)
+            .lengths(Some(pixels), other));
+    }
+    let need = pixels * planes.len();
+    if dst < need {
+        return Err(SizeError::new(SizeErrorKind::PixelCountMismatch)
+            .of(Operand::Dst)
+            .lengths(Some(need), dst));
+    }
+    Ok(pixels)
+}
+
 /// Scalar-only RGB24 → planar f32 (no validation, no SIMD dispatch). Exists
 /// solely to give benchmarks a stable handle on the unaccelerated path.
 ///
@@ -716,8 +746,8 @@ fn planes_to_rgba_f32_loop_scalar(r: &[f32], g: &[f32], b: &[f32], a: &[f32], ds
 /// `src.len() / 3` floats. Pure identity per channel — no transfer math.
 ///
 /// # Errors
-/// - [`SizeError::NotPixelAligned`] if `src.len() % 3 != 0` or `src` is empty.
-/// - [`SizeError::PixelCountMismatch`] if any plane is shorter than the pixel
+/// - [`SizeErrorKind::NotPixelAligned`] if `src.len() % 3 != 0` or `src` is empty.
+/// - [`SizeErrorKind::PixelCountMismatch`] if any plane is shorter than the pixel
 ///   count.
 #[autoversion(v3, neon, wasm128)]
 pub fn rgb_f32_to_planes_f32(
@@ -726,13 +756,7 @@ pub fn rgb_f32_to_planes_f32(
     g: &mut [f32],
     b: &mut [f32],
 ) -> Result<(), SizeError> {
-    if src.is_empty() || !src.len().is_multiple_of(3) {
-        return Err(SizeError::NotPixelAligned);
-    }
-    let pixels = src.len() / 3;
-    if r.len() < pixels || g.len() < pixels || b.len() < pixels {
-        return Err(SizeError::PixelCountMismatch);
-    }
+    let pixels = check_deinterleave(src.len(), 3, &[r.len(), g.len(), b.len()])?;
     rgb_f32_to_planes_loop_scalar(src, &mut r[..pixels], &mut g[..pixels], &mut b[..pixels]);
     Ok(())
 }
@@ -743,8 +767,8 @@ pub fn rgb_f32_to_planes_f32(
 /// `src.len() / 4` floats. Pure identity per channel.
 ///
 /// # Errors
-/// - [`SizeError::NotPixelAligned`] if `src.len() % 4 != 0` or `src` is empty.
-/// - [`SizeError::PixelCountMismatch`] if any plane is shorter than the pixel
+/// - [`SizeErrorKind::NotPixelAligned`] if `src.len() % 4 != 0` or `src` is empty.
+/// - [`SizeErrorKind::PixelCountMismatch`] if any plane is shorter than the pixel
 ///   count.
 #[autoversion(v3, neon, wasm128)]
 pub fn rgba_f32_to_planes_f32(
@@ -754,13 +778,7 @@ pub fn rgba_f32_to_planes_f32(
     b: &mut [f32],
     a: &mut [f32],
 ) -> Result<(), SizeError> {
-    if src.is_empty() || !src.len().is_multiple_of(4) {
-        return Err(SizeError::NotPixelAligned);
-    }
-    let pixels = src.len() / 4;
-    if r.len() < pixels || g.len() < pixels || b.len() < pixels || a.len() < pixels {
-        return Err(SizeError::PixelCountMismatch);
-    }
+    let pixels = check_deinterleave(src.len(), 4, &[r.len(), g.len(), b.len(), a.len()])?;
     rgba_f32_to_planes_loop_scalar(
         src,
         &mut r[..pixels],
@@ -777,9 +795,9 @@ pub fn rgba_f32_to_planes_f32(
 /// `3 × r.len()`. Pure identity per channel.
 ///
 /// # Errors
-/// - [`SizeError::NotPixelAligned`] if any plane is empty or planes have
+/// - [`SizeErrorKind::NotPixelAligned`] if any plane is empty or planes have
 ///   different lengths.
-/// - [`SizeError::PixelCountMismatch`] if `dst` is smaller than `3 × r.len()`.
+/// - [`SizeErrorKind::PixelCountMismatch`] if `dst` is smaller than `3 × r.len()`.
 #[autoversion(v3, neon, wasm128)]
 pub fn planes_f32_to_rgb_f32(
     r: &[f32],
@@ -787,13 +805,7 @@ pub fn planes_f32_to_rgb_f32(
     b: &[f32],
     dst: &mut [f32],
 ) -> Result<(), SizeError> {
-    if r.is_empty() || r.len() != g.len() || r.len() != b.len() {
-        return Err(SizeError::NotPixelAligned);
-    }
-    let pixels = r.len();
-    if dst.len() < pixels * 3 {
-        return Err(SizeError::PixelCountMismatch);
-    }
+    let pixels = check_interleave(&[r.len(), g.len(), b.len()], dst.len())?;
     planes_to_rgb_f32_loop_scalar(r, g, b, &mut dst[..pixels * 3]);
     Ok(())
 }
@@ -804,9 +816,9 @@ pub fn planes_f32_to_rgb_f32(
 /// `4 × r.len()`. Pure identity per channel.
 ///
 /// # Errors
-/// - [`SizeError::NotPixelAligned`] if any plane is empty or planes differ
+/// - [`SizeErrorKind::NotPixelAligned`] if any plane is empty or planes differ
 ///   in length.
-/// - [`SizeError::PixelCountMismatch`] if `dst` is smaller than `4 × r.len()`.
+/// - [`SizeErrorKind::PixelCountMismatch`] if `dst` is smaller than `4 × r.len()`.
 #[autoversion(v3, neon, wasm128)]
 pub fn planes_f32_to_rgba_f32(
     r: &[f32],
@@ -815,13 +827,7 @@ pub fn planes_f32_to_rgba_f32(
     a: &[f32],
     dst: &mut [f32],
 ) -> Result<(), SizeError> {
-    if r.is_empty() || r.len() != g.len() || r.len() != b.len() || r.len() != a.len() {
-        return Err(SizeError::NotPixelAligned);
-    }
-    let pixels = r.len();
-    if dst.len() < pixels * 4 {
-        return Err(SizeError::PixelCountMismatch);
-    }
+    let pixels = check_interleave(&[r.len(), g.len(), b.len(), a.len()], dst.len())?;
     planes_to_rgba_f32_loop_scalar(r, g, b, a, &mut dst[..pixels * 4]);
     Ok(())
 }
@@ -1281,22 +1287,29 @@ mod tests {
         let mut b = vec![0.0; 4];
         // Empty src
         assert_eq!(
-            rgb24_to_planes_f32(&[], &mut r, &mut g, &mut b),
-            Err(SizeError::NotPixelAligned)
+            rgb24_to_planes_f32(&[], &mut r, &mut g, &mut b).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Not multiple of 3
         let bad = [0u8; 7];
         assert_eq!(
-            rgb24_to_planes_f32(&bad, &mut r, &mut g, &mut b),
-            Err(SizeError::NotPixelAligned)
+            rgb24_to_planes_f32(&bad, &mut r, &mut g, &mut b).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // Plane too short
         let src = [0u8; 24];
         let mut tiny = vec![0.0; 2];
         assert_eq!(
-            rgb24_to_planes_f32(&src, &mut tiny, &mut g, &mut b),
-            Err(SizeError::PixelCountMismatch)
+            rgb24_to_planes_f32(&src, &mut tiny, &mut g, &mut b).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
+        let (mut r8, mut g8) = (vec![0.0; 8], vec![0.0; 8]);
+        let e = rgb24_to_planes_f32(&src, &mut r8, &mut g8, &mut tiny).unwrap_err();
+        assert_eq!(e.operand(), Some(Operand::Dst));
+        assert_eq!((e.required(), e.actual()), (Some(8), Some(2)));
+        let e = rgb24_to_planes_f32(&bad, &mut r, &mut g, &mut b).unwrap_err();
+        assert_eq!(e.operand(), Some(Operand::Src));
+        assert_eq!((e.required(), e.actual()), (None, Some(7)));
     }
 
     #[cfg(target_arch = "x86_64")]
@@ -1408,39 +1421,39 @@ mod tests {
         let mut dst = vec![0.0; 16];
 
         assert_eq!(
-            rgb_f32_to_planes_f32(&[], &mut r, &mut g, &mut b),
-            Err(SizeError::NotPixelAligned)
+            rgb_f32_to_planes_f32(&[], &mut r, &mut g, &mut b).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         let bad = [0.0f32; 7];
         assert_eq!(
-            rgb_f32_to_planes_f32(&bad, &mut r, &mut g, &mut b),
-            Err(SizeError::NotPixelAligned)
+            rgb_f32_to_planes_f32(&bad, &mut r, &mut g, &mut b).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         let src = [0.0f32; 12]; // 4 pixels of RGB
         let mut tiny = vec![0.0f32; 2];
         assert_eq!(
-            rgb_f32_to_planes_f32(&src, &mut tiny, &mut g, &mut b),
-            Err(SizeError::PixelCountMismatch)
+            rgb_f32_to_planes_f32(&src, &mut tiny, &mut g, &mut b).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
 
         // Plane length mismatch on interleave side
         let r_short = vec![0.0; 3];
         assert_eq!(
-            planes_f32_to_rgb_f32(&r_short, &g, &b, &mut dst),
-            Err(SizeError::NotPixelAligned)
+            planes_f32_to_rgb_f32(&r_short, &g, &b, &mut dst).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         // dst too small
         let mut tiny_dst = vec![0.0; 5];
         assert_eq!(
-            planes_f32_to_rgb_f32(&r, &g, &b, &mut tiny_dst),
-            Err(SizeError::PixelCountMismatch)
+            planes_f32_to_rgb_f32(&r, &g, &b, &mut tiny_dst).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
         );
 
         // RGBA mismatch
         let a_short = vec![0.0; 3];
         assert_eq!(
-            planes_f32_to_rgba_f32(&r, &g, &b, &a_short, &mut dst),
-            Err(SizeError::NotPixelAligned)
+            planes_f32_to_rgba_f32(&r, &g, &b, &a_short, &mut dst).map_err(|e| e.kind()),
+            Err(SizeErrorKind::NotPixelAligned)
         );
         let _ = &a;
     }
@@ -0,0 +1,334 @@
+//! FFmpeg `AVPixelFormat` names.
+//!
+//! [`AvPixelFormat`] mirrors the FFmpeg pixel formats frames commonly arrive
+//! in, keyed by the names `av_get_pix_fmt_name` returns (`"bgr0"`,
+//! `"rgba64le"`, `"nv12"`). Nothing links to FFmpeg: bindings hand over a
+//! name, [`AvPixelFormat::from_name`] parses it, and [`convert_pix_fmt`]
+//! converts between two formats with the same routing as
+//! [`fourcc::convert_layout`](crate::fourcc::convert_layout). The numeric
+//! enum values are not mapped — they are not stable across FFmpeg major
+//! versions.
+//!
+//! FFmpeg names 8-bit packed formats in byte order (`bgra` is `B, G, R, A`
+//! in memory), unlike DRM. YUV, planar and palette formats are recognized but
+//! not [supported](AvPixelFormat::is_supported).
+//!
+//! ```rust
+//! use garb::ffmpeg::{convert_pix_fmt, AvPixelFormat};
+//!
+//! let src_fmt = AvPixelFormat::from_name("AV_PIX_FMT_BGR0").unwrap();
+//! let bgr0 = [30u8, 20, 10, 0];
+//! let mut rgba = [0u8; 4];
+//! convert_pix_fmt(&bgr0, src_fmt, &mut rgba, AvPixelFormat::Rgba, 1, 1, 4, 4)?;
+//! assert_eq!(rgba, [10, 20, 30, 255]);
+//! # Ok::<(), garb::fourcc::FourccError>(())
+//! ```
+
+use crate::bytes::PixelLayout;
+use crate::fourcc::{FourccError, FourccLayout, convert_layout};
+
+macro_rules! pix_fmts {
+    ($($(#[$doc:meta])* $name:ident = $av:literal $(| $alias:literal)*,)*) => {
+        /// An FFmpeg pixel format, by `av_get_pix_fmt_name` name.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum AvPixelFormat {
+            $($(#[$doc])* $name,)*
+        }
+
+        impl AvPixelFormat {
+            /// Every format garb recognizes.
+            pub const ALL: &[Self] = &[$(Self::$name,)*];
+
+            /// FFmpeg's name, as `av_get_pix_fmt_name` returns it.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$name => $av,)*
+                }
+            }
+
+            /// Parse an FFmpeg name (`"bgr0"`) or constant (`"AV_PIX_FMT_BGR0"`),
+            /// ignoring ASCII case. Unprefixed native-endian aliases such as
+            /// `"rgba64"` resolve to little-endian.
+            pub fn from_name(name: &str) -> Option<Self> {
+                let name = match name.get(..11) {
+                    Some(p) if p.eq_ignore_ascii_case("AV_PIX_FMT_") => &name[11..],
+                    _ => name,
+                };
+                $(
+                    if name.eq_ignore_ascii_case($av) $(|| name.eq_ignore_ascii_case($alias))* {
+                        return Some(Self::$name);
+                    }
+                )*
+                None
+            }
+        }
+    };
+}
+
+pix_fmts! {
+    /// `R, G, B`.
+    Rgb24 = "rgb24",
+    /// `B, G, R`.
+    Bgr24 = "bgr24",
+    /// `R, G, B, A`.
+    Rgba = "rgba",
+    /// `B, G, R, A`.
+    Bgra = "bgra",
+    /// `A, R, G, B`.
+    Argb = "argb",
+    /// `A, B, G, R`.
+    Abgr = "abgr",
+    /// `R, G, B, X`.
+    Rgb0 = "rgb0",
+    /// `B, G, R, X`.
+    Bgr0 = "bgr0",
+    /// `X, R, G, B` (`AV_PIX_FMT_0RGB`).
+    Xrgb = "0rgb",
+    /// `X, B, G, R` (`AV_PIX_FMT_0BGR`).
+    Xbgr = "0bgr",
+    /// One gray byte (`AV_PIX_FMT_GRAY8`).
+    Gray8 = "gray" | "gray8",
+    /// Gray, alpha bytes.
+    Ya8 = "ya8" | "gray8a",
+    /// Little-endian `u16`, `R[15:11] G[10:5] B[4:0]`.
+    Rgb565le = "rgb565le" | "rgb565",
+    /// Big-endian `u16`, `R[15:11] G[10:5] B[4:0]`.
+    Rgb565be = "rgb565be",
+    /// Little-endian `u32`, `X[31:30] B[29:20] G[19:10] R[9:0]`.
+    X2bgr10le = "x2bgr10le" | "x2bgr10",
+    /// Little-endian `u32`, `X[31:30] R[29:20] G[19:10] B[9:0]`.
+    X2rgb10le = "x2rgb10le" | "x2rgb10",
+    /// Little-endian `u16` `R, G, B, A`.
+    Rgba64le = "rgba64le" | "rgba64",
+    /// Big-endian `u16` `R, G, B, A`.
+    Rgba64be = "rgba64be",
+    /// Little-endian `u16` `B, G, R, A`.
+    Bgra64le = "bgra64le" | "bgra64",
+    /// Big-endian `u16` `B, G, R, A`.
+    Bgra64be = "bgra64be",
+    /// Little-endian `u16` `R, G, B`.
+    Rgb48le = "rgb48le" | "rgb48",
+    /// Little-endian `u16` gray.
+    Gray16le = "gray16le" | "gray16",
+    /// 8-bit palette indices.
+    Pal8 = "pal8",
+    /// Planar `G, B, R`.
+    Gbrp = "gbrp",
+    /// Planar 4:2:0 YUV.
+    Yuv420p = "yuv420p",
+    /// Planar 4:2:0 full-range YUV.
+    Yuvj420p = "yuvj420p",
+    /// Planar 4:2:2 YUV.
+    Yuv422p = "yuv422p",
+    /// Planar 4:4:4 YUV.
+    Yuv444p = "yuv444p",
+    /// Planar 4:2:0 YUV, 10 bits in little-endian `u16`.
+    Yuv420p10le = "yuv420p10le" | "yuv420p10",
+    /// Y plane plus interleaved UV plane, 4:2:0.
+    Nv12 = "nv12",
+    /// Y plane plus interleaved VU plane, 4:2:0.
+    Nv21 = "nv21",
+    /// 10-bit [`Nv12`](Self::Nv12) in the high bits of little-endian `u16`.
+    P010le = "p010le" | "p010",
+    /// Packed 4:2:2 `Y0, U, Y1, V`.
+    Yuyv422 = "yuyv422",
+    /// Packed 4:2:2 `U, Y0, V, Y1`.
+    Uyvy422 = "uyvy422",
+}
+
+impl AvPixelFormat {
+    /// Memory layout, or `None` for formats garb has no kernels for (YUV,
+    /// planar, palette, `x2rgb10le`, 48-bit RGB, 16-bit gray).
+    pub const fn layout(self) -> Option<FourccLayout> {
+        const fn bytes(layout: PixelLayout, padded: bool) -> Option<FourccLayout> {
+            Some(FourccLayout::Bytes { layout, padded })
+        }
+        const fn rgba16(swap_br: bool, big_endian: bool) -> Option<FourccLayout> {
+            Some(FourccLayout::Rgba16 {
+                swap_br,
+                big_endian,
+            })
+        }
+        match self {
+            Self::Rgb24 => bytes(PixelLayout::Rgb, false),
+            Self::Bgr24 => bytes(PixelLayout::Bgr, false),
+            Self::Rgba => bytes(PixelLayout::Rgba, false),
+            Self::Bgra => bytes(PixelLayout::Bgra, false),
+            Self::Argb => bytes(PixelLayout::Argb, false),
+            Self::Abgr => bytes(PixelLayout::Abgr, false),
+            Self::Rgb0 => bytes(PixelLayout::Rgba, true),
+            Self::Bgr0 => bytes(PixelLayout::Bgra, true),
+            Self::Xrgb => bytes(PixelLayout::Argb, true),
+            Self::Xbgr => bytes(PixelLayout::Abgr, true),
+            Self::Gray8 => bytes(PixelLayout::Gray, false),
+            Self::Ya8 => bytes(PixelLayout::GrayAlpha, false),
+            Self::Rgb565le => Some(FourccLayout::Rgb565 { big_endian: false }),
+            Self::Rgb565be => Some(FourccLayout::Rgb565 { big_endian: true }),
+            Self::X2bgr10le => Some(FourccLayout::Rgba1010102 { padded: true }),
+            Self::Rgba64le => rgba16(false, false),
+            Self::Rgba64be => rgba16(false, true),
+            Self::Bgra64le => rgba16(true, false),
+            Self::Bgra64be => rgba16(true, true),
+            _ => None,
+        }
+    }
+
+    /// Whether [`convert_pix_fmt`] can read or write this format in this
+    /// build. Byte layouts always can; RGB565, 10-bit and 16-bit layouts need
+    /// the `experimental` feature. Gray formats are only a source, except
+    /// gray → same gray.
+    pub const fn is_supported(self) -> bool {
+        match self.layout() {
+            Some(FourccLayout::Bytes { .. }) => true,
+            Some(_) => cfg!(feature = "experimental"),
+            None => false,
+        }
+    }
+}
+
+/// Convert a strided image between two FFmpeg formats (strides in bytes,
+/// FFmpeg's `linesize`; must be positive).
+///
+/// Returns `Unsupported` for formats without a [`layout`](AvPixelFormat::layout)
+/// and for pairs garb can't convert.
+#[allow(clippy::too_many_arguments)]
+pub fn convert_pix_fmt(
+    src: &[u8],
+    src_fmt: AvPixelFormat,
+    dst: &mut [u8],
+    dst_fmt: AvPixelFormat,
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), FourccError> {
+    let (Some(s), Some(d)) = (src_fmt.layout(), dst_fmt.layout()) else {
+        return Err(FourccError::Unsupported);
+    };
+    convert_layout(src, s, dst, d, width, height, src_stride, dst_stride)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_roundtrip() {
+        for &f in AvPixelFormat::ALL {
+            assert_eq!(AvPixelFormat::from_name(f.name()), Some(f));
+        }
+        assert_eq!(
+            AvPixelFormat::from_name("AV_PIX_FMT_RGBA64LE"),
+            Some(AvPixelFormat::Rgba64le)
+        );
+        assert_eq!(
+            AvPixelFormat::from_name("AV_PIX_FMT_GRAY8"),
+            Some(AvPixelFormat::Gray8)
+        );
+        assert_eq!(
+            AvPixelFormat::from_name("yuv420p10le"),
+            Some(AvPixelFormat::Yuv420p10le)
+        );
+        assert_eq!(AvPixelFormat::from_name("AV_PIX_FMT_"), None);
+        assert_eq!(AvPixelFormat::from_name("vaapi"), None);
+        assert!(AvPixelFormat::Bgr0.is_supported());
+        assert!(!AvPixelFormat::Nv12.is_supported());
+    }
+
+    #[test]
+    fn test_convert() {
+        // 0rgb (X, R, G, B) → bgr24.
+        let mut bgr = [0u8; 6];
+        convert_pix_fmt(
+            &[0, 1, 2, 3, 0, 4, 5, 6],
+            AvPixelFormat::Xrgb,
+            &mut bgr,
+            AvPixelFormat::Bgr24,
+            2,
+            1,
+            8,
+            6,
+        )
+        .unwrap();
+        assert_eq!(bgr, [3, 2, 1, 6, 5, 4]);
+
+        let mut out = [0u8; 8];
+        assert_eq!(
+            convert_pix_fmt(
+                &[0; 8],
+                AvPixelFormat::Nv12,
+                &mut out,
+                AvPixelFormat::Rgba,
+                2,
+                2,
+                2,
+                8
+            ),
+            Err(FourccError::Unsupported)
+        );
+
+        // rgba64be → bgra64le is a lossless swizzle + byte swap.
+        let be = [0x12, 0x34, 0, 1, 0xAB, 0xCD, 0xFF, 0xFF];
+        convert_pix_fmt(
+            &be,
+            AvPixelFormat::Rgba64be,
+            &mut out,
+            AvPixelFormat::Bgra64le,
+            1,
+            1,
+            8,
+            8,
+        )
+        .unwrap();
+        assert_eq!(out, [0xCD, 0xAB, 1, 0, 0x34, 0x12, 0xFF, 0xFF]);
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn test_convert_16bit_to_8bit() {
+        let le = [0xFF, 0xFF, 0x80, 0x80, 0, 0, 0xFF, 0xFF];
+        let mut out = [0u8; 4];
+        convert_pix_fmt(
+            &le,
+            AvPixelFormat::Bgra64le,
+            &mut out,
+            AvPixelFormat::Rgba,
+            1,
+            1,
+            8,
+            4,
+        )
+        .unwrap();
+        assert_eq!(out, [0, 0x80, 0xFF, 255]);
+
+        let mut back = [0u8; 8];
+        convert_pix_fmt(
+            &out,
+            AvPixelFormat::Rgba,
+            &mut back,
+            AvPixelFormat::Rgba64be,
+            1,
+            1,
+            4,
+            8,
+        )
+        .unwrap();
+        assert_eq!(back, [0, 0, 0x80, 0x80, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+        assert!(AvPixelFormat::Rgb565be.is_supported());
+        let mut be = [0u8; 2];
+        convert_pix_fmt(
+            &[0xFF, 0, 0xFF, 255],
+            AvPixelFormat::Rgba,
+            &mut be,
+            AvPixelFormat::Rgb565be,
+            1,
+            1,
+            4,
+            2,
+        )
+        .unwrap();
+        assert_eq!(be, [0xF8, 0x1F]);
+    }
+}
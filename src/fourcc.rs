@@ -0,0 +1,881 @@
+//! DRM fourcc and V4L2 pixel-format codes.
+//!
+//! DRM names packed formats by channel order *within a little-endian word*,
+//! most significant first: `DRM_FORMAT_XRGB8888` (`XR24`) is
+//! `[31:0] x:R:G:B`, so its bytes in memory are `B, G, R, X` — garb's
+//! [`Bgra`](PixelLayout::Bgra). V4L2 reuses the DRM codes for its 32-bit
+//! RGB formats (same bytes), but its 24-bit and gray formats have their own
+//! codes with byte-order names: `V4L2_PIX_FMT_RGB24` (`RGB3`) is `R, G, B`
+//! while `DRM_FORMAT_RGB888` (`RG24`) is `B, G, R`.
+//!
+//! [`fourcc_layout`] maps a code to its memory layout; [`convert_fourcc`]
+//! converts between two codes with the [`bytes`](crate::bytes) kernels.
+//! Padded (`X…`) sources get opaque alpha when the destination has alpha.
+//! RGB565 and 2:10:10:10 codes map always but convert only with the
+//! `experimental` feature, through an 8-bit RGBA stage.
+//! [`layout_capability`] reports the route and its relative cost.
+//!
+//! ```rust
+//! use garb::fourcc::{convert_fourcc, DRM_FORMAT_ABGR8888, DRM_FORMAT_XRGB8888};
+//!
+//! let xrgb = [30u8, 20, 10, 0, 60, 50, 40, 0]; // B, G, R, X
+//! let mut rgba = [0u8; 8];
+//! convert_fourcc(&xrgb, DRM_FORMAT_XRGB8888, &mut rgba, DRM_FORMAT_ABGR8888, 2, 1, 8, 8)?;
+//! assert_eq!(rgba, [10, 20, 30, 255, 40, 50, 60, 255]);
+//! # Ok::<(), garb::fourcc::FourccError>(())
+//! ```
+
+use crate::bytes::{
+    Capability, KernelKind, Orientation, PixelLayout, RowConverter, SimdTier, Swizzle,
+    check_strided, row_capability, transform_4bpp_strided,
+};
+use crate::{Operand, SizeError};
+
+/// Build a fourcc code from its four characters (`fourcc(b"XR24")`), as
+/// DRM's `fourcc_code` and V4L2's `v4l2_fourcc` do.
+pub const fn fourcc(code: &[u8; 4]) -> u32 {
+    u32::from_le_bytes(*code)
+}
+
+/// `XR24`: bytes `B, G, R, X`.
+pub const DRM_FORMAT_XRGB8888: u32 = fourcc(b"XR24");
+/// `AR24`: bytes `B, G, R, A`.
+pub const DRM_FORMAT_ARGB8888: u32 = fourcc(b"AR24");
+/// `XB24`: bytes `R, G, B, X`.
+pub const DRM_FORMAT_XBGR8888: u32 = fourcc(b"XB24");
+/// `AB24`: bytes `R, G, B, A`.
+pub const DRM_FORMAT_ABGR8888: u32 = fourcc(b"AB24");
+/// `RX24`: bytes `X, B, G, R`.
+pub const DRM_FORMAT_RGBX8888: u32 = fourcc(b"RX24");
+/// `RA24`: bytes `A, B, G, R`.
+pub const DRM_FORMAT_RGBA8888: u32 = fourcc(b"RA24");
+/// `BX24`: bytes `X, R, G, B`.
+pub const DRM_FORMAT_BGRX8888: u32 = fourcc(b"BX24");
+/// `BA24`: bytes `A, R, G, B`.
+pub const DRM_FORMAT_BGRA8888: u32 = fourcc(b"BA24");
+/// `RG24`: bytes `B, G, R`.
+pub const DRM_FORMAT_RGB888: u32 = fourcc(b"RG24");
+/// `BG24`: bytes `R, G, B`.
+pub const DRM_FORMAT_BGR888: u32 = fourcc(b"BG24");
+/// `R8  `: one gray byte.
+pub const DRM_FORMAT_R8: u32 = fourcc(b"R8  ");
+/// `RG16`: little-endian `u16`, `R[15:11] G[10:5] B[4:0]`.
+pub const DRM_FORMAT_RGB565: u32 = fourcc(b"RG16");
+/// `AB30`: little-endian `u32`, `A[31:30] B[29:20] G[19:10] R[9:0]`.
+pub const DRM_FORMAT_ABGR2101010: u32 = fourcc(b"AB30");
+/// `XB30`: as [`DRM_FORMAT_ABGR2101010`] with the top two bits unused.
+pub const DRM_FORMAT_XBGR2101010: u32 = fourcc(b"XB30");
+/// `AB48`: little-endian `u16` channels `R, G, B, A`.
+pub const DRM_FORMAT_ABGR16161616: u32 = fourcc(b"AB48");
+/// `AR48`: little-endian `u16` channels `B, G, R, A`.
+pub const DRM_FORMAT_ARGB16161616: u32 = fourcc(b"AR48");
+
+/// `RGB3`: bytes `R, G, B`.
+pub const V4L2_PIX_FMT_RGB24: u32 = fourcc(b"RGB3");
+/// `BGR3`: bytes `B, G, R`.
+pub const V4L2_PIX_FMT_BGR24: u32 = fourcc(b"BGR3");
+/// `GREY`: one gray byte.
+pub const V4L2_PIX_FMT_GREY: u32 = fourcc(b"GREY");
+/// `RGBP`: same layout as [`DRM_FORMAT_RGB565`].
+pub const V4L2_PIX_FMT_RGB565: u32 = fourcc(b"RGBP");
+
+/// Memory layout behind a fourcc code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FourccLayout {
+    /// 8-bit channels in byte order. When `padded`, the alpha position of
+    /// `layout` holds an undefined `X` byte.
+    Bytes {
+        /// Byte order.
+        layout: PixelLayout,
+        /// `X` byte instead of alpha.
+        padded: bool,
+    },
+    /// `u16`: `R[15:11] G[10:5] B[4:0]`.
+    Rgb565 {
+        /// High byte first.
+        big_endian: bool,
+    },
+    /// Little-endian `u32`: R in bits 0–9, G 10–19, B 20–29, A 30–31
+    /// (unused when `padded`).
+    Rgba1010102 {
+        /// `X` bits instead of alpha.
+        padded: bool,
+    },
+    /// 16-bit channels `R, G, B, A` (`B, G, R, A` when `swap_br`).
+    Rgba16 {
+        /// Blue first.
+        swap_br: bool,
+        /// Big-endian samples.
+        big_endian: bool,
+    },
+}
+
+impl FourccLayout {
+    /// Bytes per pixel.
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            Self::Bytes { layout, .. } => layout.bytes_per_pixel(),
+            Self::Rgb565 { .. } => 2,
+            Self::Rgba1010102 { .. } => 4,
+            Self::Rgba16 { .. } => 8,
+        }
+    }
+}
+
+/// Memory layout for a DRM or V4L2 code, or `None` for codes garb doesn't
+/// handle (YUV, planar, codes with DRM's big-endian flag bit set…).
+pub const fn fourcc_layout(code: u32) -> Option<FourccLayout> {
+    const fn bytes(layout: PixelLayout, padded: bool) -> Option<FourccLayout> {
+        Some(FourccLayout::Bytes { layout, padded })
+    }
+    match code {
+        DRM_FORMAT_XRGB8888 => bytes(PixelLayout::Bgra, true),
+        DRM_FORMAT_ARGB8888 => bytes(PixelLayout::Bgra, false),
+        DRM_FORMAT_XBGR8888 => bytes(PixelLayout::Rgba, true),
+        DRM_FORMAT_ABGR8888 => bytes(PixelLayout::Rgba, false),
+        DRM_FORMAT_RGBX8888 => bytes(PixelLayout::Abgr, true),
+        DRM_FORMAT_RGBA8888 => bytes(PixelLayout::Abgr, false),
+        DRM_FORMAT_BGRX8888 => bytes(PixelLayout::Argb, true),
+        DRM_FORMAT_BGRA8888 => bytes(PixelLayout::Argb, false),
+        DRM_FORMAT_RGB888 | V4L2_PIX_FMT_BGR24 => bytes(PixelLayout::Bgr, false),
+        DRM_FORMAT_BGR888 | V4L2_PIX_FMT_RGB24 => bytes(PixelLayout::Rgb, false),
+        DRM_FORMAT_R8 | V4L2_PIX_FMT_GREY => bytes(PixelLayout::Gray, false),
+        DRM_FORMAT_RGB565 | V4L2_PIX_FMT_RGB565 => Some(FourccLayout::Rgb565 { big_endian: false }),
+        DRM_FORMAT_ABGR2101010 => Some(FourccLayout::Rgba1010102 { padded: false }),
+        DRM_FORMAT_XBGR2101010 => Some(FourccLayout::Rgba1010102 { padded: true }),
+        DRM_FORMAT_ABGR16161616 => Some(FourccLayout::Rgba16 {
+            swap_br: false,
+            big_endian: false,
+        }),
+        DRM_FORMAT_ARGB16161616 => Some(FourccLayout::Rgba16 {
+            swap_br: true,
+            big_endian: false,
+        }),
+        _ => None,
+    }
+}
+
+/// Error from [`convert_fourcc`] and [`convert_layout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FourccError {
+    /// [`fourcc_layout`] doesn't know this code.
+    UnknownCode(u32),
+    /// No conversion between the two layouts (e.g. color → `R8`, or a packed
+    /// format without the `experimental` feature).
+    Unsupported,
+    /// Buffer length, dimensions, or strides are inconsistent.
+    Size(SizeError),
+}
+
+impl From<SizeError> for FourccError {
+    fn from(e: SizeError) -> Self {
+        Self::Size(e)
+    }
+}
+
+impl core::fmt::Display for FourccError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownCode(code) => {
+                f.write_str("unknown fourcc ")?;
+                for b in code.to_le_bytes() {
+                    let c = if b.is_ascii_graphic() || b == b' ' {
+                        b as char
+                    } else {
+                        '?'
+                    };
+                    core::fmt::Write::write_char(f, c)?;
+                }
+                write!(f, " (0x{code:08x})")
+            }
+            Self::Unsupported => f.write_str("no conversion between these pixel formats"),
+            Self::Size(e) => core::fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl core::error::Error for FourccError {}
+
+/// Convert a strided image between two fourcc formats (strides in bytes).
+///
+/// Same-layout pairs are a row copy; `X` → alpha pairs set alpha opaque.
+#[allow(clippy::too_many_arguments)]
+pub fn convert_fourcc(
+    src: &[u8],
+    src_code: u32,
+    dst: &mut [u8],
+    dst_code: u32,
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), FourccError> {
+    let s = fourcc_layout(src_code).ok_or(FourccError::UnknownCode(src_code))?;
+    let d = fourcc_layout(dst_code).ok_or(FourccError::UnknownCode(dst_code))?;
+    convert_layout(src, s, dst, d, width, height, src_stride, dst_stride)
+}
+
+/// [`convert_fourcc`] for layouts rather than codes; also the routing behind
+/// [`ffmpeg::convert_pix_fmt`](crate::ffmpeg::convert_pix_fmt).
+///
+/// 8-bit byte layouts use the row kernels. Packed and 16-bit layouts copy
+/// (with byte swaps) between themselves and otherwise need `experimental`,
+/// going through 8-bit RGBA.
+#[allow(clippy::too_many_arguments)]
+pub fn convert_layout(
+    src: &[u8],
+    s: FourccLayout,
+    dst: &mut [u8],
+    d: FourccLayout,
+    width: usize,
+    height: usize,
+    src_stride: usize,
+    dst_stride: usize,
+) -> Result<(), FourccError> {
+    match (s, d) {
+        (
+            FourccLayout::Bytes {
+                layout: sl,
+                padded: sp,
+            },
+            FourccLayout::Bytes {
+                layout: dl,
+                padded: dp,
+            },
+        ) => {
+            if let (PixelLayout::Argb, PixelLayout::Abgr) | (PixelLayout::Abgr, PixelLayout::Argb) =
+                (sl, dl)
+            {
+                // No row kernel swaps R and B behind a leading alpha.
+                let swap = Swizzle::new([0, 3, 2, 1]).expect("indices < 4");
+                transform_4bpp_strided(
+                    src,
+                    dst,
+                    width,
+                    height,
+                    src_stride,
+                    dst_stride,
+                    Orientation::Identity,
+                    swap,
+                )?;
+            } else {
+                let conv = RowConverter::new(sl, dl, width).ok_or(FourccError::Unsupported)?;
+                conv.push_rows(src, dst, height, src_stride, dst_stride)?;
+            }
+            if sp && !dp {
+                fill_alpha(dl, dst, width, height, dst_stride)?;
+            }
+            Ok(())
+        }
+        (FourccLayout::Rgb565 { .. }, FourccLayout::Rgb565 { .. })
+        | (FourccLayout::Rgba1010102 { .. }, FourccLayout::Rgba1010102 { .. })
+        | (FourccLayout::Rgba16 { .. }, FourccLayout::Rgba16 { .. }) => {
+            check_strided(
+                Operand::Src,
+                src.len(),
+                width,
+                height,
+                src_stride,
+                s.bytes_per_pixel(),
+            )?;
+            check_strided(
+                Operand::Dst,
+                dst.len(),
+                width,
+                height,
+                dst_stride,
+                d.bytes_per_pixel(),
+            )?;
+            let row = width * s.bytes_per_pixel();
+            for y in 0..height {
+                let out = &mut dst[y * dst_stride..][..row];
+                out.copy_from_slice(&src[y * src_stride..][..row]);
+                match (s, d) {
+                    (
+                        FourccLayout::Rgba1010102 { padded: true },
+                        FourccLayout::Rgba1010102 { padded: false },
+                    ) => {
+                        for px in out.chunks_exact_mut(4) {
+                            px[3] |= 0xC0;
+                        }
+                    }
+                    (
+                        FourccLayout::Rgb565 { big_endian: se },
+                        FourccLayout::Rgb565 { big_endian: de },
+                    ) if se != de => crate::bytes::swap_bytes_u16_inplace(out)?,
+                    (
+                        FourccLayout::Rgba16 {
+                            swap_br: sb,
+                            big_endian: se,
+                        },
+                        FourccLayout::Rgba16 {
+                            swap_br: db,
+                            big_endian: de,
+                        },
+                    ) => {
+                        if sb != db {
+                            for px in out.chunks_exact_mut(8) {
+                                let (r, rest) = px.split_at_mut(2);
+                                r.swap_with_slice(&mut rest[2..4]);
+                            }
+                        }
+                        if se != de {
+                            crate::bytes::swap_bytes_u16_inplace(out)?;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(())
+        }
+        #[cfg(feature = "experimental")]
+        _ => staged::convert(src, s, dst, d, width, height, src_stride, dst_stride),
+        #[cfg(not(feature = "experimental"))]
+        _ => Err(FourccError::Unsupported),
+    }
+}
+
+/// What [`convert_layout(src, s, dst, d, ..)`](convert_layout) runs on
+/// `tier`, or `None` if it returns `Unsupported` for the pair in this build.
+///
+/// Chained conversions (an `X` → alpha fill, or the 8-bit RGBA stage for
+/// packed and 16-bit layouts) report more than one pass.
+pub const fn layout_capability(
+    s: FourccLayout,
+    d: FourccLayout,
+    tier: SimdTier,
+) -> Option<Capability> {
+    match (s, d) {
+        (
+            FourccLayout::Bytes {
+                layout: sl,
+                padded: sp,
+            },
+            FourccLayout::Bytes {
+                layout: dl,
+                padded: dp,
+            },
+        ) => {
+            let cap = match (sl, dl) {
+                (PixelLayout::Argb, PixelLayout::Abgr) | (PixelLayout::Abgr, PixelLayout::Argb) => {
+                    let kind = match tier {
+                        SimdTier::V3 => KernelKind::Simd,
+                        _ => KernelKind::Scalar,
+                    };
+                    Capability::pass(kind, 4, 4)
+                }
+                _ => match row_capability(sl, dl, tier) {
+                    Some(c) => c,
+                    None => return None,
+                },
+            };
+            if sp && !dp && dl.bytes_per_pixel() == 4 {
+                Some(cap.then(inplace_pass(tier)))
+            } else {
+                Some(cap)
+            }
+        }
+        (FourccLayout::Rgb565 { .. }, FourccLayout::Rgb565 { .. })
+        | (FourccLayout::Rgba1010102 { .. }, FourccLayout::Rgba1010102 { .. })
+        | (FourccLayout::Rgba16 { .. }, FourccLayout::Rgba16 { .. }) => {
+            let bpp = s.bytes_per_pixel();
+            let mut cap = Capability::pass(KernelKind::Copy, bpp, bpp);
+            match (s, d) {
+                (
+                    FourccLayout::Rgba1010102 { padded: true },
+                    FourccLayout::Rgba1010102 { padded: false },
+                ) => cap = cap.then(Capability::pass(KernelKind::Scalar, 4, 4)),
+                (
+                    FourccLayout::Rgb565 { big_endian: se },
+                    FourccLayout::Rgb565 { big_endian: de },
+                ) if se != de => {
+                    let kind = match tier {
+                        SimdTier::Scalar => KernelKind::Scalar,
+                        _ => KernelKind::Simd,
+                    };
+                    cap = cap.then(Capability::pass(kind, 2, 2));
+                }
+                (
+                    FourccLayout::Rgba16 {
+                        swap_br: sb,
+                        big_endian: se,
+                    },
+                    FourccLayout::Rgba16 {
+                        swap_br: db,
+                        big_endian: de,
+                    },
+                ) => {
+                    if sb != db {
+                        cap = cap.then(Capability::pass(KernelKind::Scalar, 8, 8));
+                    }
+                    if se != de {
+                        let kind = match tier {
+                            SimdTier::Scalar => KernelKind::Scalar,
+                            _ => KernelKind::Simd,
+                        };
+                        cap = cap.then(Capability::pass(kind, 8, 8));
+                    }
+                }
+                _ => {}
+            }
+            Some(cap)
+        }
+        #[cfg(feature = "experimental")]
+        _ => match (
+            staged::unpack_capability(s, tier),
+            staged::pack_capability(d, tier),
+        ) {
+            (Some(u), Some(p)) => Some(u.then(p)),
+            _ => None,
+        },
+        #[cfg(not(feature = "experimental"))]
+        _ => None,
+    }
+}
+
+/// In-place SIMD pass over 4-byte pixels (alpha fill, R/B swap).
+const fn inplace_pass(tier: SimdTier) -> Capability {
+    let kind = match tier {
+        SimdTier::Scalar => KernelKind::Scalar,
+        _ => KernelKind::Simd,
+    };
+    Capability::pass(kind, 4, 4)
+}
+
+fn fill_alpha(
+    layout: PixelLayout,
+    buf: &mut [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<(), SizeError> {
+    use crate::bytes::{fill_alpha_argb_strided, fill_alpha_rgba_strided};
+    match layout {
+        PixelLayout::Rgba | PixelLayout::Bgra => {
+            fill_alpha_rgba_strided(buf, width, height, stride)
+        }
+        PixelLayout::Argb | PixelLayout::Abgr => {
+            fill_alpha_argb_strided(buf, width, height, stride)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Packed and 16-bit formats go through a stack buffer of 8-bit RGBA.
+#[cfg(feature = "experimental")]
+mod staged {
+    use super::*;
+    use crate::SizeErrorKind;
+    use crate::bytes::{
+        autoversion, fill_alpha_rgba, rgb565_to_rgba, rgb565be_to_rgba, rgba_to_bgra_inplace,
+        rgba_to_rgb565, rgba_to_rgb565be, rgba16_to_rgba1010102, rgba1010102_to_rgba16,
+    };
+
+    const CHUNK: usize = 64;
+
+    /// Cost of [`unpack`] into the 8-bit RGBA stage.
+    pub(super) const fn unpack_capability(s: FourccLayout, tier: SimdTier) -> Option<Capability> {
+        let auto = autoversion(tier);
+        Some(match s {
+            FourccLayout::Bytes { layout, padded } => {
+                let Some(c) = row_capability(layout, PixelLayout::Rgba, tier) else {
+                    return None;
+                };
+                if padded {
+                    c.then(inplace_pass(tier))
+                } else {
+                    c
+                }
+            }
+            FourccLayout::Rgb565 { .. } => Capability::pass(auto, 2, 4),
+            FourccLayout::Rgba1010102 { padded } => {
+                let c =
+                    Capability::pass(auto, 4, 8).then(Capability::pass(KernelKind::Scalar, 8, 4));
+                if padded {
+                    c.then(inplace_pass(tier))
+                } else {
+                    c
+                }
+            }
+            FourccLayout::Rgba16 { swap_br, .. } => {
+                let c = Capability::pass(KernelKind::Scalar, 8, 4);
+                if swap_br {
+                    c.then(inplace_pass(tier))
+                } else {
+                    c
+                }
+            }
+        })
+    }
+
+    /// Cost of [`pack`] from the 8-bit RGBA stage.
+    pub(super) const fn pack_capability(d: FourccLayout, tier: SimdTier) -> Option<Capability> {
+        let auto = autoversion(tier);
+        Some(match d {
+            FourccLayout::Bytes { layout, .. } => {
+                return row_capability(PixelLayout::Rgba, layout, tier);
+            }
+            FourccLayout::Rgb565 { .. } => Capability::pass(auto, 4, 2),
+            FourccLayout::Rgba1010102 { .. } => {
+                Capability::pass(KernelKind::Scalar, 4, 8).then(Capability::pass(auto, 8, 4))
+            }
+            FourccLayout::Rgba16 { .. } => Capability::pass(KernelKind::Scalar, 4, 8),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn convert(
+        src: &[u8],
+        s: FourccLayout,
+        dst: &mut [u8],
+        d: FourccLayout,
+        width: usize,
+        height: usize,
+        src_stride: usize,
+        dst_stride: usize,
+    ) -> Result<(), FourccError> {
+        if let FourccLayout::Bytes { layout, .. } = d
+            && RowConverter::new(PixelLayout::Rgba, layout, 1).is_none()
+        {
+            return Err(FourccError::Unsupported);
+        }
+        let (sbpp, dbpp) = (s.bytes_per_pixel(), d.bytes_per_pixel());
+        check_strided(Operand::Src, src.len(), width, height, src_stride, sbpp)?;
+        check_strided(Operand::Dst, dst.len(), width, height, dst_stride, dbpp)?;
+        let mut rgba = [0u8; CHUNK * 4];
+        let mut wide = [0u16; CHUNK * 4];
+        for y in 0..height {
+            let srow = &src[y * src_stride..][..width * sbpp];
+            let drow = &mut dst[y * dst_stride..][..width * dbpp];
+            for (s_px, d_px) in srow.chunks(CHUNK * sbpp).zip(drow.chunks_mut(CHUNK * dbpp)) {
+                let n = s_px.len() / sbpp;
+                let px = &mut rgba[..n * 4];
+                let wide = &mut wide[..n * 4];
+                unpack(s, s_px, px, wide)?;
+                pack(d, px, d_px, wide)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn unpack(
+        s: FourccLayout,
+        src: &[u8],
+        rgba: &mut [u8],
+        wide: &mut [u16],
+    ) -> Result<(), FourccError> {
+        let padded = match s {
+            FourccLayout::Bytes { layout, padded } => {
+                let n = rgba.len() / 4;
+                RowConverter::new(layout, PixelLayout::Rgba, n)
+                    .ok_or(FourccError::Unsupported)?
+                    .push_row(src, rgba)?;
+                padded
+            }
+            FourccLayout::Rgb565 { big_endian } => {
+                if big_endian {
+                    rgb565be_to_rgba(src, rgba)?;
+                } else {
+                    rgb565_to_rgba(src, rgba)?;
+                }
+                false
+            }
+            FourccLayout::Rgba1010102 { padded } => {
+                rgba1010102_to_rgba16(src, wide)?;
+                for (o, &v) in rgba.iter_mut().zip(wide.iter()) {
+                    *o = ((u32::from(v) * 255 + 511) / 1023) as u8;
+                }
+                padded
+            }
+            FourccLayout::Rgba16 {
+                swap_br,
+                big_endian,
+            } => {
+                for (o, b) in rgba.iter_mut().zip(src.chunks_exact(2)) {
+                    let v = if big_endian {
+                        u16::from_be_bytes([b[0], b[1]])
+                    } else {
+                        u16::from_le_bytes([b[0], b[1]])
+                    };
+                    *o = ((u32::from(v) * 255 + 32895) >> 16) as u8;
+                }
+                if swap_br {
+                    rgba_to_bgra_inplace(rgba)?;
+                }
+                false
+            }
+        };
+        if padded {
+            fill_alpha_rgba(rgba)?;
+        }
+        Ok(())
+    }
+
+    fn pack(
+        d: FourccLayout,
+        rgba: &[u8],
+        dst: &mut [u8],
+        wide: &mut [u16],
+    ) -> Result<(), FourccError> {
+        match d {
+            FourccLayout::Bytes { layout, .. } => {
+                RowConverter::new(PixelLayout::Rgba, layout, rgba.len() / 4)
+                    .ok_or(FourccError::Unsupported)?
+                    .push_row(rgba, dst)?;
+            }
+            FourccLayout::Rgb565 { big_endian: false } => rgba_to_rgb565(rgba, dst)?,
+            FourccLayout::Rgb565 { big_endian: true } => rgba_to_rgb565be(rgba, dst)?,
+            FourccLayout::Rgba1010102 { .. } => {
+                for (w, &v) in wide.iter_mut().zip(rgba) {
+                    *w = (u16::from(v) << 2) | (u16::from(v) >> 6);
+                }
+                rgba16_to_rgba1010102(wide, dst)?;
+            }
+            FourccLayout::Rgba16 {
+                swap_br,
+                big_endian,
+            } => {
+                if dst.len() < rgba.len() * 2 {
+                    return Err(SizeError::new(SizeErrorKind::PixelCountMismatch)
+                        .of(Operand::Dst)
+                        .into());
+                }
+                let order = if swap_br { [2, 1, 0, 3] } else { [0, 1, 2, 3] };
+                for (px, out) in rgba.chunks_exact(4).zip(dst.chunks_exact_mut(8)) {
+                    for (c, o) in order.iter().zip(out.chunks_exact_mut(2)) {
+                        let v = u16::from(px[*c]) * 257;
+                        o.copy_from_slice(&if big_endian {
+                            v.to_be_bytes()
+                        } else {
+                            v.to_le_bytes()
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SizeErrorKind;
+
+    #[test]
+    fn test_endian_naming() {
+        assert_eq!(DRM_FORMAT_XRGB8888, 0x3432_5258);
+        assert_eq!(
+            fourcc_layout(DRM_FORMAT_RGB888),
+            fourcc_layout(V4L2_PIX_FMT_BGR24)
+        );
+        assert_eq!(
+            fourcc_layout(DRM_FORMAT_BGRA8888),
+            Some(FourccLayout::Bytes {
+                layout: PixelLayout::Argb,
+                padded: false
+            })
+        );
+        assert_eq!(fourcc_layout(fourcc(b"NV12")), None);
+
+        // RG24 is B, G, R in memory; RGB3 is R, G, B.
+        let src = [1u8, 2, 3];
+        let mut out = [0u8; 3];
+        convert_fourcc(
+            &src,
+            DRM_FORMAT_RGB888,
+            &mut out,
+            V4L2_PIX_FMT_RGB24,
+            1,
+            1,
+            3,
+            3,
+        )
+        .unwrap();
+        assert_eq!(out, [3, 2, 1]);
+    }
+
+    #[test]
+    fn test_padding_and_errors() {
+        // BX24 (X, R, G, B) → RA24 (A, B, G, R), 2×2 with padded strides.
+        let src = [0u8, 1, 2, 3, 0, 4, 5, 6, 9, 9, 0, 7, 8, 9, 0, 10, 11, 12];
+        let mut dst = [0u8; 16];
+        convert_fourcc(
+            &src,
+            DRM_FORMAT_BGRX8888,
+            &mut dst,
+            DRM_FORMAT_RGBA8888,
+            2,
+            2,
+            10,
+            8,
+        )
+        .unwrap();
+        assert_eq!(
+            dst,
+            [255, 3, 2, 1, 255, 6, 5, 4, 255, 9, 8, 7, 255, 12, 11, 10]
+        );
+
+        let mut gray = [0u8; 2];
+        assert_eq!(
+            convert_fourcc(
+                &dst,
+                DRM_FORMAT_RGBA8888,
+                &mut gray,
+                DRM_FORMAT_R8,
+                2,
+                1,
+                8,
+                2
+            ),
+            Err(FourccError::Unsupported)
+        );
+        assert_eq!(
+            convert_fourcc(&dst, fourcc(b"YUYV"), &mut gray, DRM_FORMAT_R8, 2, 1, 8, 2),
+            Err(FourccError::UnknownCode(fourcc(b"YUYV")))
+        );
+        assert_eq!(
+            convert_fourcc(
+                &dst[..7],
+                DRM_FORMAT_RGBA8888,
+                &mut dst.clone(),
+                DRM_FORMAT_ABGR8888,
+                2,
+                1,
+                8,
+                8
+            )
+            .map_err(|e| match e {
+                FourccError::Size(e) => e.kind(),
+                _ => unreachable!(),
+            }),
+            Err(SizeErrorKind::InvalidStride)
+        );
+
+        let mut ab30 = [0u8, 0, 0, 0];
+        convert_fourcc(
+            &[1, 2, 3, 0],
+            DRM_FORMAT_XBGR2101010,
+            &mut ab30,
+            DRM_FORMAT_ABGR2101010,
+            1,
+            1,
+            4,
+            4,
+        )
+        .unwrap();
+        assert_eq!(ab30, [1, 2, 3, 0xC0]);
+    }
+
+    #[test]
+    fn test_capability_matches_convert() {
+        let codes = [
+            DRM_FORMAT_XRGB8888,
+            DRM_FORMAT_ARGB8888,
+            DRM_FORMAT_RGBA8888,
+            DRM_FORMAT_BGRX8888,
+            DRM_FORMAT_RGB888,
+            DRM_FORMAT_R8,
+            DRM_FORMAT_RGB565,
+            DRM_FORMAT_ABGR2101010,
+            DRM_FORMAT_XBGR2101010,
+            DRM_FORMAT_ABGR16161616,
+            DRM_FORMAT_ARGB16161616,
+        ];
+        for s in codes {
+            for d in codes {
+                let (sl, dl) = (fourcc_layout(s).unwrap(), fourcc_layout(d).unwrap());
+                let src = [0u8; 8];
+                let mut dst = [0u8; 8];
+                let ok = convert_fourcc(&src, s, &mut dst, d, 1, 1, 8, 8).is_ok();
+                let cap = layout_capability(sl, dl, SimdTier::V3);
+                assert_eq!(cap.is_some(), ok, "{sl:?} -> {dl:?}");
+                if let Some(c) = cap {
+                    assert_eq!(c.src_bytes_per_pixel, sl.bytes_per_pixel());
+                    assert_eq!(c.dst_bytes_per_pixel, dl.bytes_per_pixel());
+                }
+            }
+        }
+        let x_to_a = layout_capability(
+            fourcc_layout(DRM_FORMAT_XRGB8888).unwrap(),
+            fourcc_layout(DRM_FORMAT_ABGR8888).unwrap(),
+            SimdTier::Neon,
+        )
+        .unwrap();
+        assert_eq!((x_to_a.kind, x_to_a.passes), (KernelKind::Simd, 2));
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn test_packed_staging() {
+        extern crate alloc;
+        use alloc::vec;
+
+        // 70 px crosses the 64-px chunk boundary.
+        let mut xr24 = vec![0u8; 70 * 4];
+        for (i, px) in xr24.chunks_exact_mut(4).enumerate() {
+            px.copy_from_slice(&[0xFF, 0, if i == 69 { 0xFF } else { 0 }, 0]);
+        }
+        let mut rg16 = vec![0u8; 70 * 2];
+        convert_fourcc(
+            &xr24,
+            DRM_FORMAT_XRGB8888,
+            &mut rg16,
+            DRM_FORMAT_RGB565,
+            70,
+            1,
+            280,
+            140,
+        )
+        .unwrap();
+        assert_eq!(&rg16[..2], &0x001Fu16.to_le_bytes());
+        assert_eq!(&rg16[138..], &0xF81Fu16.to_le_bytes());
+
+        let mut ab30 = vec![0u8; 70 * 4];
+        convert_fourcc(
+            &rg16,
+            V4L2_PIX_FMT_RGB565,
+            &mut ab30,
+            DRM_FORMAT_ABGR2101010,
+            70,
+            1,
+            140,
+            280,
+        )
+        .unwrap();
+        let last = u32::from_le_bytes(ab30[276..].try_into().unwrap());
+        assert_eq!(last, 0x3FF | (0x3FF << 20) | (3 << 30));
+
+        let mut ab24 = vec![0u8; 70 * 4];
+        convert_fourcc(
+            &ab30,
+            DRM_FORMAT_ABGR2101010,
+            &mut ab24,
+            DRM_FORMAT_ABGR8888,
+            70,
+            1,
+            280,
+            280,
+        )
+        .unwrap();
+        assert_eq!(&ab24[276..], &[255, 0, 255, 255]);
+
+        let le = FourccLayout::Rgb565 { big_endian: false };
+        let be = FourccLayout::Rgb565 { big_endian: true };
+        let mut rg16be = vec![0u8; 70 * 2];
+        convert_layout(&rg16, le, &mut rg16be, be, 70, 1, 140, 140).unwrap();
+        assert_eq!(&rg16be[138..], &0xF81Fu16.to_be_bytes());
+        let rgba = FourccLayout::Bytes {
+            layout: PixelLayout::Rgba,
+            padded: false,
+        };
+        convert_layout(&rg16be, be, &mut ab24, rgba, 70, 1, 140, 280).unwrap();
+        assert_eq!(&ab24[..4], &[0, 0, 255, 255]);
+        assert_eq!(&ab24[276..], &[255, 0, 255, 255]);
+        convert_layout(&ab24, rgba, &mut rg16be, be, 70, 1, 280, 140).unwrap();
+        assert_eq!(&rg16be[..2], &0x001Fu16.to_be_bytes());
+        assert!(layout_capability(le, be, SimdTier::Scalar).is_some());
+    }
+}
@@ -0,0 +1,546 @@
+//! Conversions for [`image`] crate buffers.
+//!
+//! [`image_to_rgba8`] and [`image_to_bgra8`] write an `ImageBuffer` as 8-bit
+//! RGBA or BGRA bytes (e.g. into a display surface); [`bgra8_to_image`] turns
+//! a BGRA capture back into an `RgbaImage`. [`dynamic_to_rgba8`] and
+//! [`dynamic_to_bgra8`] dispatch on a `DynamicImage`'s color type to the SIMD
+//! kernels instead of `image`'s per-pixel `to_rgba8`, falling back to it for
+//! color types garb doesn't handle.
+//!
+//! [`convert_image`] and [`convert_image_into`] convert between `image`
+//! pixel types, e.g. `Rgb<u8>` → `Rgba<u8>` or `Rgba<u16>` → `Rgba<u8>`; the
+//! pairs are the [`ConvertPixel`] impls.
+//!
+//! 8-bit `Rgba`, `Rgb`, `Luma` and `LumaA` are always supported. 16-bit and
+//! `f32` pixels need the `experimental` feature (they go through its depth
+//! conversions, in chunks on the stack).
+//!
+//! ```rust
+//! use image::{DynamicImage, RgbImage, Rgb};
+//! use garb::image::{dynamic_to_bgra8, image_to_bgra8};
+//!
+//! let img = RgbImage::from_pixel(2, 2, Rgb([255, 0, 128]));
+//! let mut bgra = vec![0u8; 2 * 2 * 4];
+//! image_to_bgra8(&img, &mut bgra)?;
+//! assert_eq!(&bgra[..4], &[128, 0, 255, 255]);
+//!
+//! dynamic_to_bgra8(&DynamicImage::ImageRgb8(img.clone()), &mut bgra)?;
+//!
+//! let rgba: image::RgbaImage = garb::image::convert_image(&img);
+//! assert_eq!(rgba.get_pixel(1, 1).0, [255, 0, 128, 255]);
+//! # Ok::<(), garb::SizeError>(())
+//! ```
+
+use alloc::vec;
+use core::ops::{Deref, DerefMut};
+
+use ::image::{DynamicImage, ImageBuffer, Luma, LumaA, Pixel, Rgb, Rgba, RgbaImage};
+
+use crate::{Operand, SizeError, SizeErrorKind};
+
+/// An `image` pixel type that garb converts to 8-bit RGBA and BGRA.
+pub trait ImagePixel: Pixel {
+    /// Packed samples → RGBA bytes (4 per pixel).
+    fn samples_to_rgba8(samples: &[Self::Subpixel], dst: &mut [u8]) -> Result<(), SizeError>;
+    /// Packed samples → BGRA bytes (4 per pixel).
+    fn samples_to_bgra8(samples: &[Self::Subpixel], dst: &mut [u8]) -> Result<(), SizeError>;
+}
+
+/// RGBA → RGBA: a validated copy.
+fn copy_rgba(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    if src.is_empty() || !src.len().is_multiple_of(4) {
+        return Err(SizeError::new(SizeErrorKind::NotPixelAligned)
+            .of(Operand::Src)
+            .lengths(None, src.len()));
+    }
+    let have = dst.len();
+    dst.get_mut(..src.len())
+        .ok_or(
+            SizeError::new(SizeErrorKind::PixelCountMismatch)
+                .of(Operand::Dst)
+                .lengths(Some(src.len()), have),
+        )?
+        .copy_from_slice(src);
+    Ok(())
+}
+
+macro_rules! image_pixel {
+    ($px:ty, $rgba:path, $bgra:path) => {
+        impl ImagePixel for $px {
+            fn samples_to_rgba8(
+                samples: &[Self::Subpixel],
+                dst: &mut [u8],
+            ) -> Result<(), SizeError> {
+                $rgba(bytemuck::cast_slice(samples), dst)
+            }
+            fn samples_to_bgra8(
+                samples: &[Self::Subpixel],
+                dst: &mut [u8],
+            ) -> Result<(), SizeError> {
+                $bgra(bytemuck::cast_slice(samples), dst)
+            }
+        }
+    };
+}
+
+image_pixel!(Rgba<u8>, copy_rgba, crate::bytes::rgba_to_bgra);
+image_pixel!(
+    Rgb<u8>,
+    crate::bytes::rgb_to_rgba,
+    crate::bytes::rgb_to_bgra
+);
+image_pixel!(
+    Luma<u8>,
+    crate::bytes::gray_to_rgba,
+    crate::bytes::gray_to_bgra
+);
+image_pixel!(
+    LumaA<u8>,
+    crate::bytes::gray_alpha_to_rgba,
+    crate::bytes::gray_alpha_to_bgra
+);
+
+/// An `image` pixel type that garb converts to the pixel type `Q`.
+///
+/// Implemented for layout changes between 8-bit pixels and, with
+/// `experimental`, for depth changes (`u8`, `u16`, `f32`) within a layout and
+/// from 16-bit and `f32` pixels to `Rgba<u8>`. Gray from color uses BT.709
+/// weights and drops alpha.
+pub trait ConvertPixel<Q: Pixel>: Pixel {
+    /// Packed samples → packed `Q` samples, one pixel for each.
+    fn convert_samples(src: &[Self::Subpixel], dst: &mut [Q::Subpixel]) -> Result<(), SizeError>;
+}
+
+macro_rules! convert_pixel {
+    ($($from:ty => $to:ty, $f:path;)*) => {$(
+        impl ConvertPixel<$to> for $from {
+            fn convert_samples(
+                src: &[Self::Subpixel],
+                dst: &mut [<$to as Pixel>::Subpixel],
+            ) -> Result<(), SizeError> {
+                $f(bytemuck::cast_slice(src), bytemuck::cast_slice_mut(dst))
+            }
+        }
+    )*};
+}
+
+convert_pixel! {
+    Rgb<u8> => Rgba<u8>, crate::bytes::rgb_to_rgba;
+    Rgba<u8> => Rgb<u8>, crate::bytes::rgba_to_rgb;
+    Luma<u8> => Rgba<u8>, crate::bytes::gray_to_rgba;
+    LumaA<u8> => Rgba<u8>, crate::bytes::gray_alpha_to_rgba;
+}
+
+#[cfg(feature = "experimental")]
+mod depth {
+    use super::*;
+
+    type BytesFn = fn(&[u8], &mut [u8]) -> Result<(), SizeError>;
+
+    /// Pixels per chunk staged through the stack.
+    const CHUNK: usize = 256;
+
+    /// Depth-convert `src` to 8-bit samples a chunk at a time, then lay each
+    /// chunk out as 4-byte pixels with `layout`.
+    fn staged(
+        src: &[u8],
+        src_bpp: usize,
+        channels: usize,
+        depth: BytesFn,
+        layout: BytesFn,
+        dst: &mut [u8],
+    ) -> Result<(), SizeError> {
+        if src.is_empty() || !src.len().is_multiple_of(src_bpp) {
+            return Err(SizeError::new(SizeErrorKind::NotPixelAligned)
+                .of(Operand::Src)
+                .lengths(None, src.len()));
+        }
+        let need = src.len() / src_bpp * 4;
+        if dst.len() < need {
+            return Err(SizeError::new(SizeErrorKind::PixelCountMismatch)
+                .of(Operand::Dst)
+                .lengths(Some(need), dst.len()));
+        }
+        let mut tmp = [0u8; CHUNK * 4];
+        for (s, d) in src.chunks(CHUNK * src_bpp).zip(dst.chunks_mut(CHUNK * 4)) {
+            let t = &mut tmp[..s.len() / src_bpp * channels];
+            depth(s, t)?;
+            layout(t, d)?;
+        }
+        Ok(())
+    }
+
+    macro_rules! image_pixel_depth {
+        ($px:ty, $depth:path, $rgba:path, $bgra:path) => {
+            impl ImagePixel for $px {
+                fn samples_to_rgba8(
+                    samples: &[Self::Subpixel],
+                    dst: &mut [u8],
+                ) -> Result<(), SizeError> {
+                    let ch = usize::from(<$px as Pixel>::CHANNEL_COUNT);
+                    let bpp = ch * size_of::<<$px as Pixel>::Subpixel>();
+                    staged(bytemuck::cast_slice(samples), bpp, ch, $depth, $rgba, dst)
+                }
+                fn samples_to_bgra8(
+                    samples: &[Self::Subpixel],
+                    dst: &mut [u8],
+                ) -> Result<(), SizeError> {
+                    let ch = usize::from(<$px as Pixel>::CHANNEL_COUNT);
+                    let bpp = ch * size_of::<<$px as Pixel>::Subpixel>();
+                    staged(bytemuck::cast_slice(samples), bpp, ch, $depth, $bgra, dst)
+                }
+            }
+        };
+    }
+
+    use crate::bytes::{
+        convert_f32_to_u8, convert_u16_to_u8, gray_alpha_to_bgra, gray_alpha_to_rgba, gray_to_bgra,
+        gray_to_rgba, rgb_to_bgra, rgb_to_rgba, rgba_to_bgra,
+    };
+
+    image_pixel_depth!(Rgba<u16>, convert_u16_to_u8, copy_rgba, rgba_to_bgra);
+    image_pixel_depth!(Rgb<u16>, convert_u16_to_u8, rgb_to_rgba, rgb_to_bgra);
+    image_pixel_depth!(Luma<u16>, convert_u16_to_u8, gray_to_rgba, gray_to_bgra);
+    image_pixel_depth!(
+        LumaA<u16>,
+        convert_u16_to_u8,
+        gray_alpha_to_rgba,
+        gray_alpha_to_bgra
+    );
+    image_pixel_depth!(Rgba<f32>, convert_f32_to_u8, copy_rgba, rgba_to_bgra);
+    image_pixel_depth!(Rgb<f32>, convert_f32_to_u8, rgb_to_rgba, rgb_to_bgra);
+    image_pixel_depth!(Luma<f32>, convert_f32_to_u8, gray_to_rgba, gray_to_bgra);
+    image_pixel_depth!(
+        LumaA<f32>,
+        convert_f32_to_u8,
+        gray_alpha_to_rgba,
+        gray_alpha_to_bgra
+    );
+
+    use crate::bytes::{
+        convert_f32_to_u16, convert_u8_to_f32, convert_u8_to_u16, convert_u16_to_f32,
+        gray_alpha_to_gray, gray_alpha_to_rgb, gray_to_gray_alpha, gray_to_rgb, rgb_to_gray_bt709,
+        rgba_to_gray_bt709,
+    };
+
+    convert_pixel! {
+        Luma<u8> => Rgb<u8>, gray_to_rgb;
+        LumaA<u8> => Rgb<u8>, gray_alpha_to_rgb;
+        Luma<u8> => LumaA<u8>, gray_to_gray_alpha;
+        LumaA<u8> => Luma<u8>, gray_alpha_to_gray;
+        Rgb<u8> => Luma<u8>, rgb_to_gray_bt709;
+        Rgba<u8> => Luma<u8>, rgba_to_gray_bt709;
+    }
+
+    macro_rules! convert_depth {
+        ($($layout:ident),*) => {$(
+            convert_pixel! {
+                $layout<u8> => $layout<u16>, convert_u8_to_u16;
+                $layout<u16> => $layout<u8>, convert_u16_to_u8;
+                $layout<u8> => $layout<f32>, convert_u8_to_f32;
+                $layout<f32> => $layout<u8>, convert_f32_to_u8;
+                $layout<u16> => $layout<f32>, convert_u16_to_f32;
+                $layout<f32> => $layout<u16>, convert_f32_to_u16;
+            }
+        )*};
+    }
+
+    convert_depth!(Rgba, Rgb, Luma, LumaA);
+
+    /// Depth and layout in one go, through [`ImagePixel`]'s staged path.
+    macro_rules! convert_to_rgba8 {
+        ($($px:ty),*) => {$(
+            impl ConvertPixel<Rgba<u8>> for $px {
+                fn convert_samples(
+                    src: &[Self::Subpixel],
+                    dst: &mut [u8],
+                ) -> Result<(), SizeError> {
+                    <$px as ImagePixel>::samples_to_rgba8(src, dst)
+                }
+            }
+        )*};
+    }
+
+    convert_to_rgba8!(
+        Rgb<u16>,
+        Luma<u16>,
+        LumaA<u16>,
+        Rgb<f32>,
+        Luma<f32>,
+        LumaA<f32>
+    );
+}
+
+/// The `width × height` pixels of `img`, ignoring any excess in its container.
+fn samples<P: Pixel, C: Deref<Target = [P::Subpixel]>>(img: &ImageBuffer<P, C>) -> &[P::Subpixel] {
+    let n = img.width() as usize * img.height() as usize * usize::from(P::CHANNEL_COUNT);
+    &img.as_raw()[..n]
+}
+
+/// Write `img` as 8-bit RGBA into `dst` (at least `width * height * 4` bytes).
+/// An image without pixels writes nothing.
+pub fn image_to_rgba8<P: ImagePixel, C: Deref<Target = [P::Subpixel]>>(
+    img: &ImageBuffer<P, C>,
+    dst: &mut [u8],
+) -> Result<(), SizeError> {
+    let samples = samples(img);
+    if samples.is_empty() {
+        return Ok(());
+    }
+    P::samples_to_rgba8(samples, dst)
+}
+
+/// Write `img` as 8-bit BGRA into `dst` (at least `width * height * 4`
+/// bytes), the byte order of Windows and most GPU display surfaces. An image
+/// without pixels writes nothing.
+pub fn image_to_bgra8<P: ImagePixel, C: Deref<Target = [P::Subpixel]>>(
+    img: &ImageBuffer<P, C>,
+    dst: &mut [u8],
+) -> Result<(), SizeError> {
+    let samples = samples(img);
+    if samples.is_empty() {
+        return Ok(());
+    }
+    P::samples_to_bgra8(samples, dst)
+}
+
+/// Packed BGRA bytes (`width * height * 4`, extra bytes ignored) → a new
+/// `RgbaImage`.
+pub fn bgra8_to_image(src: &[u8], width: u32, height: u32) -> Result<RgbaImage, SizeError> {
+    let len = width as usize * height as usize * 4;
+    let src = src.get(..len).ok_or(
+        SizeError::new(SizeErrorKind::PixelCountMismatch)
+            .of(Operand::Src)
+            .lengths(Some(len), src.len()),
+    )?;
+    let mut buf = vec![0u8; len];
+    if len > 0 {
+        crate::bytes::bgra_to_rgba(src, &mut buf)?;
+    }
+    Ok(RgbaImage::from_raw(width, height, buf).expect("buffer sized to fit"))
+}
+
+/// Convert `img` into `dst`, which must have the same dimensions.
+///
+/// ```rust
+/// use image::{GrayImage, Luma, RgbaImage};
+///
+/// let gray = GrayImage::from_pixel(2, 1, Luma([9]));
+/// let mut rgba = RgbaImage::new(2, 1);
+/// garb::image::convert_image_into(&gray, &mut rgba)?;
+/// assert_eq!(rgba.get_pixel(1, 0).0, [9, 9, 9, 255]);
+/// # Ok::<(), garb::SizeError>(())
+/// ```
+pub fn convert_image_into<P, Q, C, D>(
+    img: &ImageBuffer<P, C>,
+    dst: &mut ImageBuffer<Q, D>,
+) -> Result<(), SizeError>
+where
+    P: ConvertPixel<Q>,
+    Q: Pixel,
+    C: Deref<Target = [P::Subpixel]>,
+    D: DerefMut<Target = [Q::Subpixel]>,
+{
+    let ch = usize::from(Q::CHANNEL_COUNT);
+    let need = img.width() as usize * img.height() as usize * ch;
+    if dst.dimensions() != img.dimensions() {
+        let have = dst.width() as usize * dst.height() as usize * ch;
+        return Err(SizeError::new(SizeErrorKind::PixelCountMismatch)
+            .of(Operand::Dst)
+            .lengths(Some(need), have));
+    }
+    if need == 0 {
+        return Ok(());
+    }
+    let out: &mut [Q::Subpixel] = dst;
+    P::convert_samples(samples(img), &mut out[..need])
+}
+
+/// Convert `img` into a new `ImageBuffer` of pixel type `Q`.
+///
+/// ```rust
+/// use image::{ImageBuffer, Rgb, Rgba};
+///
+/// let img = ImageBuffer::from_pixel(2, 2, Rgb([10u8, 20, 30]));
+/// let rgba: ImageBuffer<Rgba<u8>, Vec<u8>> = garb::image::convert_image(&img);
+/// assert_eq!(rgba.get_pixel(0, 1).0, [10, 20, 30, 255]);
+/// ```
+pub fn convert_image<P, Q, C>(img: &ImageBuffer<P, C>) -> ImageBuffer<Q, vec::Vec<Q::Subpixel>>
+where
+    P: ConvertPixel<Q>,
+    Q: Pixel,
+    C: Deref<Target = [P::Subpixel]>,
+{
+    let mut dst = ImageBuffer::new(img.width(), img.height());
+    convert_image_into(img, &mut dst).expect("buffer sized to fit");
+    dst
+}
+
+/// Convert `img` with garb's kernels, or `None` if its color type isn't handled.
+fn dispatch(img: &DynamicImage, dst: &mut [u8], bgra: bool) -> Option<Result<(), SizeError>> {
+    fn go<P: ImagePixel>(
+        img: &ImageBuffer<P, vec::Vec<P::Subpixel>>,
+        dst: &mut [u8],
+        bgra: bool,
+    ) -> Result<(), SizeError> {
+        if bgra {
+            image_to_bgra8(img, dst)
+        } else {
+            image_to_rgba8(img, dst)
+        }
+    }
+    Some(match img {
+        DynamicImage::ImageRgba8(i) => go(i, dst, bgra),
+        DynamicImage::ImageRgb8(i) => go(i, dst, bgra),
+        DynamicImage::ImageLuma8(i) => go(i, dst, bgra),
+        DynamicImage::ImageLumaA8(i) => go(i, dst, bgra),
+        #[cfg(feature = "experimental")]
+        DynamicImage::ImageRgba16(i) => go(i, dst, bgra),
+        #[cfg(feature = "experimental")]
+        DynamicImage::ImageRgb16(i) => go(i, dst, bgra),
+        #[cfg(feature = "experimental")]
+        DynamicImage::ImageLuma16(i) => go(i, dst, bgra),
+        #[cfg(feature = "experimental")]
+        DynamicImage::ImageLumaA16(i) => go(i, dst, bgra),
+        #[cfg(feature = "experimental")]
+        DynamicImage::ImageRgba32F(i) => go(i, dst, bgra),
+        #[cfg(feature = "experimental")]
+        DynamicImage::ImageRgb32F(i) => go(i, dst, bgra),
+        _ => return None,
+    })
+}
+
+/// `DynamicImage` → `RgbaImage`, using the SIMD kernels for supported color
+/// types and `image`'s `to_rgba8` otherwise.
+pub fn dynamic_to_rgba8(img: &DynamicImage) -> RgbaImage {
+    let (w, h) = (img.width(), img.height());
+    let mut buf = vec![0u8; w as usize * h as usize * 4];
+    match dispatch(img, &mut buf, false) {
+        Some(Ok(())) => RgbaImage::from_raw(w, h, buf).expect("buffer sized to fit"),
+        // Unhandled color type, or an empty image.
+        _ => img.to_rgba8(),
+    }
+}
+
+/// Write a `DynamicImage` as 8-bit BGRA into `dst` (at least
+/// `width * height * 4` bytes).
+///
+/// Color types without a garb kernel go through `image`'s `to_rgba8` first.
+/// An image without pixels writes nothing.
+pub fn dynamic_to_bgra8(img: &DynamicImage, dst: &mut [u8]) -> Result<(), SizeError> {
+    if img.width() == 0 || img.height() == 0 {
+        return Ok(());
+    }
+    match dispatch(img, dst, true) {
+        Some(result) => result,
+        None => crate::bytes::rgba_to_bgra(img.to_rgba8().as_raw(), dst),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::image::{GrayImage, RgbImage};
+
+    #[test]
+    fn test_buffers_to_bgra8() {
+        let img = RgbImage::from_pixel(3, 2, Rgb([10, 20, 30]));
+        let mut dst = vec![0u8; 3 * 2 * 4];
+        image_to_bgra8(&img, &mut dst).unwrap();
+        assert!(dst.chunks_exact(4).all(|px| px == [30, 20, 10, 255]));
+
+        let gray = GrayImage::from_pixel(2, 1, Luma([7]));
+        image_to_rgba8(&gray, &mut dst).unwrap();
+        assert_eq!(&dst[..8], &[7, 7, 7, 255, 7, 7, 7, 255]);
+
+        let mut short = [0u8; 4];
+        assert_eq!(
+            image_to_rgba8(&img, &mut short).map_err(|e| e.kind()),
+            Err(SizeErrorKind::PixelCountMismatch)
+        );
+
+        // Zero-pixel images are a no-op, as in `convert_image_into`.
+        let empty = RgbImage::new(0, 3);
+        image_to_rgba8(&empty, &mut []).unwrap();
+        image_to_bgra8(&empty, &mut []).unwrap();
+        dynamic_to_bgra8(&DynamicImage::ImageRgb8(empty), &mut []).unwrap();
+        dynamic_to_bgra8(&DynamicImage::new_luma_a16(2, 0), &mut []).unwrap();
+    }
+
+    #[test]
+    fn test_bgra8_roundtrip() {
+        let src = [1u8, 2, 3, 4, 5, 6, 7, 8];
+        let img = bgra8_to_image(&src, 2, 1).unwrap();
+        assert_eq!(img.get_pixel(1, 0), &Rgba([7, 6, 5, 8]));
+        let mut back = [0u8; 8];
+        image_to_bgra8(&img, &mut back).unwrap();
+        assert_eq!(back, src);
+        let e = bgra8_to_image(&src[..4], 2, 1).unwrap_err();
+        assert_eq!(e.kind(), SizeErrorKind::PixelCountMismatch);
+        assert_eq!(e.operand(), Some(Operand::Src));
+        assert_eq!((e.required(), e.actual()), (Some(8), Some(4)));
+    }
+
+    #[test]
+    fn test_convert_image() {
+        let img = RgbImage::from_fn(3, 2, |x, y| Rgb([x as u8 * 40, y as u8 * 90, 7]));
+        let rgba: RgbaImage = convert_image(&img);
+        assert_eq!(rgba, DynamicImage::ImageRgb8(img.clone()).to_rgba8());
+        let back: RgbImage = convert_image(&rgba);
+        assert_eq!(back, img);
+
+        let mut small = RgbaImage::new(2, 2);
+        let err = convert_image_into(&img, &mut small).unwrap_err();
+        assert_eq!(err.kind(), SizeErrorKind::PixelCountMismatch);
+        assert_eq!(err.operand(), Some(Operand::Dst));
+        assert_eq!((err.required(), err.actual()), (Some(24), Some(16)));
+        assert!(convert_image::<_, Rgba<u8>, _>(&RgbImage::new(0, 3)).is_empty());
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn test_convert_image_depth() {
+        let img = ImageBuffer::from_fn(300, 2, |x, y| {
+            let v = (x + y * 7) as u16 % 256;
+            Rgb([v * 257, y as u16 * 257, 65535 - v * 257])
+        });
+        let dynamic = DynamicImage::ImageRgb16(img.clone());
+        let rgb8: RgbImage = convert_image(&img);
+        assert_eq!(rgb8, dynamic.to_rgb8());
+        let rgba8: RgbaImage = convert_image(&img);
+        assert_eq!(rgba8, dynamic.to_rgba8());
+
+        let gray = GrayImage::from_fn(4, 4, |x, y| Luma([(x * 60 + y) as u8]));
+        let rgb: RgbImage = convert_image(&gray);
+        assert_eq!(rgb, DynamicImage::ImageLuma8(gray.clone()).to_rgb8());
+        let wide: ImageBuffer<Luma<u16>, _> = convert_image(&gray);
+        let narrow: GrayImage = convert_image(&wide);
+        assert_eq!(narrow, gray);
+    }
+
+    #[test]
+    fn test_dynamic_matches_image() {
+        let mut imgs = vec![
+            DynamicImage::ImageLumaA8(::image::GrayAlphaImage::from_pixel(5, 3, LumaA([9, 99]))),
+            DynamicImage::ImageRgb16(::image::ImageBuffer::from_pixel(
+                300,
+                2,
+                Rgb([0u16, 32768, 65535]),
+            )),
+        ];
+        imgs.push(DynamicImage::ImageRgba32F(
+            ::image::Rgba32FImage::from_pixel(2, 2, Rgba([0.0, 0.5, 1.0, 2.0])),
+        ));
+        for img in &imgs {
+            let expected = img.to_rgba8();
+            assert_eq!(dynamic_to_rgba8(img), expected);
+            let mut bgra = vec![0u8; expected.len()];
+            dynamic_to_bgra8(img, &mut bgra).unwrap();
+            let mut rgba = bgra.clone();
+            crate::bytes::bgra_to_rgba_inplace(&mut rgba).unwrap();
+            assert_eq!(rgba, expected.as_raw().as_slice());
+        }
+        let empty = DynamicImage::new_rgb8(0, 0);
+        assert!(dynamic_to_rgba8(&empty).is_empty());
+    }
+}
@@ -1,7 +1,10 @@
 //! Whole-image pixel format conversions using [`imgref`] types.
 //!
 //! These functions handle strided image buffers using the SIMD-optimized
-//! core operations. No allocation — caller owns all buffers.
+//! core operations. Copy conversions write into caller-owned buffers;
+//! [`convert_owned`](crate::convert_owned) allocates the destination.
+//! In-place conversions reuse the `ImgVec`'s `Vec`, growing or shrinking it
+//! when the pixel size changes.
 //!
 //! # Generic API
 //!
@@ -20,13 +23,20 @@
 //! let dst = ImgRefMut::new(&mut dst_buf, 2, 2);
 //! convert_imgref(src.as_ref(), dst).unwrap();
 //! ```
+//!
+//! Operations that don't change the pixel type (alpha fill, byte swaps,
+//! premultiply) and formats without an `rgb` pixel type (RGB565, RGBA4444,
+//! 1010102 — stored as `[u8; N]` per pixel) are named functions instead.
 
 use alloc::vec::Vec;
 
 use imgref::{ImgRef, ImgRefMut, ImgVec};
-use rgb::{Bgr, Bgra, Gray, GrayAlpha, Rgb, Rgba};
+use rgb::{Abgr, Argb, Bgr, Bgra, Gray, GrayAlpha, Rgb, Rgba};
+
+use bytemuck::Pod;
 
-use crate::{ConvertImage, ConvertImageInplace, SizeError};
+use crate::bytes::{Executor, par_bands};
+use crate::{ConvertImage, ConvertImageInplace, Operand, SizeError, SizeErrorKind};
 
 // ---------------------------------------------------------------------------
 // Dimension check
@@ -34,7 +44,9 @@ use crate::{ConvertImage, ConvertImageInplace, SizeError};
 
 fn check_dims(sw: usize, sh: usize, dw: usize, dh: usize) -> Result<(), SizeError> {
     if sw != dw || sh != dh {
-        Err(SizeError::PixelCountMismatch)
+        Err(SizeError::new(SizeErrorKind::PixelCountMismatch)
+            .of(Operand::Dst)
+            .lengths(Some(sw * sh), dw * dh))
     } else {
         Ok(())
     }
@@ -60,6 +72,28 @@ macro_rules! impl_convert_image {
                 }
                 Ok(())
             }
+
+            fn convert_image_with<E: Executor>(
+                src: ImgRef<'_, Self>,
+                mut dst: ImgRefMut<'_, $dst>,
+                exec: E,
+            ) -> Result<(), SizeError> {
+                check_dims(src.width(), src.height(), dst.width(), dst.height())?;
+                if src.width() == 0 || src.height() == 0 {
+                    return Self::convert_image(src, dst);
+                }
+                let (sb, db) = (size_of::<$src>(), size_of::<$dst>());
+                let (ss, ds) = (src.stride() * sb, dst.stride() * db);
+                let (sw, dw) = (src.width() * sb, dst.width() * db);
+                let s: &[u8] = bytemuck::cast_slice(src.buf());
+                let d: &mut [u8] = bytemuck::cast_slice_mut(dst.buf_mut());
+                par_bands(s, d, src.height(), ss, ds, exec, |_, s, d, rows| {
+                    for y in 0..rows {
+                        $bytes_fn(&s[y * ss..][..sw], &mut d[y * ds..][..dw])?;
+                    }
+                    Ok(())
+                })
+            }
         }
     };
 }
@@ -88,12 +122,118 @@ macro_rules! impl_convert_image_inplace {
     };
 }
 
+/// Named in-place operation on an image whose pixel type doesn't change.
+macro_rules! imgref_inplace_fn {
+    ($(#[$doc:meta])* $name:ident, $px:ty, $bytes_fn:path) => {
+        $(#[$doc])*
+        ///
+        /// Empty rows (width == 0) are skipped without panicking.
+        pub fn $name(mut img: ImgRefMut<'_, $px>) {
+            for row in img.rows_mut() {
+                let bytes: &mut [u8] = bytemuck::cast_slice_mut(row);
+                if !bytes.is_empty() {
+                    $bytes_fn(bytes).expect("row is always valid");
+                }
+            }
+        }
+    };
+}
+
+/// Named copy conversion, for pairs a `ConvertImage` impl can't express
+/// (same pixel types, or formats stored as plain byte arrays).
+macro_rules! imgref_copy_fn {
+    ($(#[$doc:meta])* $name:ident, $src:ty, $dst:ty, $bytes_fn:path) => {
+        $(#[$doc])*
+        pub fn $name(src: ImgRef<'_, $src>, mut dst: ImgRefMut<'_, $dst>) -> Result<(), SizeError> {
+            check_dims(src.width(), src.height(), dst.width(), dst.height())?;
+            for (s, d) in src.rows().zip(dst.rows_mut()) {
+                $bytes_fn(bytemuck::cast_slice(s), bytemuck::cast_slice_mut(d))?;
+            }
+            Ok(())
+        }
+    };
+}
+
 // ===========================================================================
 // In-place 4bpp swaps
 // ===========================================================================
 
 impl_convert_image_inplace!(Rgba<u8>, Bgra<u8>, crate::bytes::rgba_to_bgra_inplace);
 impl_convert_image_inplace!(Bgra<u8>, Rgba<u8>, crate::bytes::bgra_to_rgba_inplace);
+impl_convert_image_inplace!(Argb<u8>, Rgba<u8>, crate::bytes::argb_to_rgba_inplace);
+impl_convert_image_inplace!(Abgr<u8>, Bgra<u8>, crate::bytes::abgr_to_bgra_inplace);
+impl_convert_image_inplace!(Rgba<u8>, Argb<u8>, crate::bytes::rgba_to_argb_inplace);
+impl_convert_image_inplace!(Bgra<u8>, Abgr<u8>, crate::bytes::bgra_to_abgr_inplace);
+impl_convert_image_inplace!(Argb<u8>, Bgra<u8>, crate::bytes::argb_to_bgra_inplace);
+impl_convert_image_inplace!(Bgra<u8>, Argb<u8>, crate::bytes::bgra_to_argb_inplace);
+impl_convert_image_inplace!(Abgr<u8>, Rgba<u8>, crate::bytes::abgr_to_rgba_inplace);
+impl_convert_image_inplace!(Rgba<u8>, Abgr<u8>, crate::bytes::rgba_to_abgr_inplace);
+
+// ===========================================================================
+// In-place 3bpp swaps
+// ===========================================================================
+
+impl_convert_image_inplace!(Rgb<u8>, Bgr<u8>, crate::bytes::rgb_to_bgr_inplace);
+impl_convert_image_inplace!(Bgr<u8>, Rgb<u8>, crate::bytes::bgr_to_rgb_inplace);
+
+// ===========================================================================
+// In-place bpp changes (the Vec grows or shrinks)
+// ===========================================================================
+
+/// Rows of `img` packed to `width` pixels, as bytes.
+fn contiguous_bytes<T: Pod>(img: ImgVec<T>) -> (Vec<u8>, usize, usize) {
+    if img.width() == 0 || img.height() == 0 {
+        return (Vec::new(), img.width(), img.height());
+    }
+    let (buf, w, h) = img.into_contiguous_buf();
+    (bytemuck::allocation::cast_vec(buf), w, h)
+}
+
+/// Reinterpret `bytes` as pixels, reusing the allocation when its capacity
+/// is a whole number of pixels (copying otherwise).
+fn into_pixels<T: Pod>(mut bytes: Vec<u8>) -> Vec<T> {
+    if !bytes.capacity().is_multiple_of(size_of::<T>()) {
+        bytes.shrink_to_fit();
+    }
+    bytemuck::allocation::try_cast_vec(bytes)
+        .unwrap_or_else(|(_, bytes)| bytemuck::allocation::pod_collect_to_vec(&bytes))
+}
+
+fn shrink_4_to_3<S: Pod, D: Pod>(img: ImgVec<S>) -> ImgVec<D> {
+    let (mut bytes, w, h) = contiguous_bytes(img);
+    if !bytes.is_empty() {
+        crate::bytes::rgba_to_rgb_inplace(&mut bytes).expect("image is always valid");
+    }
+    bytes.truncate(w * h * 3);
+    ImgVec::new_stride(into_pixels(bytes), w, h, w.max(1))
+}
+
+fn grow_3_to_4<S: Pod, D: Pod>(img: ImgVec<S>) -> ImgVec<D> {
+    let (mut bytes, w, h) = contiguous_bytes(img);
+    if !bytes.is_empty() {
+        bytes.resize(w * h * 4, 0);
+        crate::bytes::rgb_to_rgba_inplace(&mut bytes, w * h).expect("resized to fit");
+    }
+    ImgVec::new_stride(into_pixels(bytes), w, h, w.max(1))
+}
+
+/// Implement ConvertImageInplace for a pair whose pixel size differs.
+/// The result is contiguous (`stride == width`).
+macro_rules! impl_convert_image_resize {
+    ($src:ty, $dst:ty, $f:ident) => {
+        impl ConvertImageInplace<$dst> for $src {
+            fn convert_image_inplace(img: ImgVec<Self>) -> ImgVec<$dst> {
+                $f(img)
+            }
+        }
+    };
+}
+
+// Alpha is the last byte in both orders, so one kernel serves RGB and BGR.
+impl_convert_image_resize!(Rgba<u8>, Rgb<u8>, shrink_4_to_3);
+impl_convert_image_resize!(Bgra<u8>, Bgr<u8>, shrink_4_to_3);
+impl_convert_image_resize!(Rgb<u8>, Rgba<u8>, grow_3_to_4);
+impl_convert_image_resize!(Bgr<u8>, Bgra<u8>, grow_3_to_4);
 
 // ===========================================================================
 // Copy conversions
@@ -121,6 +261,73 @@ impl_convert_image!(Bgra<u8>, Bgr<u8>, crate::bytes::bgra_to_bgr);
 impl_convert_image!(Bgra<u8>, Rgb<u8>, crate::bytes::bgra_to_rgb);
 impl_convert_image!(Rgba<u8>, Bgr<u8>, crate::bytes::rgba_to_bgr);
 
+// 3bpp ↔ 3bpp
+impl_convert_image!(Rgb<u8>, Bgr<u8>, crate::bytes::rgb_to_bgr);
+impl_convert_image!(Bgr<u8>, Rgb<u8>, crate::bytes::bgr_to_rgb);
+
+// Alpha-first 4bpp ↔ 4bpp
+impl_convert_image!(Argb<u8>, Rgba<u8>, crate::bytes::argb_to_rgba);
+impl_convert_image!(Abgr<u8>, Bgra<u8>, crate::bytes::abgr_to_bgra);
+impl_convert_image!(Rgba<u8>, Argb<u8>, crate::bytes::rgba_to_argb);
+impl_convert_image!(Bgra<u8>, Abgr<u8>, crate::bytes::bgra_to_abgr);
+impl_convert_image!(Argb<u8>, Bgra<u8>, crate::bytes::argb_to_bgra);
+impl_convert_image!(Bgra<u8>, Argb<u8>, crate::bytes::bgra_to_argb);
+impl_convert_image!(Abgr<u8>, Rgba<u8>, crate::bytes::abgr_to_rgba);
+impl_convert_image!(Rgba<u8>, Abgr<u8>, crate::bytes::rgba_to_abgr);
+
+// → alpha-first 4bpp
+impl_convert_image!(Rgb<u8>, Argb<u8>, crate::bytes::rgb_to_argb);
+impl_convert_image!(Rgb<u8>, Abgr<u8>, crate::bytes::rgb_to_abgr);
+impl_convert_image!(Bgr<u8>, Argb<u8>, crate::bytes::bgr_to_argb);
+impl_convert_image!(Bgr<u8>, Abgr<u8>, crate::bytes::bgr_to_abgr);
+impl_convert_image!(Gray<u8>, Argb<u8>, crate::bytes::gray_to_argb);
+impl_convert_image!(Gray<u8>, Abgr<u8>, crate::bytes::gray_to_abgr);
+impl_convert_image!(GrayAlpha<u8>, Argb<u8>, crate::bytes::gray_alpha_to_argb);
+impl_convert_image!(GrayAlpha<u8>, Abgr<u8>, crate::bytes::gray_alpha_to_abgr);
+
+// Alpha-first 4bpp → 3bpp
+impl_convert_image!(Argb<u8>, Rgb<u8>, crate::bytes::argb_to_rgb);
+impl_convert_image!(Argb<u8>, Bgr<u8>, crate::bytes::argb_to_bgr);
+impl_convert_image!(Abgr<u8>, Rgb<u8>, crate::bytes::abgr_to_rgb);
+impl_convert_image!(Abgr<u8>, Bgr<u8>, crate::bytes::abgr_to_bgr);
+
+// ===========================================================================
+// Named operations (alpha fill, byte swaps)
+// ===========================================================================
+
+imgref_inplace_fn!(
+    /// Set alpha to 255 in an `Rgba<u8>` image.
+    fill_alpha_rgba, Rgba<u8>, crate::bytes::fill_alpha_rgba
+);
+imgref_inplace_fn!(
+    /// Set alpha to 255 in a `Bgra<u8>` image.
+    fill_alpha_bgra, Bgra<u8>, crate::bytes::fill_alpha_bgra
+);
+imgref_inplace_fn!(
+    /// Set alpha to 255 in an `Argb<u8>` image.
+    fill_alpha_argb, Argb<u8>, crate::bytes::fill_alpha_argb
+);
+imgref_inplace_fn!(
+    /// Set alpha to 255 in an `Abgr<u8>` image.
+    fill_alpha_abgr, Abgr<u8>, crate::bytes::fill_alpha_abgr
+);
+imgref_inplace_fn!(
+    /// Reverse the bytes of every `u16` sample in place (endianness swap).
+    swap_bytes_u16_inplace, u16, crate::bytes::swap_bytes_u16_inplace
+);
+imgref_inplace_fn!(
+    /// Reverse the bytes of every `u32` sample in place (endianness swap).
+    swap_bytes_u32_inplace, u32, crate::bytes::swap_bytes_u32_inplace
+);
+imgref_copy_fn!(
+    /// Copy `u16` samples with their bytes reversed (endianness swap).
+    swap_bytes_u16, u16, u16, crate::bytes::swap_bytes_u16
+);
+imgref_copy_fn!(
+    /// Copy `u32` samples with their bytes reversed (endianness swap).
+    swap_bytes_u32, u32, u32, crate::bytes::swap_bytes_u32
+);
+
 // ===========================================================================
 // Deprecated named functions
 // ===========================================================================
@@ -281,9 +488,131 @@ mod experimental_imgref {
     impl_convert_image!(Gray<u8>, GrayAlpha<u8>, crate::bytes::gray_to_gray_alpha);
     impl_convert_image!(GrayAlpha<u8>, Gray<u8>, crate::bytes::gray_alpha_to_gray);
 
+    impl_convert_image!(GrayAlpha<u8>, Bgr<u8>, crate::bytes::gray_alpha_to_bgr);
+
     // Identity gray
     impl_convert_image!(Rgb<u8>, Gray<u8>, crate::bytes::rgb_to_gray_identity);
     impl_convert_image!(Rgba<u8>, Gray<u8>, crate::bytes::rgba_to_gray_identity);
+    impl_convert_image!(Bgr<u8>, Gray<u8>, crate::bytes::bgr_to_gray_identity);
+    impl_convert_image!(Bgra<u8>, Gray<u8>, crate::bytes::bgra_to_gray_identity);
+
+    // Depth: u8 ↔ u16 ↔ f32 for every layout (channel order unchanged)
+    macro_rules! impl_depth {
+        ($($px:ident),*) => {
+            $(
+                impl_convert_image!($px<u8>, $px<u16>, crate::bytes::convert_u8_to_u16);
+                impl_convert_image!($px<u16>, $px<u8>, crate::bytes::convert_u16_to_u8);
+                impl_convert_image!($px<u8>, $px<f32>, crate::bytes::convert_u8_to_f32);
+                impl_convert_image!($px<f32>, $px<u8>, crate::bytes::convert_f32_to_u8);
+                impl_convert_image!($px<u16>, $px<f32>, crate::bytes::convert_u16_to_f32);
+                impl_convert_image!($px<f32>, $px<u16>, crate::bytes::convert_f32_to_u16);
+            )*
+        };
+    }
+    impl_depth!(Rgba, Bgra, Argb, Abgr, Rgb, Bgr, Gray, GrayAlpha);
+
+    // -----------------------------------------------------------------------
+    // Big-endian samples (images of `[u8; 2]`, one element per sample)
+    // -----------------------------------------------------------------------
+
+    imgref_copy_fn!(
+        /// `u8` samples → big-endian `u16` samples.
+        convert_u8_to_u16be, u8, [u8; 2], crate::bytes::convert_u8_to_u16be
+    );
+    imgref_copy_fn!(
+        /// Big-endian `u16` samples → `u8` samples.
+        convert_u16be_to_u8, [u8; 2], u8, crate::bytes::convert_u16be_to_u8
+    );
+    imgref_copy_fn!(
+        /// Big-endian `u16` samples → `f32` samples.
+        convert_u16be_to_f32, [u8; 2], f32, crate::bytes::convert_u16be_to_f32
+    );
+    imgref_copy_fn!(
+        /// `f32` samples → big-endian `u16` samples.
+        convert_f32_to_u16be, f32, [u8; 2], crate::bytes::convert_f32_to_u16be
+    );
+
+    // -----------------------------------------------------------------------
+    // Packed formats (one `[u8; N]` element per pixel)
+    // -----------------------------------------------------------------------
+
+    macro_rules! packed_imgref {
+        ($($fmt:ident, $n:literal, $name:literal;)*) => {
+            paste::paste! {
+                $(
+                    imgref_copy_fn!(
+                        #[doc = concat!("`Rgba<u8>` → ", $name, ".")]
+                        [<convert_rgba_to_ $fmt>], Rgba<u8>, [u8; $n], crate::bytes::[<rgba_to_ $fmt>]
+                    );
+                    imgref_copy_fn!(
+                        #[doc = concat!("`Bgra<u8>` → ", $name, ".")]
+                        [<convert_bgra_to_ $fmt>], Bgra<u8>, [u8; $n], crate::bytes::[<bgra_to_ $fmt>]
+                    );
+                    imgref_copy_fn!(
+                        #[doc = concat!($name, " → `Rgba<u8>`.")]
+                        [<convert_ $fmt _to_rgba>], [u8; $n], Rgba<u8>, crate::bytes::[<$fmt _to_rgba>]
+                    );
+                    imgref_copy_fn!(
+                        #[doc = concat!($name, " → `Bgra<u8>`.")]
+                        [<convert_ $fmt _to_bgra>], [u8; $n], Bgra<u8>, crate::bytes::[<$fmt _to_bgra>]
+                    );
+                )*
+            }
+        };
+    }
+
+    packed_imgref!(
+        rgb565, 2, "RGB565 (little-endian)";
+        rgb565be, 2, "RGB565 (big-endian)";
+        rgba4444, 2, "RGBA4444 (little-endian)";
+        rgba4444be, 2, "RGBA4444 (big-endian)";
+    );
+
+    imgref_copy_fn!(
+        /// RGBA 10:10:10:2 (little-endian) → `Rgba<u16>` (10-bit values).
+        convert_rgba1010102_to_rgba16, [u8; 4], Rgba<u16>, crate::bytes::rgba1010102_to_rgba16
+    );
+    imgref_copy_fn!(
+        /// `Rgba<u16>` (10-bit values) → RGBA 10:10:10:2 (little-endian).
+        convert_rgba16_to_rgba1010102, Rgba<u16>, [u8; 4], crate::bytes::rgba16_to_rgba1010102
+    );
+    imgref_copy_fn!(
+        /// RGBA 10:10:10:2 (big-endian) → `Rgba<u16>` (10-bit values).
+        convert_rgba1010102be_to_rgba16, [u8; 4], Rgba<u16>, crate::bytes::rgba1010102be_to_rgba16
+    );
+    imgref_copy_fn!(
+        /// `Rgba<u16>` (10-bit values) → RGBA 10:10:10:2 (big-endian).
+        convert_rgba16_to_rgba1010102be, Rgba<u16>, [u8; 4], crate::bytes::rgba16_to_rgba1010102be
+    );
+
+    // -----------------------------------------------------------------------
+    // u8 alpha premultiplication and f32 copies
+    // -----------------------------------------------------------------------
+
+    imgref_inplace_fn!(
+        /// Premultiply alpha for an `Rgba<u8>` image in-place.
+        premultiply_rgba_u8, Rgba<u8>, crate::bytes::premultiply_alpha_rgba_u8
+    );
+    imgref_inplace_fn!(
+        /// Premultiply alpha for a `Bgra<u8>` image in-place.
+        premultiply_bgra_u8, Bgra<u8>, crate::bytes::premultiply_alpha_bgra_u8
+    );
+    imgref_copy_fn!(
+        /// Premultiply alpha, copying an `Rgba<u8>` image.
+        premultiply_rgba_u8_copy, Rgba<u8>, Rgba<u8>, crate::bytes::premultiply_alpha_rgba_u8_copy
+    );
+    imgref_copy_fn!(
+        /// Premultiply alpha, copying a `Bgra<u8>` image.
+        premultiply_bgra_u8_copy, Bgra<u8>, Bgra<u8>, crate::bytes::premultiply_alpha_bgra_u8_copy
+    );
+    imgref_copy_fn!(
+        /// Premultiply alpha, copying an `Rgba<f32>` image.
+        premultiply_rgba_f32_copy, Rgba<f32>, Rgba<f32>, crate::bytes::premultiply_alpha_f32_copy
+    );
+    imgref_copy_fn!(
+        /// Unpremultiply alpha, copying an `Rgba<f32>` image.
+        unpremultiply_rgba_f32_copy, Rgba<f32>, Rgba<f32>, crate::bytes::unpremultiply_alpha_f32_copy
+    );
 
     // -----------------------------------------------------------------------
     // Deprecated named functions
@@ -553,15 +882,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_convert_imgref_with_executor() {
+        use crate::bytes::{Band, Executor};
+
+        struct Reverse;
+        impl Executor for Reverse {
+            fn max_bands(&self) -> usize {
+                7
+            }
+            fn run_bands(&self, bands: &mut [Band<'_>]) {
+                bands.iter_mut().rev().for_each(Band::run);
+            }
+        }
+
+        // Sub-image with padding, large enough to split into bands.
+        let (w, h, stride) = (600, 800, 613);
+        let buf: vec::Vec<Rgb<u8>> = (0..stride * h)
+            .map(|i| Rgb::new(i as u8, (i >> 8) as u8, (i >> 16) as u8))
+            .collect();
+        let src = ImgVec::new_stride(buf, stride, h, stride);
+        let src = src.sub_image(5, 0, w, h);
+        let mut seq = vec![Bgra::default(); w * h];
+        let mut par = vec![Bgra::default(); w * h];
+        crate::convert_imgref(src, ImgRefMut::new(&mut seq, w, h)).unwrap();
+        crate::convert_imgref_with(src, ImgRefMut::new(&mut par, w, h), Reverse).unwrap();
+        assert!(seq == par);
+    }
+
     #[test]
     fn test_dimension_mismatch() {
         let src = ImgVec::new(vec![Rgba::new(1u8, 2, 3, 4); 4], 2, 2);
         let mut dst_buf = vec![Bgra::default(); 6];
         let dst = ImgRefMut::new(&mut dst_buf, 3, 2);
-        assert_eq!(
-            crate::convert_imgref(src.as_ref(), dst),
-            Err(crate::SizeError::PixelCountMismatch)
-        );
+        let err = crate::convert_imgref(src.as_ref(), dst).unwrap_err();
+        assert_eq!(err.kind(), crate::SizeErrorKind::PixelCountMismatch);
+        assert_eq!(err.operand(), Some(crate::Operand::Dst));
+        assert_eq!((err.required(), err.actual()), (Some(4), Some(6)));
     }
 
     #[test]
@@ -586,6 +943,114 @@ mod tests {
         assert_eq!(img.height(), 0);
     }
 
+    #[test]
+    fn test_inplace_bpp_change_resizes_vec() {
+        // 2×2 RGBA with stride 3 (one padding pixel per row).
+        let mut buf = vec![Rgba::new(0u8, 0, 0, 0); 5];
+        buf[0] = Rgba::new(1, 2, 3, 4);
+        buf[1] = Rgba::new(5, 6, 7, 8);
+        buf[3] = Rgba::new(9, 10, 11, 12);
+        buf[4] = Rgba::new(13, 14, 15, 16);
+        let img = ImgVec::new_stride(buf, 2, 2, 3);
+        let rgb: ImgVec<Rgb<u8>> = crate::convert_imgref_inplace(img);
+        assert_eq!((rgb.width(), rgb.height(), rgb.stride()), (2, 2, 2));
+        assert_eq!(
+            rgb.buf(),
+            &[
+                Rgb::new(1, 2, 3),
+                Rgb::new(5, 6, 7),
+                Rgb::new(9, 10, 11),
+                Rgb::new(13, 14, 15)
+            ]
+        );
+
+        let rgba: ImgVec<Rgba<u8>> = crate::convert_imgref_inplace(rgb);
+        assert_eq!(rgba.buf().len(), 4);
+        assert_eq!(rgba.buf()[3], Rgba::new(13, 14, 15, 255));
+
+        let empty = ImgVec::new_stride(alloc::vec::Vec::<Rgb<u8>>::new(), 0, 3, 1);
+        let empty: ImgVec<Rgba<u8>> = crate::convert_imgref_inplace(empty);
+        assert_eq!((empty.width(), empty.height()), (0, 3));
+    }
+
+    #[test]
+    fn test_convert_owned() {
+        let src = ImgVec::new_stride(vec![Gray::new(7u8); 5], 2, 2, 3);
+        let dst = crate::convert_owned::<Bgra<u8>>(src.as_ref());
+        assert_eq!((dst.width(), dst.height(), dst.stride()), (2, 2, 2));
+        assert_eq!(
+            dst.buf(),
+            &[Bgra {
+                b: 7,
+                g: 7,
+                r: 7,
+                a: 255
+            }; 4]
+        );
+
+        let empty = ImgVec::new_stride(alloc::vec::Vec::<Rgb<u8>>::new(), 0, 0, 1);
+        assert!(
+            crate::convert_owned::<Rgba<u8>>(empty.as_ref())
+                .buf()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_alpha_first_layouts() {
+        use rgb::{Abgr, Argb, Bgr};
+
+        let argb = ImgVec::new(vec![Argb::new_argb(4u8, 1, 2, 3); 4], 2, 2);
+        let mut bgr = vec![Bgr::default(); 4];
+        crate::convert_imgref(argb.as_ref(), ImgRefMut::new(&mut bgr, 2, 2)).unwrap();
+        assert_eq!(bgr[3], Bgr { b: 3, g: 2, r: 1 });
+
+        let rgba: ImgVec<Rgba<u8>> = crate::convert_imgref_inplace(argb);
+        assert_eq!(rgba.buf()[0], Rgba::new(1, 2, 3, 4));
+
+        let rgb = ImgVec::new(vec![Rgb::new(1u8, 2, 3); 4], 2, 2);
+        let mut abgr = vec![Abgr::default(); 4];
+        crate::convert_imgref(rgb.as_ref(), ImgRefMut::new(&mut abgr, 2, 2)).unwrap();
+        assert_eq!(abgr[0], Abgr::new_abgr(255, 3, 2, 1));
+    }
+
+    #[test]
+    fn test_named_ops_skip_padding() {
+        use rgb::Argb;
+
+        // 1×2 image with stride 2: the padding pixel must stay untouched.
+        let mut buf = vec![Argb::new_argb(0u8, 1, 2, 3); 4];
+        super::fill_alpha_argb(ImgRefMut::new_stride(&mut buf, 1, 2, 2));
+        assert_eq!(buf[0].a, 255);
+        assert_eq!(buf[1].a, 0);
+        assert_eq!(buf[2].a, 255);
+
+        let mut samples = vec![0x1234u16, 0xABCD];
+        super::swap_bytes_u16_inplace(ImgRefMut::new(&mut samples, 2, 1));
+        assert_eq!(samples, [0x3412, 0xCDAB]);
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn test_depth_and_packed() {
+        let src = ImgVec::new(vec![Rgb::new(0u8, 128, 255); 4], 2, 2);
+        let mut wide = vec![Rgb::<u16>::default(); 4];
+        crate::convert_imgref(src.as_ref(), ImgRefMut::new(&mut wide, 2, 2)).unwrap();
+        assert_eq!(wide[0], Rgb::new(0, 128 * 257, 65535));
+
+        let rgba = ImgVec::new(vec![Rgba::new(255u8, 0, 255, 255); 4], 2, 2);
+        let mut packed = vec![[0u8; 2]; 4];
+        super::convert_rgba_to_rgb565(rgba.as_ref(), ImgRefMut::new(&mut packed, 2, 2)).unwrap();
+        assert_eq!(packed[0], 0xF81Fu16.to_le_bytes());
+        let mut back = vec![Rgba::default(); 4];
+        super::convert_rgb565_to_rgba(
+            ImgRefMut::new(&mut packed, 2, 2).as_ref(),
+            ImgRefMut::new(&mut back, 2, 2),
+        )
+        .unwrap();
+        assert_eq!(back, rgba.buf()[..]);
+    }
+
     #[allow(deprecated)]
     #[test]
     fn test_deprecated_fns_still_work() {
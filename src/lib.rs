@@ -12,6 +12,8 @@
 //! ## Modules
 //!
 //! - [`bytes`] — Core `&[u8]` conversions (contiguous and strided).
+//! - [`fourcc`] — DRM / V4L2 pixel-format codes and conversions between them.
+//! - [`ffmpeg`] — FFmpeg `AVPixelFormat` names and conversions between them.
 //! - [`typed_rgb`] — Type-safe wrappers using `rgb` crate pixel types (feature `rgb`).
 //! - [`imgref`] — Whole-image conversions on `ImgVec` / `ImgRef` (feature `imgref`).
 //!
@@ -43,24 +45,48 @@
 //! - In-place: `(buf, width, height, stride)`
 //! - Copy: `(src, dst, width, height, src_stride, dst_stride)`
 //!
+//! Bottom-up buffers with a negative row pitch (GDI DIBs, Direct3D mapped
+//! resources, FFmpeg frames) use the `_strided_signed` variants, which take a
+//! [`bytes::SignedStride`] (base offset + `isize` stride) per buffer.
+//!
 //! ## Feature flags
 //!
-//! - **`std`** — Enables `std` on dependencies (e.g. `archmage`).
+//! - **`std`** — Enables `std` on dependencies (e.g. `archmage`). Implies
+//!   `alloc`.
+//! - **`alloc`** — `Vec`-based helpers (e.g. [`typed_rgb::rgb_to_rgba_vec`]).
 //! - **`experimental`** — Gray layout, weighted luma, depth conversion, f32
 //!   alpha premultiply/unpremultiply. API may change between minor versions.
 //! - **`rgb`** — Type-safe conversions using [`rgb`] crate pixel types
 //!   via bytemuck. Zero-copy in-place swaps return reinterpreted references.
 //! - **`imgref`** — Multi-row conversions using `ImgRef` / `ImgRefMut`
-//!   from the [`imgref`](https://docs.rs/imgref) crate. No allocation — caller owns all buffers.
+//!   from the [`imgref`](https://docs.rs/imgref) crate. Implies `rgb` and `alloc`.
+//! - **`image`** — [`image`] crate `ImageBuffer` / `DynamicImage` to and
+//!   from 8-bit RGBA/BGRA, and between `ImageBuffer` pixel types. Implies
+//!   `std`.
+//! - **`ndarray`** — `ImgRef<Rgb<u8>>` to and from NCHW / NHWC `f32`
+//!   [`ndarray`] views. Implies `imgref` and `experimental`.
+//! - **`wgpu`** — `wgpu::TextureFormat` mapping for
+//!   [`bytes::TextureLayout`] via `wgpu-types`.
+//! - **`parallel`** — [`bytes::Parallelism`], a thread-based
+//!   [`bytes::Executor`] for the `par_…` row-band conversions
+//!   (`std::thread::scope`). Implies `std`. Without it, bring your own
+//!   executor.
 
 #![no_std]
 #![forbid(unsafe_code)]
 
-#[cfg(feature = "imgref")]
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "parallel")]
+extern crate std;
+
 pub mod bytes;
 
+pub mod fourcc;
+
+pub mod ffmpeg;
+
 #[cfg(feature = "experimental")]
 pub mod deinterleave;
 
@@ -70,13 +96,42 @@ pub mod typed_rgb;
 #[cfg(feature = "imgref")]
 pub mod imgref;
 
+#[cfg(feature = "image")]
+pub mod image;
+
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+
 /// Pixel buffer size or alignment error.
 ///
 /// Returned when a buffer's length is not a multiple of the pixel size,
 /// a destination buffer is too small, or stride/dimensions are inconsistent.
+/// [`kind`](Self::kind) gives the category; the other accessors report which
+/// buffer failed and the sizes behind it, when the check knows them.
+///
+/// ```rust
+/// use garb::{Operand, SizeErrorKind};
+///
+/// let err = garb::bytes::rgb_to_bgra_strided(&[0; 600], &mut [0; 700], 60, 3, 200, 240).unwrap_err();
+/// assert_eq!(err.kind(), SizeErrorKind::InvalidStride);
+/// assert_eq!(err.operand(), Some(Operand::Dst));
+/// assert_eq!((err.required(), err.actual()), (Some(720), Some(700)));
+/// assert_eq!((err.row_bytes(), err.stride()), (Some(240), Some(240)));
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeError {
+    kind: SizeErrorKind,
+    operand: Option<Operand>,
+    required: Option<usize>,
+    actual: Option<usize>,
+    row_bytes: Option<usize>,
+    stride: Option<usize>,
+}
+
+/// Category of a [`SizeError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
-pub enum SizeError {
+pub enum SizeErrorKind {
     /// Buffer length is not a multiple of the expected bytes per pixel (or is empty).
     NotPixelAligned,
     /// Destination buffer has fewer pixels than the source.
@@ -85,7 +140,88 @@ pub enum SizeError {
     InvalidStride,
 }
 
-impl core::fmt::Display for SizeError {
+/// Buffer argument a [`SizeError`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Operand {
+    /// The source buffer.
+    Src,
+    /// The destination buffer.
+    Dst,
+    /// The buffer of an in-place conversion.
+    Buf,
+    /// The error-row scratch buffer of a Floyd–Steinberg conversion.
+    Scratch,
+}
+
+impl SizeError {
+    /// Error of `kind` with no context.
+    pub const fn new(kind: SizeErrorKind) -> Self {
+        Self {
+            kind,
+            operand: None,
+            required: None,
+            actual: None,
+            row_bytes: None,
+            stride: None,
+        }
+    }
+
+    /// Category.
+    pub const fn kind(&self) -> SizeErrorKind {
+        self.kind
+    }
+
+    /// Which buffer failed the check.
+    pub const fn operand(&self) -> Option<Operand> {
+        self.operand
+    }
+
+    /// Length the buffer needed, in elements of its slice (bytes for `&[u8]`).
+    pub const fn required(&self) -> Option<usize> {
+        self.required
+    }
+
+    /// Length the buffer had.
+    pub const fn actual(&self) -> Option<usize> {
+        self.actual
+    }
+
+    /// Computed row length (`width × bytes per pixel`).
+    pub const fn row_bytes(&self) -> Option<usize> {
+        self.row_bytes
+    }
+
+    /// Stride the row length was checked against.
+    pub const fn stride(&self) -> Option<usize> {
+        self.stride
+    }
+
+    pub(crate) const fn of(mut self, operand: Operand) -> Self {
+        self.operand = Some(operand);
+        self
+    }
+
+    pub(crate) const fn lengths(mut self, required: Option<usize>, actual: usize) -> Self {
+        self.required = required;
+        self.actual = Some(actual);
+        self
+    }
+
+    pub(crate) const fn row(mut self, row_bytes: usize, stride: usize) -> Self {
+        self.row_bytes = Some(row_bytes);
+        self.stride = Some(stride);
+        self
+    }
+}
+
+impl From<SizeErrorKind> for SizeError {
+    fn from(kind: SizeErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl core::fmt::Display for SizeErrorKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::NotPixelAligned => f.write_str("buffer length is not pixel-aligned"),
@@ -97,6 +233,28 @@ impl core::fmt::Display for SizeError {
     }
 }
 
+impl core::fmt::Display for SizeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.operand {
+            Some(Operand::Src) => f.write_str("src: ")?,
+            Some(Operand::Dst) => f.write_str("dst: ")?,
+            Some(Operand::Buf) => f.write_str("buf: ")?,
+            Some(Operand::Scratch) => f.write_str("scratch: ")?,
+            None => {}
+        }
+        core::fmt::Display::fmt(&self.kind, f)?;
+        match (self.required, self.actual) {
+            (Some(required), Some(actual)) => write!(f, " (needs {required}, has {actual})")?,
+            (None, Some(actual)) => write!(f, " (length {actual})")?,
+            _ => {}
+        }
+        if let (Some(row), Some(stride)) = (self.row_bytes, self.stride) {
+            write!(f, " (row {row} bytes, stride {stride})")?;
+        }
+        Ok(())
+    }
+}
+
 impl core::error::Error for SizeError {}
 
 // ===========================================================================
@@ -167,11 +325,25 @@ pub trait ConvertImage<Dst>: Sized {
         src: ::imgref::ImgRef<'_, Self>,
         dst: ::imgref::ImgRefMut<'_, Dst>,
     ) -> Result<(), SizeError>;
+
+    /// Like [`convert_image`](Self::convert_image), split into row bands
+    /// scheduled by `exec`. The default ignores `exec` and runs sequentially.
+    fn convert_image_with<E: bytes::Executor>(
+        src: ::imgref::ImgRef<'_, Self>,
+        dst: ::imgref::ImgRefMut<'_, Dst>,
+        exec: E,
+    ) -> Result<(), SizeError> {
+        let _ = exec;
+        Self::convert_image(src, dst)
+    }
 }
 
 /// In-place image conversion: consumes `ImgVec<Src>`, returns `ImgVec<Dst>`.
 ///
-/// Only implemented for same-size pixel pairs.
+/// Same-size pairs reinterpret the buffer and keep the stride. RGBA ↔ RGB
+/// and BGRA ↔ BGR pack the rows, convert, and shrink or grow the `Vec`
+/// (reallocating only if growing exceeds its capacity); the result has
+/// `stride == width`.
 /// Use [`convert_imgref_inplace`] for the free-function form.
 #[cfg(feature = "imgref")]
 pub trait ConvertImageInplace<Dst>: Sized {
@@ -200,6 +372,81 @@ pub fn convert_imgref<S: ConvertImage<D>, D>(
     S::convert_image(src, dst)
 }
 
+/// Copy-convert an image in row bands scheduled by `exec`.
+///
+/// See [`bytes::Executor`]. Small images run on the calling thread.
+#[cfg(feature = "imgref")]
+#[inline(always)]
+pub fn convert_imgref_with<S: ConvertImage<D>, D, E: bytes::Executor>(
+    src: ::imgref::ImgRef<'_, S>,
+    dst: ::imgref::ImgRefMut<'_, D>,
+    exec: E,
+) -> Result<(), SizeError> {
+    S::convert_image_with(src, dst, exec)
+}
+
+/// Copy-convert an image into a newly allocated one.
+///
+/// The destination has `stride == width`.
+///
+/// ```rust
+/// use rgb::{Rgb, Bgra};
+/// use imgref::ImgVec;
+/// use garb::convert_owned;
+///
+/// let src = ImgVec::new(vec![Rgb::new(255u8, 0, 128); 4], 2, 2);
+/// let bgra = convert_owned::<Bgra<u8>>(src.as_ref());
+/// assert_eq!(bgra.buf()[0], Bgra { b: 128, g: 0, r: 255, a: 255 });
+/// ```
+#[cfg(feature = "imgref")]
+pub fn convert_owned<D: Clone + Default>(
+    src: ::imgref::ImgRef<'_, impl ConvertImage<D>>,
+) -> ::imgref::ImgVec<D> {
+    let (w, h) = (src.width(), src.height());
+    let mut dst = ::imgref::ImgVec::new_stride(alloc::vec![D::default(); w * h], w, h, w.max(1));
+    if w > 0 && h > 0 {
+        ConvertImage::convert_image(src, dst.as_mut()).expect("destination sized to match");
+    }
+    dst
+}
+
+/// Copy-convert an image, flipping it vertically: destination row `y` comes
+/// from source row `height - 1 - y`.
+///
+/// `imgref` strides are unsigned, so a bottom-up buffer (BMP/DIB, negative
+/// row pitch) is viewed as an upside-down `ImgRef`; this writes it upright.
+/// For raw byte buffers see [`bytes::SignedStride`].
+///
+/// ```rust
+/// use rgb::{Bgra, Rgba};
+/// use imgref::{ImgVec, ImgRefMut};
+/// use garb::convert_imgref_flip_y;
+///
+/// let bottom_up = ImgVec::new(vec![Bgra { b: 1, g: 2, r: 3, a: 4 }, Bgra { b: 5, g: 6, r: 7, a: 8 }], 1, 2);
+/// let mut buf = vec![Rgba::default(); 2];
+/// convert_imgref_flip_y(bottom_up.as_ref(), ImgRefMut::new(&mut buf, 1, 2)).unwrap();
+/// assert_eq!(buf, [Rgba::new(7, 6, 5, 8), Rgba::new(3, 2, 1, 4)]);
+/// ```
+#[cfg(feature = "imgref")]
+pub fn convert_imgref_flip_y<S: ConvertImage<D>, D>(
+    src: ::imgref::ImgRef<'_, S>,
+    mut dst: ::imgref::ImgRefMut<'_, D>,
+) -> Result<(), SizeError> {
+    let (w, h) = (src.width(), src.height());
+    if (w, h) != (dst.width(), dst.height()) {
+        return Err(SizeError::new(SizeErrorKind::PixelCountMismatch)
+            .of(Operand::Dst)
+            .lengths(Some(w * h), dst.width() * dst.height()));
+    }
+    for y in 0..h {
+        S::convert_image(
+            src.sub_image(0, h - 1 - y, w, 1),
+            dst.sub_image_mut(0, y, w, 1),
+        )?;
+    }
+    Ok(())
+}
+
 /// In-place image conversion. Consumes and returns the image with reinterpreted pixels.
 ///
 /// ```rust
@@ -0,0 +1,232 @@
+//! `ndarray` tensors for ML preprocessing.
+//!
+//! [`rgb_to_tensor`] writes an `ImgRef<Rgb<u8>>` into a 3-D `f32` view in
+//! [`TensorLayout::Nchw`] order (planes, shape `(3, height, width)`) or
+//! [`TensorLayout::Nhwc`] order (interleaved, shape `(height, width, 3)`).
+//! The view is one item of a batch; take it from a 4-D batch with
+//! `batch.index_axis_mut(Axis(0), n)`. [`tensor_to_rgb`] goes back.
+//!
+//! Values are not normalized: `0..=255` map to `0.0..=255.0`, as in
+//! [`deinterleave`](crate::deinterleave). Apply scale and mean with ndarray
+//! arithmetic afterwards.
+//!
+//! NCHW runs the SIMD deinterleave kernel per row, so each row of each plane
+//! must be contiguous; NHWC needs each row's `width × 3` values contiguous.
+//! Other strides (including negative ones) return `InvalidStride`, and a
+//! shape that doesn't match the image returns `PixelCountMismatch`.
+//!
+//! ```rust
+//! use ::ndarray::Array3;
+//! use imgref::ImgVec;
+//! use rgb::Rgb;
+//! use garb::ndarray::{rgb_to_tensor, TensorLayout};
+//!
+//! let img = ImgVec::new(vec![Rgb::new(255u8, 0, 128); 4 * 2], 4, 2);
+//! let mut chw = Array3::<f32>::zeros((3, 2, 4));
+//! rgb_to_tensor(img.as_ref(), chw.view_mut(), TensorLayout::Nchw)?;
+//! assert_eq!(chw[[2, 1, 3]], 128.0);
+//! # Ok::<(), garb::SizeError>(())
+//! ```
+
+use ::imgref::{ImgRef, ImgRefMut};
+use ::ndarray::{ArrayView3, ArrayViewMut3, Axis};
+use rgb::Rgb;
+
+use crate::{Operand, SizeError, SizeErrorKind};
+
+/// Axis order of a 3-D image tensor (one item of an N-batch).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TensorLayout {
+    /// Channel planes: shape `(3, height, width)`.
+    Nchw,
+    /// Interleaved channels: shape `(height, width, 3)`.
+    Nhwc,
+}
+
+impl TensorLayout {
+    /// Tensor shape for a `width × height` RGB image.
+    pub const fn shape(self, width: usize, height: usize) -> (usize, usize, usize) {
+        match self {
+            Self::Nchw => (3, height, width),
+            Self::Nhwc => (height, width, 3),
+        }
+    }
+}
+
+/// Check a tensor's shape against the image it is converted from or to
+/// (`tensor` says which). Errors are reported against the destination, with
+/// element counts.
+fn check_shape(
+    dim: (usize, usize, usize),
+    width: usize,
+    height: usize,
+    layout: TensorLayout,
+    tensor: Operand,
+) -> Result<(), SizeError> {
+    if dim == layout.shape(width, height) {
+        return Ok(());
+    }
+    let (image, tensor_len) = (width * height * 3, dim.0 * dim.1 * dim.2);
+    let (required, actual) = match tensor {
+        Operand::Dst => (image, tensor_len),
+        _ => (tensor_len, image),
+    };
+    Err(SizeError::new(SizeErrorKind::PixelCountMismatch)
+        .of(Operand::Dst)
+        .lengths(Some(required), actual))
+}
+
+/// 8-bit RGB image → `f32` tensor (values `0.0..=255.0`).
+pub fn rgb_to_tensor(
+    src: ImgRef<'_, Rgb<u8>>,
+    mut dst: ArrayViewMut3<'_, f32>,
+    layout: TensorLayout,
+) -> Result<(), SizeError> {
+    check_shape(dst.dim(), src.width(), src.height(), layout, Operand::Dst)?;
+    match layout {
+        TensorLayout::Nchw => {
+            let mut planes = dst.outer_iter_mut();
+            let (Some(mut r), Some(mut g), Some(mut b)) =
+                (planes.next(), planes.next(), planes.next())
+            else {
+                unreachable!("shape checked");
+            };
+            for (y, row) in src.rows().enumerate() {
+                let (Some(r), Some(g), Some(b)) = (
+                    r.index_axis_mut(Axis(0), y).into_slice(),
+                    g.index_axis_mut(Axis(0), y).into_slice(),
+                    b.index_axis_mut(Axis(0), y).into_slice(),
+                ) else {
+                    return Err(SizeError::new(SizeErrorKind::InvalidStride).of(Operand::Dst));
+                };
+                crate::deinterleave::rgb24_to_planes_f32(bytemuck::cast_slice(row), r, g, b)?;
+            }
+        }
+        TensorLayout::Nhwc => {
+            for (y, row) in src.rows().enumerate() {
+                let d = dst
+                    .index_axis_mut(Axis(0), y)
+                    .into_slice()
+                    .ok_or(SizeError::new(SizeErrorKind::InvalidStride).of(Operand::Dst))?;
+                let s: &[u8] = bytemuck::cast_slice(row);
+                for (d, &s) in d.iter_mut().zip(s) {
+                    *d = f32::from(s);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `f32` tensor → 8-bit RGB image, clamping to `0.0..=255.0` and rounding.
+pub fn tensor_to_rgb(
+    src: ArrayView3<'_, f32>,
+    mut dst: ImgRefMut<'_, Rgb<u8>>,
+    layout: TensorLayout,
+) -> Result<(), SizeError> {
+    check_shape(src.dim(), dst.width(), dst.height(), layout, Operand::Src)?;
+    let to_u8 = |v: f32| (v.clamp(0.0, 255.0) + 0.5) as u8;
+    // Row `y` of plane `c` (NCHW) or of the whole tensor (NHWC).
+    let row = |c: Option<usize>, y: usize| {
+        let t = match c {
+            Some(c) => src.index_axis_move(Axis(0), c).index_axis_move(Axis(0), y),
+            None => src
+                .index_axis_move(Axis(0), y)
+                .into_shape_with_order(src.dim().1 * 3)
+                .ok()?,
+        };
+        t.to_slice()
+    };
+    for (y, out) in dst.rows_mut().enumerate() {
+        match layout {
+            TensorLayout::Nchw => {
+                let (Some(r), Some(g), Some(b)) =
+                    (row(Some(0), y), row(Some(1), y), row(Some(2), y))
+                else {
+                    return Err(SizeError::new(SizeErrorKind::InvalidStride).of(Operand::Src));
+                };
+                for (x, px) in out.iter_mut().enumerate() {
+                    *px = Rgb::new(to_u8(r[x]), to_u8(g[x]), to_u8(b[x]));
+                }
+            }
+            TensorLayout::Nhwc => {
+                let s = row(None, y)
+                    .ok_or(SizeError::new(SizeErrorKind::InvalidStride).of(Operand::Src))?;
+                let d: &mut [u8] = bytemuck::cast_slice_mut(out);
+                for (d, &s) in d.iter_mut().zip(s) {
+                    *d = to_u8(s);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::vec;
+
+    use super::*;
+    use ::imgref::ImgVec;
+    use ::ndarray::{Array3, Array4, ShapeBuilder, Slice};
+
+    fn image() -> ImgVec<Rgb<u8>> {
+        let px: alloc::vec::Vec<_> = (0..15u8)
+            .map(|i| Rgb::new(i, i.wrapping_mul(17), 255 - i))
+            .collect();
+        ImgVec::new(px, 5, 3)
+    }
+
+    #[test]
+    fn test_roundtrip_both_layouts() {
+        let img = image();
+        for layout in [TensorLayout::Nchw, TensorLayout::Nhwc] {
+            let mut batch = Array4::<f32>::zeros({
+                let (a, b, c) = layout.shape(5, 3);
+                (2, a, b, c)
+            });
+            rgb_to_tensor(img.as_ref(), batch.index_axis_mut(Axis(0), 1), layout).unwrap();
+            let t = batch.index_axis(Axis(0), 1);
+            let (r, g, b) = match layout {
+                TensorLayout::Nchw => (t[[0, 2, 4]], t[[1, 2, 4]], t[[2, 2, 4]]),
+                TensorLayout::Nhwc => (t[[2, 4, 0]], t[[2, 4, 1]], t[[2, 4, 2]]),
+            };
+            assert_eq!((r, g, b), (14.0, f32::from(14u8.wrapping_mul(17)), 241.0));
+
+            let mut out = ImgVec::new(vec![Rgb::new(0u8, 0, 0); 15], 5, 3);
+            tensor_to_rgb(t, out.as_mut(), layout).unwrap();
+            assert_eq!(out.buf(), img.buf());
+        }
+    }
+
+    #[test]
+    fn test_shape_and_stride_errors() {
+        let img = image();
+        let mut wrong = Array3::<f32>::zeros((3, 5, 4));
+        let err = rgb_to_tensor(img.as_ref(), wrong.view_mut(), TensorLayout::Nchw).unwrap_err();
+        assert_eq!(err.kind(), SizeErrorKind::PixelCountMismatch);
+        assert_eq!(err.operand(), Some(Operand::Dst));
+        assert_eq!((err.required(), err.actual()), (Some(45), Some(60)));
+
+        // Column-major storage: rows are not contiguous.
+        let mut f = Array3::<f32>::zeros((3, 3, 5).f());
+        let err = rgb_to_tensor(img.as_ref(), f.view_mut(), TensorLayout::Nchw).unwrap_err();
+        assert_eq!(
+            (err.kind(), err.operand()),
+            (SizeErrorKind::InvalidStride, Some(Operand::Dst))
+        );
+
+        // Wider backing array sliced to the image: rows stay contiguous.
+        let mut wide = Array3::<f32>::zeros((3, 3, 8));
+        let view = wide.slice_axis_mut(Axis(2), Slice::from(1..6));
+        rgb_to_tensor(img.as_ref(), view, TensorLayout::Nchw).unwrap();
+        assert_eq!(wide[[2, 0, 1]], 255.0);
+        assert_eq!(wide[[2, 0, 0]], 0.0);
+
+        let clamped = Array3::from_elem((1, 1, 3), 300.0f32);
+        let mut out = ImgVec::new(vec![Rgb::new(0u8, 0, 0)], 1, 1);
+        tensor_to_rgb(clamped.view(), out.as_mut(), TensorLayout::Nhwc).unwrap();
+        assert_eq!(out.buf()[0], Rgb::new(255, 255, 255));
+    }
+}
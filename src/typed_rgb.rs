@@ -19,9 +19,85 @@
 //! let bgra: &mut [Bgra<u8>] = convert_inplace(&mut pixels);
 //! ```
 
-use crate::{ConvertInplace, ConvertTo, SizeError};
+use crate::{ConvertInplace, ConvertTo, Operand, SizeError, SizeErrorKind};
 use rgb::{Bgr, Bgra, Gray, GrayAlpha, Rgb, Rgba};
 
+/// Alpha-first pixel types, re-exported from [`rgb`].
+pub use rgb::{Abgr, Argb};
+
+// ===========================================================================
+// Padded pixel types (RGBX / BGRX)
+// ===========================================================================
+
+/// RGB pixel padded to 4 bytes (`RGBX8888`). The `x` byte is ignored on
+/// input and carries no meaning; conversions to [`Rgba`] set alpha to 255.
+///
+/// Only 8-bit channels: padded formats don't exist at other depths.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct Rgbx {
+    /// Red.
+    pub r: u8,
+    /// Green.
+    pub g: u8,
+    /// Blue.
+    pub b: u8,
+    /// Padding.
+    pub x: u8,
+}
+
+/// BGR pixel padded to 4 bytes (`BGRX8888`, Windows `XRGB` surfaces). The
+/// `x` byte is ignored on input; conversions to [`Bgra`] set alpha to 255.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+pub struct Bgrx {
+    /// Blue.
+    pub b: u8,
+    /// Green.
+    pub g: u8,
+    /// Red.
+    pub r: u8,
+    /// Padding.
+    pub x: u8,
+}
+
+impl Rgbx {
+    /// Pixel with padding byte 0.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, x: 0 }
+    }
+}
+
+impl Bgrx {
+    /// Pixel with padding byte 0.
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { b, g, r, x: 0 }
+    }
+}
+
+/// Copy 4bpp pixels, then set byte 3 to 255.
+fn copy_fill_alpha(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    let len = dst.len();
+    let dst = dst.get_mut(..src.len()).ok_or(
+        SizeError::new(SizeErrorKind::PixelCountMismatch)
+            .of(Operand::Dst)
+            .lengths(Some(src.len()), len),
+    )?;
+    dst.copy_from_slice(src);
+    crate::bytes::fill_alpha_rgba(dst)
+}
+
+/// Swap bytes 0 and 2 while copying, then set byte 3 to 255.
+fn swap_fill_alpha(src: &[u8], dst: &mut [u8]) -> Result<(), SizeError> {
+    crate::bytes::rgba_to_bgra(src, dst)?;
+    crate::bytes::fill_alpha_rgba(&mut dst[..src.len()])
+}
+
+/// Reinterpretation with no byte changes (alpha becomes padding).
+fn keep_bytes(_buf: &mut [u8]) -> Result<(), SizeError> {
+    Ok(())
+}
+
 // ===========================================================================
 // Macro for ConvertTo impls (copy conversions)
 // ===========================================================================
@@ -103,6 +179,74 @@ impl_convert_to!(Bgra<u8>, Bgr<u8>, crate::bytes::bgra_to_bgr);
 impl_convert_to!(Bgra<u8>, Rgb<u8>, crate::bytes::bgra_to_rgb);
 impl_convert_to!(Rgba<u8>, Bgr<u8>, crate::bytes::rgba_to_bgr);
 
+// ===========================================================================
+// Alpha-first (ARGB / ABGR)
+// ===========================================================================
+
+impl_convert_inplace!(Argb<u8>, Rgba<u8>, crate::bytes::argb_to_rgba_inplace);
+impl_convert_inplace!(Abgr<u8>, Bgra<u8>, crate::bytes::abgr_to_bgra_inplace);
+impl_convert_inplace!(Rgba<u8>, Argb<u8>, crate::bytes::rgba_to_argb_inplace);
+impl_convert_inplace!(Bgra<u8>, Abgr<u8>, crate::bytes::bgra_to_abgr_inplace);
+impl_convert_inplace!(Argb<u8>, Bgra<u8>, crate::bytes::argb_to_bgra_inplace);
+impl_convert_inplace!(Bgra<u8>, Argb<u8>, crate::bytes::bgra_to_argb_inplace);
+impl_convert_inplace!(Abgr<u8>, Rgba<u8>, crate::bytes::abgr_to_rgba_inplace);
+impl_convert_inplace!(Rgba<u8>, Abgr<u8>, crate::bytes::rgba_to_abgr_inplace);
+
+impl_convert_to!(Argb<u8>, Rgba<u8>, crate::bytes::argb_to_rgba);
+impl_convert_to!(Abgr<u8>, Bgra<u8>, crate::bytes::abgr_to_bgra);
+impl_convert_to!(Rgba<u8>, Argb<u8>, crate::bytes::rgba_to_argb);
+impl_convert_to!(Bgra<u8>, Abgr<u8>, crate::bytes::bgra_to_abgr);
+impl_convert_to!(Argb<u8>, Bgra<u8>, crate::bytes::argb_to_bgra);
+impl_convert_to!(Bgra<u8>, Argb<u8>, crate::bytes::bgra_to_argb);
+impl_convert_to!(Abgr<u8>, Rgba<u8>, crate::bytes::abgr_to_rgba);
+impl_convert_to!(Rgba<u8>, Abgr<u8>, crate::bytes::rgba_to_abgr);
+
+impl_convert_to!(Rgb<u8>, Argb<u8>, crate::bytes::rgb_to_argb);
+impl_convert_to!(Rgb<u8>, Abgr<u8>, crate::bytes::rgb_to_abgr);
+impl_convert_to!(Bgr<u8>, Argb<u8>, crate::bytes::bgr_to_argb);
+impl_convert_to!(Bgr<u8>, Abgr<u8>, crate::bytes::bgr_to_abgr);
+impl_convert_to!(Gray<u8>, Argb<u8>, crate::bytes::gray_to_argb);
+impl_convert_to!(Gray<u8>, Abgr<u8>, crate::bytes::gray_to_abgr);
+impl_convert_to!(GrayAlpha<u8>, Argb<u8>, crate::bytes::gray_alpha_to_argb);
+impl_convert_to!(GrayAlpha<u8>, Abgr<u8>, crate::bytes::gray_alpha_to_abgr);
+
+impl_convert_to!(Argb<u8>, Rgb<u8>, crate::bytes::argb_to_rgb);
+impl_convert_to!(Argb<u8>, Bgr<u8>, crate::bytes::argb_to_bgr);
+impl_convert_to!(Abgr<u8>, Rgb<u8>, crate::bytes::abgr_to_rgb);
+impl_convert_to!(Abgr<u8>, Bgr<u8>, crate::bytes::abgr_to_bgr);
+
+// ===========================================================================
+// Padded (RGBX / BGRX)
+// ===========================================================================
+
+impl_convert_inplace!(Rgbx, Rgba<u8>, crate::bytes::fill_alpha_rgba);
+impl_convert_inplace!(Bgrx, Bgra<u8>, crate::bytes::fill_alpha_bgra);
+impl_convert_inplace!(Rgba<u8>, Rgbx, keep_bytes);
+impl_convert_inplace!(Bgra<u8>, Bgrx, keep_bytes);
+impl_convert_inplace!(Rgbx, Bgrx, crate::bytes::rgba_to_bgra_inplace);
+impl_convert_inplace!(Bgrx, Rgbx, crate::bytes::bgra_to_rgba_inplace);
+
+impl_convert_to!(Rgbx, Rgba<u8>, copy_fill_alpha);
+impl_convert_to!(Bgrx, Bgra<u8>, copy_fill_alpha);
+impl_convert_to!(Rgbx, Bgra<u8>, swap_fill_alpha);
+impl_convert_to!(Bgrx, Rgba<u8>, swap_fill_alpha);
+impl_convert_to!(Rgba<u8>, Bgrx, crate::bytes::rgba_to_bgra);
+impl_convert_to!(Bgra<u8>, Rgbx, crate::bytes::bgra_to_rgba);
+impl_convert_to!(Rgbx, Bgrx, crate::bytes::rgba_to_bgra);
+impl_convert_to!(Bgrx, Rgbx, crate::bytes::bgra_to_rgba);
+
+impl_convert_to!(Rgb<u8>, Rgbx, crate::bytes::rgb_to_rgba);
+impl_convert_to!(Rgb<u8>, Bgrx, crate::bytes::rgb_to_bgra);
+impl_convert_to!(Bgr<u8>, Rgbx, crate::bytes::bgr_to_rgba);
+impl_convert_to!(Bgr<u8>, Bgrx, crate::bytes::bgr_to_bgra);
+impl_convert_to!(Gray<u8>, Rgbx, crate::bytes::gray_to_rgba);
+impl_convert_to!(Gray<u8>, Bgrx, crate::bytes::gray_to_bgra);
+
+impl_convert_to!(Rgbx, Rgb<u8>, crate::bytes::rgba_to_rgb);
+impl_convert_to!(Rgbx, Bgr<u8>, crate::bytes::rgba_to_bgr);
+impl_convert_to!(Bgrx, Bgr<u8>, crate::bytes::bgra_to_bgr);
+impl_convert_to!(Bgrx, Rgb<u8>, crate::bytes::bgra_to_rgb);
+
 // ===========================================================================
 // Alpha fill (not a conversion pair — standalone functions)
 // ===========================================================================
@@ -131,6 +275,84 @@ pub fn fill_alpha_bgra(pixels: &mut [Bgra<u8>]) {
     crate::bytes::fill_alpha_rgba(bytes).expect("typed slice is always valid");
 }
 
+/// Set alpha to 255 for all pixels in a `&mut [Argb<u8>]`.
+///
+/// Empty input is a no-op (no panic).
+pub fn fill_alpha_argb(pixels: &mut [Argb<u8>]) {
+    if pixels.is_empty() {
+        return;
+    }
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(pixels);
+    crate::bytes::fill_alpha_argb(bytes).expect("typed slice is always valid");
+}
+
+/// Set alpha to 255 for all pixels in a `&mut [Abgr<u8>]`.
+///
+/// Empty input is a no-op (no panic).
+pub fn fill_alpha_abgr(pixels: &mut [Abgr<u8>]) {
+    if pixels.is_empty() {
+        return;
+    }
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(pixels);
+    crate::bytes::fill_alpha_abgr(bytes).expect("typed slice is always valid");
+}
+
+// ===========================================================================
+// In-place bpp changes
+// ===========================================================================
+
+/// RGBA → RGB in place; returns the compacted pixels at the front of `pixels`.
+///
+/// The result covers the first `pixels.len() * 3` bytes; the remaining
+/// elements keep stale data. Empty input returns an empty slice.
+pub fn rgba_to_rgb_mut(pixels: &mut [Rgba<u8>]) -> &mut [Rgb<u8>] {
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(pixels);
+    if bytes.is_empty() {
+        return &mut [];
+    }
+    bytemuck::cast_slice_mut(
+        crate::bytes::rgba_to_rgb_inplace(bytes).expect("typed slice is always valid"),
+    )
+}
+
+/// RGB → RGBA in place, growing `pixels` into its spare capacity.
+///
+/// The vector is resized to hold `len * 4` bytes (reallocating only if its
+/// capacity is too small) and the returned slice views them as RGBA. The
+/// `Rgb` elements of `pixels` then hold RGBA bytes; use the returned slice.
+#[cfg(feature = "alloc")]
+pub fn rgb_to_rgba_vec(pixels: &mut alloc::vec::Vec<Rgb<u8>>) -> &mut [Rgba<u8>] {
+    let n = pixels.len();
+    if n == 0 {
+        return &mut [];
+    }
+    pixels.resize((n * 4).div_ceil(3), Rgb::default());
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(pixels.as_mut_slice());
+    bytemuck::cast_slice_mut(crate::bytes::rgb_to_rgba_inplace(bytes, n).expect("resized to fit"))
+}
+
+// ===========================================================================
+// Allocating conversions
+// ===========================================================================
+
+/// Copy-convert into a newly allocated `Vec`.
+///
+/// ```rust
+/// use rgb::{Rgb, Bgra};
+/// use garb::typed_rgb::convert_to_vec;
+///
+/// let bgra = convert_to_vec::<Bgra<u8>>(&[Rgb::new(255u8, 0, 128); 2]);
+/// assert_eq!(bgra[1], Bgra { b: 128, g: 0, r: 255, a: 255 });
+/// ```
+#[cfg(feature = "alloc")]
+pub fn convert_to_vec<D: Clone + Default>(src: &[impl ConvertTo<D>]) -> alloc::vec::Vec<D> {
+    let mut dst = alloc::vec![D::default(); src.len()];
+    if !src.is_empty() {
+        ConvertTo::convert_to(src, &mut dst).expect("destination sized to match");
+    }
+    dst
+}
+
 // ===========================================================================
 // Deprecated named functions — use convert() / convert_inplace() instead
 // ===========================================================================
@@ -272,6 +494,87 @@ mod experimental_typed {
     impl_convert_to!(Bgr<u8>, Gray<u8>, crate::bytes::bgr_to_gray_identity);
     impl_convert_to!(Bgra<u8>, Gray<u8>, crate::bytes::bgra_to_gray_identity);
 
+    // -----------------------------------------------------------------------
+    // Packed pixel types
+    // -----------------------------------------------------------------------
+
+    /// RGB565 pixel: a little-endian `u16` with `R[15:11] G[10:5] B[4:0]`.
+    ///
+    /// Stored as bytes so the layout matches
+    /// [`bytes::rgb565_to_rgba`](crate::bytes::rgb565_to_rgba) on every target.
+    #[derive(
+        Clone, Copy, Debug, Default, PartialEq, Eq, Hash, bytemuck::Pod, bytemuck::Zeroable,
+    )]
+    #[repr(C)]
+    pub struct Rgb565([u8; 2]);
+
+    /// RGBA4444 pixel: a little-endian `u16` with `R[15:12] G[11:8] B[7:4] A[3:0]`.
+    #[derive(
+        Clone, Copy, Debug, Default, PartialEq, Eq, Hash, bytemuck::Pod, bytemuck::Zeroable,
+    )]
+    #[repr(C)]
+    pub struct Rgba4444([u8; 2]);
+
+    /// RGBA1010102 pixel: a little-endian `u32` with R in bits 0–9, G in
+    /// 10–19, B in 20–29 and A in 30–31.
+    #[derive(
+        Clone, Copy, Debug, Default, PartialEq, Eq, Hash, bytemuck::Pod, bytemuck::Zeroable,
+    )]
+    #[repr(C)]
+    pub struct Rgba1010102([u8; 4]);
+
+    macro_rules! packed_bits {
+        ($ty:ident, $bits:ty) => {
+            impl $ty {
+                /// Pixel from its packed value.
+                pub const fn from_bits(bits: $bits) -> Self {
+                    Self(bits.to_le_bytes())
+                }
+
+                /// Packed value.
+                pub const fn to_bits(self) -> $bits {
+                    <$bits>::from_le_bytes(self.0)
+                }
+            }
+        };
+    }
+
+    packed_bits!(Rgb565, u16);
+    packed_bits!(Rgba4444, u16);
+    packed_bits!(Rgba1010102, u32);
+
+    impl_convert_to!(Rgb565, Rgba<u8>, crate::bytes::rgb565_to_rgba);
+    impl_convert_to!(Rgb565, Bgra<u8>, crate::bytes::rgb565_to_bgra);
+    impl_convert_to!(Rgba4444, Rgba<u8>, crate::bytes::rgba4444_to_rgba);
+    impl_convert_to!(Rgba4444, Bgra<u8>, crate::bytes::rgba4444_to_bgra);
+    impl_convert_to!(Rgba<u8>, Rgb565, crate::bytes::rgba_to_rgb565);
+    impl_convert_to!(Bgra<u8>, Rgb565, crate::bytes::bgra_to_rgb565);
+    impl_convert_to!(Rgba<u8>, Rgba4444, crate::bytes::rgba_to_rgba4444);
+    impl_convert_to!(Bgra<u8>, Rgba4444, crate::bytes::bgra_to_rgba4444);
+
+    /// 10-bit channels in the low bits of each `u16`; 2-bit alpha is expanded
+    /// to 10 bits by bit replication.
+    impl ConvertTo<Rgba<u16>> for Rgba1010102 {
+        #[inline]
+        fn convert_to(src: &[Self], dst: &mut [Rgba<u16>]) -> Result<(), SizeError> {
+            crate::bytes::rgba1010102_to_rgba16(
+                bytemuck::cast_slice(src),
+                bytemuck::cast_slice_mut(dst),
+            )
+        }
+    }
+
+    /// Channels above 1023 are masked, not clamped.
+    impl ConvertTo<Rgba1010102> for Rgba<u16> {
+        #[inline]
+        fn convert_to(src: &[Self], dst: &mut [Rgba1010102]) -> Result<(), SizeError> {
+            crate::bytes::rgba16_to_rgba1010102(
+                bytemuck::cast_slice(src),
+                bytemuck::cast_slice_mut(dst),
+            )
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Deprecated named functions
     // -----------------------------------------------------------------------
@@ -496,6 +799,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_inplace_bpp_change() {
+        let mut pixels = vec![Rgba::new(1u8, 2, 3, 4), Rgba::new(5, 6, 7, 8)];
+        let rgb = super::rgba_to_rgb_mut(&mut pixels);
+        assert_eq!(rgb, [rgb::Rgb::new(1, 2, 3), rgb::Rgb::new(5, 6, 7)]);
+        assert!(super::rgba_to_rgb_mut(&mut []).is_empty());
+
+        let mut pixels = alloc::vec::Vec::with_capacity(8);
+        pixels.extend([rgb::Rgb::new(1u8, 2, 3), rgb::Rgb::new(4, 5, 6)]);
+        let ptr = pixels.as_ptr() as usize;
+        let rgba = super::rgb_to_rgba_vec(&mut pixels);
+        assert_eq!(rgba, [Rgba::new(1, 2, 3, 255), Rgba::new(4, 5, 6, 255)]);
+        assert_eq!(rgba.as_ptr() as usize, ptr);
+    }
+
+    #[test]
+    fn test_convert_to_vec() {
+        let bgra = super::convert_to_vec::<Bgra<u8>>(&[Rgba::new(1u8, 2, 3, 4); 3]);
+        assert_eq!(
+            bgra,
+            [Bgra {
+                b: 3,
+                g: 2,
+                r: 1,
+                a: 4
+            }; 3]
+        );
+        assert!(super::convert_to_vec::<Bgra<u8>>(&[] as &[Rgba<u8>]).is_empty());
+    }
+
     #[test]
     fn test_convert_inplace() {
         let mut pixels = vec![Rgba::new(255u8, 128, 0, 200), Rgba::new(10, 20, 30, 40)];
@@ -577,8 +910,8 @@ mod tests {
         let src = vec![Rgba::new(1u8, 2, 3, 4); 3];
         let mut dst = vec![Bgra::default(); 2];
         assert_eq!(
-            crate::convert(&src, &mut dst),
-            Err(crate::SizeError::PixelCountMismatch)
+            crate::convert(&src, &mut dst).map_err(|e| e.kind()),
+            Err(crate::SizeErrorKind::PixelCountMismatch)
         );
     }
 
@@ -628,6 +961,94 @@ mod tests {
         assert!(bgr.is_empty());
     }
 
+    #[test]
+    fn test_alpha_first_and_padded() {
+        use super::{Abgr, Argb, Bgrx, Rgbx};
+        use rgb::Rgb;
+
+        let mut pixels = vec![Argb::new_argb(4u8, 1, 2, 3); 2];
+        let rgba: &mut [Rgba<u8>] = crate::convert_inplace(&mut pixels);
+        assert_eq!(rgba[1], Rgba::new(1, 2, 3, 4));
+        let mut abgr = vec![Abgr::default(); 2];
+        crate::convert(&[Rgb::new(1u8, 2, 3); 2][..], &mut abgr).unwrap();
+        assert_eq!(abgr[0], Abgr::new_abgr(255, 3, 2, 1));
+
+        let padded = vec![
+            Rgbx {
+                r: 1,
+                g: 2,
+                b: 3,
+                x: 9
+            };
+            2
+        ];
+        let mut bgra = vec![Bgra::default(); 2];
+        crate::convert(&padded, &mut bgra).unwrap();
+        assert_eq!(
+            bgra[0],
+            Bgra {
+                b: 3,
+                g: 2,
+                r: 1,
+                a: 255
+            }
+        );
+        let mut rgb = vec![Rgb::default(); 2];
+        crate::convert(&padded, &mut rgb).unwrap();
+        assert_eq!(rgb[1], Rgb::new(1, 2, 3));
+
+        let mut pixels = vec![
+            Bgrx {
+                b: 3,
+                g: 2,
+                r: 1,
+                x: 0
+            };
+            2
+        ];
+        let bgra: &mut [Bgra<u8>] = crate::convert_inplace(&mut pixels);
+        assert_eq!(bgra[0].a, 255);
+        let mut short = vec![Rgba::default(); 1];
+        let err = crate::convert(&padded, &mut short).unwrap_err();
+        assert_eq!(err.kind(), crate::SizeErrorKind::PixelCountMismatch);
+        assert_eq!(err.operand(), Some(crate::Operand::Dst));
+        assert_eq!((err.required(), err.actual()), (Some(8), Some(4)));
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn test_packed_types() {
+        use super::{Rgb565, Rgba4444, Rgba1010102};
+
+        let mut packed = vec![Rgb565::default(); 2];
+        crate::convert(&[Rgba::new(255u8, 0, 255, 255); 2][..], &mut packed).unwrap();
+        assert_eq!(packed[0].to_bits(), 0xF81F);
+        let mut back = vec![Bgra::default(); 2];
+        crate::convert(&packed, &mut back).unwrap();
+        assert_eq!(
+            back[1],
+            Bgra {
+                b: 255,
+                g: 0,
+                r: 255,
+                a: 255
+            }
+        );
+
+        let src = [Rgba4444::from_bits(0xF00F)];
+        let mut rgba = [Rgba::default()];
+        crate::convert(&src[..], &mut rgba[..]).unwrap();
+        assert_eq!(rgba[0], Rgba::new(255, 0, 0, 255));
+
+        let src = [Rgba1010102::from_bits(0xC000_03FF)];
+        let mut wide = [Rgba::<u16>::default()];
+        crate::convert(&src[..], &mut wide[..]).unwrap();
+        assert_eq!(wide[0], Rgba::new(1023, 0, 0, 1023));
+        let mut again = [Rgba1010102::default()];
+        crate::convert(&wide[..], &mut again[..]).unwrap();
+        assert_eq!(again, src);
+    }
+
     #[allow(deprecated)]
     #[test]
     fn test_deprecated_fns_still_work() {
@@ -22,6 +22,146 @@ fn readme_strided() {
     rgba_to_bgra_inplace_strided(&mut buf, 60, 100, 256).unwrap();
 }
 
+#[test]
+fn readme_signed_stride() {
+    use garb::bytes::{SignedStride, bgra_to_rgba_strided_signed};
+
+    let mut dib = vec![0u8; 240 * 100];
+    dib[..4].copy_from_slice(&[1, 2, 3, 4]); // bottom row, first pixel
+    let mut rgba = vec![0u8; 240 * 100];
+    bgra_to_rgba_strided_signed(
+        &dib,
+        &mut rgba,
+        60,
+        100,
+        SignedStride::bottom_up(240, 100),
+        SignedStride::top_down(240),
+    )
+    .unwrap();
+    assert_eq!(rgba[240 * 99..][..4], [3, 2, 1, 4]);
+}
+
+#[test]
+fn readme_row_converter() {
+    use garb::bytes::{PixelLayout, RowConverter};
+
+    let conv = RowConverter::new(PixelLayout::Rgb, PixelLayout::Bgra, 60).unwrap();
+    let row = vec![0u8; 60 * 3];
+    let mut out = vec![0u8; 60 * 4];
+    conv.push_row(&row, &mut out).unwrap();
+    assert_eq!(out[..4], [0, 0, 0, 255]);
+}
+
+#[test]
+fn readme_orientation() {
+    use garb::bytes::{Orientation, Swizzle, transform_4bpp_strided};
+
+    let (w, h) = (60, 40);
+    let bgra = vec![0u8; w * h * 4];
+    let orientation = Orientation::from_exif(6).unwrap();
+    let (dw, dh) = orientation.output_size(w, h);
+    assert_eq!((dw, dh), (40, 60));
+    let mut rgba = vec![0u8; dw * dh * 4];
+    transform_4bpp_strided(
+        &bgra,
+        &mut rgba,
+        w,
+        h,
+        w * 4,
+        dw * 4,
+        orientation,
+        Swizzle::SWAP_BR,
+    )
+    .unwrap();
+}
+
+#[test]
+fn readme_gpu_upload() {
+    use garb::bytes::{COPY_BYTES_PER_ROW_ALIGNMENT, PixelLayout, convert_for_upload};
+
+    let (w, h) = (100, 60);
+    let rgb = vec![7u8; w * h * 3];
+    let mut staging = vec![0u8; 512 * h];
+    let plan = convert_for_upload(
+        &rgb,
+        PixelLayout::Rgb,
+        w,
+        h,
+        w * 3,
+        &mut staging,
+        COPY_BYTES_PER_ROW_ALIGNMENT,
+    )
+    .unwrap();
+    assert_eq!((plan.bytes_per_row, plan.layout.vk_format()), (512, 37));
+    assert_eq!(staging[512..516], [7, 7, 7, 255]);
+}
+
+#[test]
+fn readme_capability() {
+    use garb::bytes::{KernelKind, PixelLayout, SimdTier, row_capability};
+
+    let tier = SimdTier::detect();
+    let best = [PixelLayout::Bgra, PixelLayout::Rgba]
+        .into_iter()
+        .filter_map(|dst| Some((row_capability(PixelLayout::Rgb, dst, tier)?.cost, dst)))
+        .min_by_key(|&(cost, _)| cost);
+    assert!(best.is_some());
+    const CAP: Option<garb::bytes::Capability> =
+        row_capability(PixelLayout::Rgba, PixelLayout::Rgba, SimdTier::Scalar);
+    assert_eq!(CAP.unwrap().kind, KernelKind::Copy);
+}
+
+#[test]
+fn readme_fourcc() {
+    use garb::fourcc::{DRM_FORMAT_ABGR8888, DRM_FORMAT_XRGB8888, convert_fourcc};
+
+    let (w, h) = (64, 48);
+    let mut scanout = vec![0u8; w * h * 4];
+    scanout[..4].copy_from_slice(&[3, 2, 1, 0]);
+    let mut rgba = vec![0u8; w * h * 4];
+    convert_fourcc(
+        &scanout,
+        DRM_FORMAT_XRGB8888,
+        &mut rgba,
+        DRM_FORMAT_ABGR8888,
+        w,
+        h,
+        w * 4,
+        w * 4,
+    )
+    .unwrap();
+    assert_eq!(rgba[..4], [1, 2, 3, 255]);
+}
+
+#[test]
+fn readme_ffmpeg() {
+    use garb::ffmpeg::{AvPixelFormat, convert_pix_fmt};
+
+    let fmt = AvPixelFormat::from_name("bgr0").unwrap();
+    assert!(fmt.is_supported());
+    assert!(
+        !AvPixelFormat::from_name("AV_PIX_FMT_NV12")
+            .unwrap()
+            .is_supported()
+    );
+    let (w, h) = (64, 48);
+    let mut frame = vec![0u8; w * h * 4];
+    frame[..4].copy_from_slice(&[3, 2, 1, 0]);
+    let mut rgba = vec![0u8; w * h * 4];
+    convert_pix_fmt(
+        &frame,
+        fmt,
+        &mut rgba,
+        AvPixelFormat::Rgba,
+        w,
+        h,
+        w * 4,
+        w * 4,
+    )
+    .unwrap();
+    assert_eq!(rgba[..4], [1, 2, 3, 255]);
+}
+
 #[cfg(feature = "rgb")]
 #[test]
 fn readme_typed_rgb() {
@@ -53,3 +193,36 @@ fn readme_imgref() {
     assert_eq!(bgra_img.width(), 640);
     assert_eq!(bgra_img.height(), 480);
 }
+
+#[cfg(feature = "image")]
+#[test]
+fn readme_image() {
+    use garb::image::{dynamic_to_bgra8, dynamic_to_rgba8};
+    use image::{DynamicImage, Rgb, RgbImage};
+
+    let img = DynamicImage::ImageRgb8(RgbImage::from_pixel(640, 480, Rgb([255, 0, 128])));
+    let mut surface = vec![0u8; 640 * 480 * 4];
+    dynamic_to_bgra8(&img, &mut surface).unwrap();
+    assert_eq!(&surface[..4], &[128, 0, 255, 255]);
+    let rgba = dynamic_to_rgba8(&img);
+    assert_eq!(rgba.get_pixel(639, 479).0, [255, 0, 128, 255]);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn readme_ndarray() {
+    use garb::ndarray::{TensorLayout, rgb_to_tensor};
+    use ndarray::{Array4, Axis};
+
+    let img = ::imgref::ImgVec::new(vec![rgb::Rgb::new(255u8, 0, 51); 224 * 224], 224, 224);
+    let mut batch = Array4::<f32>::zeros((8, 3, 224, 224));
+    rgb_to_tensor(
+        img.as_ref(),
+        batch.index_axis_mut(Axis(0), 0),
+        TensorLayout::Nchw,
+    )
+    .unwrap();
+    batch.mapv_inplace(|v| v / 255.0);
+    assert_eq!(batch[[0, 0, 10, 10]], 1.0);
+    assert_eq!(batch[[0, 2, 10, 10]], 0.2);
+}